@@ -0,0 +1,184 @@
+//! Errors returned by the cache-based permission checks.
+//!
+//! The [`CacheHttpError`] type is returned by [`CacheHttp`] methods when a
+//! request cannot be performed. It allows callers to tell users exactly which
+//! permission is missing or which role must be moved.
+//!
+//! [`CacheHttp`]: super::http::CacheHttp
+
+use std::{error::Error, fmt};
+
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Error returned by [`CacheHttp`] methods.
+///
+/// [`CacheHttp`]: super::http::CacheHttp
+#[derive(Debug)]
+pub enum CacheHttpError {
+    /// The bot is missing permissions to perform the request.
+    MissingPermissions {
+        /// Permissions required by the request.
+        required: Permissions,
+        /// Required permissions the bot does not have.
+        missing: Permissions,
+        /// Channel where the permissions are required, if the request is not
+        /// guild-wide.
+        channel: Option<Id<ChannelMarker>>,
+    },
+    /// The request targets a role above or equal to the bot's highest role.
+    RoleHierarchy {
+        /// Role that cannot be managed by the bot.
+        role: Id<RoleMarker>,
+    },
+    /// The request targets a member above or equal to the bot in the role
+    /// hierarchy, or the guild owner.
+    MemberHierarchy {
+        /// Member that cannot be managed by the bot.
+        user: Id<UserMarker>,
+    },
+    /// A required resource has not been found in the cache.
+    CacheMiss(CacheMiss),
+    /// Another error occurred while querying the cache or building the request.
+    Other(anyhow::Error),
+}
+
+impl CacheHttpError {
+    /// Initialize a new [`CacheHttpError::MissingPermissions`].
+    ///
+    /// The missing permissions are computed from the required permissions and
+    /// the permissions of the bot.
+    pub fn missing_permissions(
+        required: Permissions,
+        permissions: Permissions,
+        channel: Option<Id<ChannelMarker>>,
+    ) -> Self {
+        Self::MissingPermissions {
+            required,
+            missing: required - permissions,
+            channel,
+        }
+    }
+}
+
+impl fmt::Display for CacheHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheHttpError::MissingPermissions {
+                missing,
+                channel: Some(channel),
+                ..
+            } => write!(f, "missing permissions {missing:?} in channel {channel}"),
+            CacheHttpError::MissingPermissions { missing, .. } => {
+                write!(f, "missing permissions {missing:?}")
+            }
+            CacheHttpError::RoleHierarchy { role } => {
+                write!(f, "role {role} is higher than bot's highest role")
+            }
+            CacheHttpError::MemberHierarchy { user } => {
+                write!(f, "member {user} is higher than bot's highest role")
+            }
+            CacheHttpError::CacheMiss(miss) => miss.fmt(f),
+            CacheHttpError::Other(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for CacheHttpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CacheHttpError::CacheMiss(miss) => Some(miss),
+            CacheHttpError::Other(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for CacheHttpError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<CacheMiss>() {
+            Ok(miss) => CacheHttpError::CacheMiss(miss),
+            Err(error) => CacheHttpError::Other(error),
+        }
+    }
+}
+
+impl From<CacheMiss> for CacheHttpError {
+    fn from(miss: CacheMiss) -> Self {
+        CacheHttpError::CacheMiss(miss)
+    }
+}
+
+/// Resource missing from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMiss {
+    /// The guild is not cached.
+    Guild(Id<GuildMarker>),
+    /// The bot member of the guild is not cached.
+    CurrentMember(Id<GuildMarker>),
+    /// The channel is not cached.
+    Channel(Id<ChannelMarker>),
+    /// The role is not cached.
+    Role(Id<RoleMarker>),
+    /// The guild member is not cached.
+    Member(Id<UserMarker>),
+}
+
+impl fmt::Display for CacheMiss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheMiss::Guild(id) => write!(f, "guild {id} not found in cache"),
+            CacheMiss::CurrentMember(id) => {
+                write!(f, "current member of guild {id} not found in cache")
+            }
+            CacheMiss::Channel(id) => write!(f, "channel {id} not found in cache"),
+            CacheMiss::Role(id) => write!(f, "role {id} not found in cache"),
+            CacheMiss::Member(id) => write!(f, "member {id} not found in cache"),
+        }
+    }
+}
+
+impl Error for CacheMiss {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn test_missing_permissions() {
+        let error = CacheHttpError::missing_permissions(
+            Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS,
+            Permissions::SEND_MESSAGES | Permissions::KICK_MEMBERS,
+            None,
+        );
+
+        assert!(matches!(
+            error,
+            CacheHttpError::MissingPermissions { missing, .. } if missing == Permissions::EMBED_LINKS
+        ));
+    }
+
+    #[test]
+    fn test_from_cache_miss() {
+        let error = CacheHttpError::from(anyhow::Error::from(CacheMiss::Channel(Id::new(1))));
+
+        assert!(matches!(
+            error,
+            CacheHttpError::CacheMiss(CacheMiss::Channel(id)) if id == Id::new(1)
+        ));
+    }
+
+    #[test]
+    fn test_from_other() {
+        let error = CacheHttpError::from(anyhow!("redis error"));
+
+        assert!(matches!(error, CacheHttpError::Other(_)));
+    }
+}
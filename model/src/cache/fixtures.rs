@@ -0,0 +1,200 @@
+//! Cached data fixtures used by permission tests.
+//!
+//! The fixtures describe a guild with the following members:
+//!
+//! - [`OWNER_ID`]: owner of the guild, without any role.
+//! - [`BOT_ID`]: the bot, with the [`BOT_ROLE`] role.
+//! - [`MODERATOR_ID`]: a member with the [`MODERATOR_ROLE`] role, above the bot.
+//! - [`MEMBER_ID`]: a member with the [`MEMBER_ROLE`] role, below the bot.
+
+use std::collections::HashSet;
+
+use twilight_model::{
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        ChannelType,
+    },
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::{
+    model::{CachedChannel, CachedGuild, CachedRole, CurrentMember},
+    permission::{MemberPermissions, MemberRoles},
+};
+
+pub const GUILD_ID: Id<GuildMarker> = Id::new(1);
+
+pub const OWNER_ID: Id<UserMarker> = Id::new(10);
+pub const BOT_ID: Id<UserMarker> = Id::new(11);
+pub const MODERATOR_ID: Id<UserMarker> = Id::new(12);
+pub const MEMBER_ID: Id<UserMarker> = Id::new(13);
+
+pub const MODERATOR_ROLE: Id<RoleMarker> = Id::new(20);
+pub const BOT_ROLE: Id<RoleMarker> = Id::new(21);
+pub const MEMBER_ROLE: Id<RoleMarker> = Id::new(22);
+
+pub const TEXT_CHANNEL: Id<ChannelMarker> = Id::new(30);
+pub const LOCKED_CHANNEL: Id<ChannelMarker> = Id::new(31);
+pub const PUBLIC_THREAD: Id<ChannelMarker> = Id::new(32);
+pub const PRIVATE_THREAD: Id<ChannelMarker> = Id::new(33);
+
+/// Guild of the fixtures.
+pub fn guild() -> CachedGuild {
+    CachedGuild {
+        id: GUILD_ID,
+        unavailable: false,
+        name: "Fixture guild".to_string(),
+        icon: None,
+        owner_id: OWNER_ID,
+        current_member: Some(CurrentMember {
+            id: BOT_ID,
+            communication_disabled_until: None,
+            roles: HashSet::from([BOT_ROLE]),
+        }),
+        roles: HashSet::from([GUILD_ID.cast(), MODERATOR_ROLE, BOT_ROLE, MEMBER_ROLE]),
+        channels: HashSet::from([TEXT_CHANNEL, LOCKED_CHANNEL, PUBLIC_THREAD, PRIVATE_THREAD]),
+    }
+}
+
+/// Role with a given position and permissions.
+pub fn role(id: Id<RoleMarker>, position: i64, permissions: Permissions) -> CachedRole {
+    CachedRole {
+        id,
+        guild_id: GUILD_ID,
+        name: format!("role-{id}"),
+        color: 0,
+        icon: None,
+        unicode_emoji: None,
+        position,
+        permissions,
+        managed: false,
+    }
+}
+
+/// The `@everyone` role, allowed to view channels and send messages.
+pub fn everyone() -> CachedRole {
+    role(
+        GUILD_ID.cast(),
+        0,
+        Permissions::VIEW_CHANNEL
+            | Permissions::SEND_MESSAGES
+            | Permissions::SEND_MESSAGES_IN_THREADS,
+    )
+}
+
+/// Role of the moderators, above the bot.
+pub fn moderator_role() -> CachedRole {
+    role(
+        MODERATOR_ROLE,
+        3,
+        Permissions::KICK_MEMBERS | Permissions::BAN_MEMBERS,
+    )
+}
+
+/// Role of the bot.
+pub fn bot_role() -> CachedRole {
+    role(
+        BOT_ROLE,
+        2,
+        Permissions::MANAGE_ROLES
+            | Permissions::MANAGE_MESSAGES
+            | Permissions::KICK_MEMBERS
+            | Permissions::EMBED_LINKS
+            | Permissions::USE_EXTERNAL_EMOJIS,
+    )
+}
+
+/// Role of regular members, below the bot.
+pub fn member_role() -> CachedRole {
+    role(MEMBER_ROLE, 1, Permissions::empty())
+}
+
+/// Resolve the permissions of a member of the guild.
+pub fn member(member_id: Id<UserMarker>, roles: Vec<CachedRole>) -> MemberPermissions {
+    let roles = MemberRoles::new(GUILD_ID, roles.into_iter().chain([everyone()])).unwrap();
+
+    MemberPermissions::new(&guild(), member_id, roles)
+}
+
+/// Permissions of the bot.
+pub fn bot() -> MemberPermissions {
+    member(BOT_ID, vec![bot_role()])
+}
+
+/// Channel of the guild.
+pub fn channel(id: Id<ChannelMarker>, overwrites: Vec<PermissionOverwrite>) -> CachedChannel {
+    CachedChannel {
+        id,
+        guild_id: GUILD_ID,
+        kind: ChannelType::GuildText,
+        name: format!("channel-{id}"),
+        parent_id: None,
+        permission_overwrites: Some(overwrites),
+        position: Some(0),
+        rate_limit_per_user: None,
+        thread_member: false,
+    }
+}
+
+/// Text channel without permission overwrites.
+pub fn text_channel() -> CachedChannel {
+    channel(TEXT_CHANNEL, Vec::new())
+}
+
+/// Text channel where `@everyone` cannot send messages, but the bot role can
+/// only send messages in threads.
+pub fn locked_channel() -> CachedChannel {
+    channel(
+        LOCKED_CHANNEL,
+        vec![
+            overwrite(
+                GUILD_ID.cast(),
+                Permissions::empty(),
+                Permissions::SEND_MESSAGES | Permissions::SEND_MESSAGES_IN_THREADS,
+            ),
+            overwrite(
+                BOT_ROLE.cast(),
+                Permissions::SEND_MESSAGES_IN_THREADS,
+                Permissions::empty(),
+            ),
+        ],
+    )
+}
+
+/// Thread created in a parent channel.
+pub fn thread(
+    id: Id<ChannelMarker>,
+    kind: ChannelType,
+    parent: &CachedChannel,
+    thread_member: bool,
+) -> CachedChannel {
+    CachedChannel {
+        id,
+        guild_id: GUILD_ID,
+        kind,
+        name: format!("thread-{id}"),
+        parent_id: Some(parent.id),
+        permission_overwrites: None,
+        position: None,
+        rate_limit_per_user: None,
+        thread_member,
+    }
+}
+
+/// Role permission overwrite.
+pub fn overwrite(
+    id: Id<GenericMarker>,
+    allow: Permissions,
+    deny: Permissions,
+) -> PermissionOverwrite {
+    PermissionOverwrite {
+        allow,
+        deny,
+        id,
+        kind: PermissionOverwriteType::Role,
+    }
+}
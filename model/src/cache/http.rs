@@ -1,29 +1,43 @@
 //! Discord HTTP client using information from the cache.
 //!
 //! This module exports wrapper around twilight's HTTP client that use the cache
-//! data to check permissions before making requests.
+//! data to check permissions before making requests. Failed checks are reported
+//! with a [`CacheHttpError`] that describes what the bot is missing.
 
-use anyhow::anyhow;
 use twilight_http::{
+    error::ErrorType,
     request::{
-        channel::{message::CreateMessage, UpdateChannelPermission},
+        channel::{
+            message::{CreateMessage, DeleteMessage, DeleteMessages},
+            webhook::CreateWebhook,
+            UpdateChannel, UpdateChannelPermission,
+        },
         guild::{
-            member::{AddRoleToMember, RemoveMember},
-            CreateGuildChannel,
+            ban::{CreateBan, DeleteBan},
+            member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
+            role::{CreateRole, UpdateRole},
+            CreateGuildChannel, GetAuditLog,
         },
     },
     Client as HttpClient,
 };
 use twilight_model::{
+    channel::ChannelType,
     guild::Permissions,
     http::permission_overwrite::PermissionOverwrite,
     id::{
-        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
         Id,
     },
+    util::Timestamp,
 };
 
-use super::{model::CachedRole, permission::RoleOrdering, redis::RedisClient};
+use super::{
+    error::{CacheHttpError, CacheMiss},
+    model::CachedRole,
+    permission::{CachePermissions, MemberPermissions, RoleOrdering},
+    redis::RedisClient,
+};
 
 /// HTTP client with permission checks.
 #[derive(Debug)]
@@ -49,9 +63,10 @@ impl<'a> CacheHttp<'a> {
 
     /// Send a message to a channel.
     ///
-    /// This method ensures that the bot has the [`SEND_MESSAGES`],
-    /// [`SEND_MESSAGES_IN_THREADS`], [`USE_EXTERNAL_EMOJIS`] and [`EMBED_LINKS`]
-    /// permissions before executing the request.
+    /// This method ensures that the bot has the [`SEND_MESSAGES`] permission
+    /// (or [`SEND_MESSAGES_IN_THREADS`] if the channel is a thread), and the
+    /// [`USE_EXTERNAL_EMOJIS`] and [`EMBED_LINKS`] permissions before executing
+    /// the request.
     ///
     /// [`SEND_MESSAGES`]: Permissions::SEND_MESSAGES
     /// [`SEND_MESSAGES_IN_THREADS`]: Permissions::SEND_MESSAGES_IN_THREADS
@@ -60,78 +75,168 @@ impl<'a> CacheHttp<'a> {
     pub async fn create_message(
         &self,
         channel: Id<ChannelMarker>,
-    ) -> Result<CreateMessage<'a>, anyhow::Error> {
-        let permissions = self.redis.permissions(self.guild_id).await?;
-        let (permissions, kind) = permissions.current_member().await?.channel(channel).await?;
-
-        let send_messages = if kind.is_thread() {
-            Permissions::SEND_MESSAGES
-        } else {
-            Permissions::SEND_MESSAGES_IN_THREADS
-        };
-
-        if !permissions
-            .contains(send_messages | Permissions::USE_EXTERNAL_EMOJIS | Permissions::EMBED_LINKS)
-        {
-            return Err(anyhow!("missing permissions to send message"));
-        }
+    ) -> Result<CreateMessage<'a>, CacheHttpError> {
+        let (permissions, kind) = self.current_member().await?.channel(channel).await?;
+        check_permissions(permissions, send_message_permissions(kind), Some(channel))?;
 
         Ok(self.http.create_message(channel))
     }
 
+    /// Delete a message.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_MESSAGES`] permission
+    /// in the channel.
+    ///
+    /// [`MANAGE_MESSAGES`]: Permissions::MANAGE_MESSAGES
+    pub async fn delete_message(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Result<DeleteMessage<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        self.check_channel(&permissions, channel_id, Permissions::MANAGE_MESSAGES)
+            .await?;
+
+        Ok(self.http.delete_message(channel_id, message_id))
+    }
+
+    /// Delete multiple messages at once.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_MESSAGES`] permission
+    /// in the channel.
+    ///
+    /// [`MANAGE_MESSAGES`]: Permissions::MANAGE_MESSAGES
+    pub async fn delete_messages(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> Result<DeleteMessages<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        self.check_channel(&permissions, channel_id, Permissions::MANAGE_MESSAGES)
+            .await?;
+
+        Ok(self.http.delete_messages(channel_id, message_ids))
+    }
+
     /// Create a new guild channel.
     ///
     /// This method ensure that the bot has the [`MANAGE_CHANNELS`] permission.
     ///
     /// [`MANAGE_CHANNELS`]: Permissions::MANAGE_CHANNELS
     pub async fn create_guild_channel(
-        &'a self,
+        &self,
         name: &'a str,
-    ) -> Result<CreateGuildChannel<'a>, anyhow::Error> {
-        let permissions = self
-            .redis
-            .permissions(self.guild_id)
-            .await?
-            .current_member()
-            .await?
-            .guild();
+    ) -> Result<CreateGuildChannel<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_CHANNELS)?;
 
-        if !permissions.contains(Permissions::MANAGE_CHANNELS) {
-            return Err(anyhow!("missing permissions to create channel"));
-        }
+        self.http
+            .create_guild_channel(self.guild_id, name)
+            .map_err(|error| CacheHttpError::Other(error.into()))
+    }
+
+    /// Update a channel, such as its name or slowmode.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_CHANNELS`] permission
+    /// in the channel.
+    ///
+    /// [`MANAGE_CHANNELS`]: Permissions::MANAGE_CHANNELS
+    pub async fn update_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<UpdateChannel<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        self.check_channel(&permissions, channel_id, Permissions::MANAGE_CHANNELS)
+            .await?;
 
-        Ok(self.http.create_guild_channel(self.guild_id, name)?)
+        Ok(self.http.update_channel(channel_id))
     }
 
-    /// Update a channel's permission overwrite.
+    /// Update a channel's permission overwrite, for example to lock a channel.
     ///
     /// This method ensures that the bot has the [`MANAGE_ROLES`] and
-    /// [`MANAGE_CHANNELS`] permission.
+    /// [`MANAGE_CHANNELS`] permission. The guild permissions are used since
+    /// this request may be used to restore the bot access to a channel.
     ///
     /// [`MANAGE_ROLES`]: Permissions::MANAGE_ROLES
     /// [`MANAGE_CHANNELS`]: Permissions::MANAGE_CHANNELS
     pub async fn update_channel_permission(
-        &'a self,
+        &self,
         channel_id: Id<ChannelMarker>,
         permission_overwrite: &'a PermissionOverwrite,
-    ) -> Result<UpdateChannelPermission<'a>, anyhow::Error> {
-        let permissions = self
-            .redis
-            .permissions(self.guild_id)
-            .await?
-            .current_member()
-            .await?
-            .guild();
-
-        if !permissions.contains(Permissions::MANAGE_ROLES | Permissions::MANAGE_CHANNELS) {
-            return Err(anyhow!("missing permissions to update channel permissions"));
-        }
+    ) -> Result<UpdateChannelPermission<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(
+            &permissions,
+            Permissions::MANAGE_ROLES | Permissions::MANAGE_CHANNELS,
+        )?;
 
         Ok(self
             .http
             .update_channel_permission(channel_id, permission_overwrite))
     }
 
+    /// Create a webhook in a channel.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_WEBHOOKS`] permission
+    /// in the channel.
+    ///
+    /// [`MANAGE_WEBHOOKS`]: Permissions::MANAGE_WEBHOOKS
+    pub async fn create_webhook(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        name: &'a str,
+    ) -> Result<CreateWebhook<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        self.check_channel(&permissions, channel_id, Permissions::MANAGE_WEBHOOKS)
+            .await?;
+
+        self.http
+            .create_webhook(channel_id, name)
+            .map_err(|error| CacheHttpError::Other(error.into()))
+    }
+
+    /// Get the audit log of the guild.
+    ///
+    /// This method ensures that the bot has the [`VIEW_AUDIT_LOG`] permission.
+    ///
+    /// [`VIEW_AUDIT_LOG`]: Permissions::VIEW_AUDIT_LOG
+    pub async fn audit_log(&self) -> Result<GetAuditLog<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::VIEW_AUDIT_LOG)?;
+
+        Ok(self.http.audit_log(self.guild_id))
+    }
+
+    /// Create a new role.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_ROLES`] permission.
+    ///
+    /// [`MANAGE_ROLES`]: Permissions::MANAGE_ROLES
+    pub async fn create_role(&self) -> Result<CreateRole<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_ROLES)?;
+
+        Ok(self.http.create_role(self.guild_id))
+    }
+
+    /// Update a role.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_ROLES`] permission and
+    /// the role to update is lower than the bot's highest role.
+    ///
+    /// [`MANAGE_ROLES`]: Permissions::MANAGE_ROLES
+    pub async fn update_role(
+        &self,
+        role_id: Id<RoleMarker>,
+    ) -> Result<UpdateRole<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_ROLES)?;
+        self.check_role_hierarchy(&permissions, role_id).await?;
+
+        Ok(self.http.update_role(self.guild_id, role_id))
+    }
+
     /// Add a role to a member.
     ///
     /// This method ensures that the bot has the [`MANAGE_ROLES`] permission and
@@ -139,47 +244,170 @@ impl<'a> CacheHttp<'a> {
     ///
     /// [`MANAGE_ROLES`]: Permissions::MANAGE_ROLES
     pub async fn add_guild_member_role(
-        &'a self,
+        &self,
         user_id: Id<UserMarker>,
         role_id: Id<RoleMarker>,
-    ) -> Result<AddRoleToMember<'a>, anyhow::Error> {
-        let permissions = self
-            .redis
-            .permissions(self.guild_id)
-            .await?
-            .current_member()
-            .await?;
+    ) -> Result<AddRoleToMember<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_ROLES)?;
+        self.check_role_hierarchy(&permissions, role_id).await?;
 
-        if !permissions.guild().contains(Permissions::MANAGE_ROLES) {
-            return Err(anyhow!("missing permissions to add role to member"));
-        }
+        Ok(self
+            .http
+            .add_guild_member_role(self.guild_id, user_id, role_id))
+    }
 
-        let role = match self.redis.get::<CachedRole>(&role_id).await? {
-            Some(role) => role,
-            None => return Err(anyhow!("role to add not found")),
+    /// Remove a role from a member.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_ROLES`] permission and
+    /// the role to remove is lower than the bot's highest role.
+    ///
+    /// [`MANAGE_ROLES`]: Permissions::MANAGE_ROLES
+    pub async fn remove_guild_member_role(
+        &self,
+        user_id: Id<UserMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<RemoveRoleFromMember<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_ROLES)?;
+        self.check_role_hierarchy(&permissions, role_id).await?;
+
+        Ok(self
+            .http
+            .remove_guild_member_role(self.guild_id, user_id, role_id))
+    }
+
+    /// Replace the roles of a member.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_ROLES`] permission and
+    /// the roles added or removed are lower than the bot's highest role. Roles
+    /// kept unchanged are not checked.
+    ///
+    /// [`MANAGE_ROLES`]: Permissions::MANAGE_ROLES
+    pub async fn update_guild_member_roles(
+        &self,
+        user_id: Id<UserMarker>,
+        roles: &'a [Id<RoleMarker>],
+    ) -> Result<UpdateGuildMember<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_ROLES)?;
+
+        let current = match self.member_roles(user_id).await? {
+            Some(current) => current,
+            None => return Err(CacheMiss::Member(user_id).into()),
         };
 
-        if RoleOrdering::from(&role) >= permissions.highest_role() {
-            return Err(anyhow!(
-                "role to add role is higher than bot's highest role"
-            ));
+        for role_id in changed_roles(&current, roles) {
+            self.check_role_hierarchy(&permissions, role_id).await?;
         }
 
         Ok(self
             .http
-            .add_guild_member_role(self.guild_id, user_id, role_id))
+            .update_guild_member(self.guild_id, user_id)
+            .roles(roles))
+    }
+
+    /// Update the nickname of a member.
+    ///
+    /// This method ensures that the bot has the [`MANAGE_NICKNAMES`] permission
+    /// and the member is lower than the bot in the role hierarchy.
+    ///
+    /// [`MANAGE_NICKNAMES`]: Permissions::MANAGE_NICKNAMES
+    pub async fn update_guild_member_nick(
+        &self,
+        user_id: Id<UserMarker>,
+        nick: Option<&'a str>,
+    ) -> Result<UpdateGuildMember<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MANAGE_NICKNAMES)?;
+        self.check_member_hierarchy(&permissions, user_id).await?;
+
+        self.http
+            .update_guild_member(self.guild_id, user_id)
+            .nick(nick)
+            .map_err(|error| CacheHttpError::Other(error.into()))
+    }
+
+    /// Timeout a member, or remove its timeout if `until` is [`None`].
+    ///
+    /// This method ensures that the bot has the [`MODERATE_MEMBERS`] permission
+    /// and the member is lower than the bot in the role hierarchy.
+    ///
+    /// [`MODERATE_MEMBERS`]: Permissions::MODERATE_MEMBERS
+    pub async fn timeout_guild_member(
+        &self,
+        user_id: Id<UserMarker>,
+        until: Option<Timestamp>,
+    ) -> Result<UpdateGuildMember<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::MODERATE_MEMBERS)?;
+        self.check_member_hierarchy(&permissions, user_id).await?;
+
+        self.http
+            .update_guild_member(self.guild_id, user_id)
+            .communication_disabled_until(until)
+            .map_err(|error| CacheHttpError::Other(error.into()))
     }
 
     /// Kick a user from a guild.
     ///
-    /// This method ensures that the bot has the [`KICK_MEMBERS`] permission. It
-    /// does not check for the role hierarchy.
+    /// This method ensures that the bot has the [`KICK_MEMBERS`] permission and
+    /// the member is lower than the bot in the role hierarchy. The removal is
+    /// recorded so that it is not counted by the anti-nuke protection.
     ///
     /// [`KICK_MEMBERS`]: Permissions::KICK_MEMBERS
     pub async fn remove_guild_member(
-        &'a self,
+        &self,
         user_id: Id<UserMarker>,
-    ) -> Result<RemoveMember<'a>, anyhow::Error> {
+    ) -> Result<RemoveMember<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::KICK_MEMBERS)?;
+        self.check_member_hierarchy(&permissions, user_id).await?;
+        self.redis.push_bot_removal(self.guild_id, user_id).await?;
+
+        Ok(self.http.remove_guild_member(self.guild_id, user_id))
+    }
+
+    /// Ban a user from the guild.
+    ///
+    /// This method ensures that the bot has the [`BAN_MEMBERS`] permission and,
+    /// if the user is a member of the guild, that it is lower than the bot in
+    /// the role hierarchy. The removal is recorded so that it is not counted by
+    /// the anti-nuke protection.
+    ///
+    /// [`BAN_MEMBERS`]: Permissions::BAN_MEMBERS
+    pub async fn create_ban(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<CreateBan<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::BAN_MEMBERS)?;
+        if let Some(roles) = self.member_roles(user_id).await? {
+            self.check_roles_hierarchy(&permissions, user_id, &roles)
+                .await?;
+        }
+        self.redis.push_bot_removal(self.guild_id, user_id).await?;
+
+        Ok(self.http.create_ban(self.guild_id, user_id))
+    }
+
+    /// Unban a user from the guild.
+    ///
+    /// This method ensures that the bot has the [`BAN_MEMBERS`] permission.
+    ///
+    /// [`BAN_MEMBERS`]: Permissions::BAN_MEMBERS
+    pub async fn delete_ban(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<DeleteBan<'a>, CacheHttpError> {
+        let permissions = self.current_member().await?;
+        check_guild(&permissions, Permissions::BAN_MEMBERS)?;
+
+        Ok(self.http.delete_ban(self.guild_id, user_id))
+    }
+
+    /// Get the permissions of the bot in the guild.
+    async fn current_member(&self) -> Result<CachePermissions<'a>, CacheHttpError> {
         let permissions = self
             .redis
             .permissions(self.guild_id)
@@ -187,10 +415,296 @@ impl<'a> CacheHttp<'a> {
             .current_member()
             .await?;
 
-        if !permissions.guild().contains(Permissions::KICK_MEMBERS) {
-            return Err(anyhow!("missing permissions to kick member"));
+        Ok(permissions)
+    }
+
+    /// Ensure the bot has the required permissions in a channel.
+    async fn check_channel(
+        &self,
+        permissions: &CachePermissions<'a>,
+        channel_id: Id<ChannelMarker>,
+        required: Permissions,
+    ) -> Result<(), CacheHttpError> {
+        let (permissions, _) = permissions.channel(channel_id).await?;
+
+        check_permissions(permissions, required, Some(channel_id))
+    }
+
+    /// Ensure a role is lower than the bot's highest role.
+    async fn check_role_hierarchy(
+        &self,
+        permissions: &CachePermissions<'a>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<(), CacheHttpError> {
+        let role = match self.redis.get::<CachedRole>(&role_id).await? {
+            Some(role) => role,
+            None => return Err(CacheMiss::Role(role_id).into()),
+        };
+
+        check_role(permissions.resolved(), &role)
+    }
+
+    /// Ensure a member is lower than the bot in the role hierarchy.
+    ///
+    /// A [`CacheMiss::Member`] error is returned if the user is not a member
+    /// of the guild.
+    async fn check_member_hierarchy(
+        &self,
+        permissions: &CachePermissions<'a>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), CacheHttpError> {
+        match self.member_roles(user_id).await? {
+            Some(roles) => {
+                self.check_roles_hierarchy(permissions, user_id, &roles)
+                    .await
+            }
+            None => Err(CacheMiss::Member(user_id).into()),
         }
+    }
 
-        Ok(self.http.remove_guild_member(self.guild_id, user_id))
+    /// Ensure a member with the given roles is lower than the bot in the role
+    /// hierarchy.
+    async fn check_roles_hierarchy(
+        &self,
+        permissions: &CachePermissions<'a>,
+        user_id: Id<UserMarker>,
+        roles: &[Id<RoleMarker>],
+    ) -> Result<(), CacheHttpError> {
+        let target = self
+            .redis
+            .permissions(self.guild_id)
+            .await?
+            .member(user_id, roles)
+            .await?;
+
+        check_member(permissions.resolved(), target.resolved())
+    }
+
+    /// Get the roles of a member of the guild.
+    ///
+    /// The member is fetched from the HTTP API if it is not cached, and
+    /// [`None`] is returned if the user is not a member of the guild.
+    async fn member_roles(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<Vec<Id<RoleMarker>>>, CacheHttpError> {
+        if let Some(member) = self.redis.member(self.guild_id, user_id).await? {
+            return Ok(Some(member.roles));
+        }
+
+        let response = match self.http.guild_member(self.guild_id, user_id).exec().await {
+            Ok(response) => response,
+            Err(error) => match error.kind() {
+                ErrorType::Response { status, .. } if status.get() == 404 => return Ok(None),
+                _ => return Err(CacheHttpError::Other(error.into())),
+            },
+        };
+
+        let member = response
+            .model()
+            .await
+            .map_err(|error| CacheHttpError::Other(error.into()))?;
+
+        Ok(Some(member.roles))
+    }
+}
+
+/// Ensure the bot has the required guild permissions.
+fn check_guild(
+    permissions: &CachePermissions<'_>,
+    required: Permissions,
+) -> Result<(), CacheHttpError> {
+    check_permissions(permissions.guild(), required, None)
+}
+
+/// Get the permissions required to send a message in a channel.
+fn send_message_permissions(kind: ChannelType) -> Permissions {
+    let send_messages = if kind.is_thread() {
+        Permissions::SEND_MESSAGES_IN_THREADS
+    } else {
+        Permissions::SEND_MESSAGES
+    };
+
+    Permissions::VIEW_CHANNEL
+        | send_messages
+        | Permissions::USE_EXTERNAL_EMOJIS
+        | Permissions::EMBED_LINKS
+}
+
+/// Ensure the bot permissions contain the required ones.
+fn check_permissions(
+    permissions: Permissions,
+    required: Permissions,
+    channel: Option<Id<ChannelMarker>>,
+) -> Result<(), CacheHttpError> {
+    if !permissions.contains(required) {
+        return Err(CacheHttpError::missing_permissions(
+            required,
+            permissions,
+            channel,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Ensure a role is lower than the bot's highest role.
+fn check_role(bot: &MemberPermissions, role: &CachedRole) -> Result<(), CacheHttpError> {
+    if !bot.is_owner() && RoleOrdering::from(role) >= bot.highest_role() {
+        return Err(CacheHttpError::RoleHierarchy { role: role.id });
+    }
+
+    Ok(())
+}
+
+/// Get the roles added or removed when replacing the roles of a member.
+fn changed_roles(current: &[Id<RoleMarker>], roles: &[Id<RoleMarker>]) -> Vec<Id<RoleMarker>> {
+    let added = roles.iter().filter(|role| !current.contains(role));
+    let removed = current.iter().filter(|role| !roles.contains(role));
+
+    added.chain(removed).copied().collect()
+}
+
+/// Ensure a member is lower than the bot in the role hierarchy.
+fn check_member(bot: &MemberPermissions, target: &MemberPermissions) -> Result<(), CacheHttpError> {
+    if target.is_owner() || (!bot.is_owner() && target.highest_role() >= bot.highest_role()) {
+        return Err(CacheHttpError::MemberHierarchy {
+            user: target.member_id(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{fixtures::*, model::CachedChannel};
+
+    /// Check the permissions of the bot to send a message in a channel.
+    fn check_send_message(
+        channel: &CachedChannel,
+        parent: Option<&CachedChannel>,
+    ) -> Result<(), CacheHttpError> {
+        let permissions = bot().channel(channel, parent);
+
+        check_permissions(
+            permissions,
+            send_message_permissions(channel.kind),
+            Some(channel.id),
+        )
+    }
+
+    #[test]
+    fn test_send_message_permissions() {
+        let channel = send_message_permissions(ChannelType::GuildText);
+        let thread = send_message_permissions(ChannelType::GuildPublicThread);
+
+        assert!(channel.contains(Permissions::SEND_MESSAGES));
+        assert!(!channel.contains(Permissions::SEND_MESSAGES_IN_THREADS));
+        assert!(thread.contains(Permissions::SEND_MESSAGES_IN_THREADS));
+        assert!(!thread.contains(Permissions::SEND_MESSAGES));
+    }
+
+    #[test]
+    fn test_create_message() {
+        assert!(check_send_message(&text_channel(), None).is_ok());
+    }
+
+    #[test]
+    fn test_create_message_locked_channel() {
+        let error = check_send_message(&locked_channel(), None).unwrap_err();
+
+        assert!(matches!(
+            error,
+            CacheHttpError::MissingPermissions { missing, channel, .. }
+                if missing == Permissions::SEND_MESSAGES && channel == Some(LOCKED_CHANNEL)
+        ));
+    }
+
+    #[test]
+    fn test_create_message_thread() {
+        let parent = locked_channel();
+        let thread = thread(
+            PUBLIC_THREAD,
+            ChannelType::GuildPublicThread,
+            &parent,
+            false,
+        );
+
+        assert!(check_send_message(&thread, Some(&parent)).is_ok());
+    }
+
+    #[test]
+    fn test_create_message_private_thread() {
+        let parent = text_channel();
+        let joined = thread(
+            PRIVATE_THREAD,
+            ChannelType::GuildPrivateThread,
+            &parent,
+            true,
+        );
+        let not_joined = thread(
+            PRIVATE_THREAD,
+            ChannelType::GuildPrivateThread,
+            &parent,
+            false,
+        );
+
+        assert!(check_send_message(&joined, Some(&parent)).is_ok());
+        assert!(check_send_message(&not_joined, Some(&parent)).is_err());
+    }
+
+    #[test]
+    fn test_missing_guild_permissions() {
+        let required = Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS;
+        let error = check_permissions(bot().guild(), required, None).unwrap_err();
+
+        assert!(matches!(
+            error,
+            CacheHttpError::MissingPermissions { missing, channel: None, .. }
+                if missing == Permissions::BAN_MEMBERS
+        ));
+    }
+
+    #[test]
+    fn test_role_hierarchy() {
+        assert!(check_role(&bot(), &member_role()).is_ok());
+        assert!(matches!(
+            check_role(&bot(), &bot_role()),
+            Err(CacheHttpError::RoleHierarchy { role }) if role == BOT_ROLE
+        ));
+        assert!(matches!(
+            check_role(&bot(), &moderator_role()),
+            Err(CacheHttpError::RoleHierarchy { role }) if role == MODERATOR_ROLE
+        ));
+    }
+
+    #[test]
+    fn test_changed_roles() {
+        let current = [MEMBER_ROLE, MODERATOR_ROLE];
+
+        assert!(changed_roles(&current, &current).is_empty());
+        assert_eq!(
+            changed_roles(&current, &[MODERATOR_ROLE, BOT_ROLE]),
+            vec![BOT_ROLE, MEMBER_ROLE]
+        );
+    }
+
+    #[test]
+    fn test_member_hierarchy() {
+        let regular = member(MEMBER_ID, vec![member_role()]);
+        let moderator = member(MODERATOR_ID, vec![moderator_role()]);
+        let owner = member(OWNER_ID, Vec::new());
+
+        assert!(check_member(&bot(), &regular).is_ok());
+        assert!(matches!(
+            check_member(&bot(), &moderator),
+            Err(CacheHttpError::MemberHierarchy { user }) if user == MODERATOR_ID
+        ));
+        assert!(matches!(
+            check_member(&bot(), &owner),
+            Err(CacheHttpError::MemberHierarchy { user }) if user == OWNER_ID
+        ));
     }
 }
@@ -8,7 +8,8 @@
 //! The cache can be queried using [`RedisClient`]. Higher-level interfaces are
 //! also provided to use the cache data: the [`permission`] allow to compute
 //! permissions for a user using cached data, and [`http`] allow to perform
-//! permission checks before http requests.
+//! permission checks before http requests. Failed checks are reported with
+//! [`error::CacheHttpError`].
 //!
 //! ## Event processing
 //! Incoming Discord events that implement [`UpdateCache`] are processed to
@@ -16,15 +17,20 @@
 //!
 //! The following events are used to update the cache:
 //!
-//! | Cached data           | Event types                                                       |
-//! |-----------------------|-------------------------------------------------------------------|
-//! | Guilds                | `GuildCreate`, `GuildUpdate`, `GuildDelete`, `UnavailableGuild`   |
-//! | Channels (guild-only) | `ChannelCreate`, `ChannelUpdate`, `ChannelUpdate` (+ thread ones) |
-//! | Roles                 | `RoleCreate`, `RoleUpdate`, `RoleDelete`                          |
-//! | Current user member   | `MemberAdd`, `MemberUpdate`                                       |
+//! | Cached data           | Event types                                                               |
+//! |-----------------------|---------------------------------------------------------------------------|
+//! | Guilds                | `GuildCreate`, `GuildUpdate`, `GuildDelete`, `UnavailableGuild`           |
+//! | Channels (guild-only) | `ChannelCreate`, `ChannelUpdate`, `ChannelUpdate` (+ thread ones)         |
+//! | Thread membership     | `GuildCreate`, `ThreadCreate`, `ThreadUpdate`, `ThreadMembersUpdate`      |
+//! | Roles                 | `RoleCreate`, `RoleUpdate`, `RoleDelete`                                  |
+//! | Current user member   | `MemberAdd`, `MemberUpdate`                                               |
+//! | Members               | `GuildCreate`, `MemberAdd`, `MemberUpdate`, `MemberRemove`, `MemberChunk` |
 
+#[cfg(test)]
+mod fixtures;
 mod process;
 
+pub mod error;
 pub mod http;
 pub mod model;
 pub mod permission;
@@ -0,0 +1,197 @@
+//! State for the anti-nuke protection.
+
+use time::OffsetDateTime;
+use tracing::{instrument, trace};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::cache::RedisClient;
+
+/// Kind of destructive action tracked by the anti-nuke protection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DestructiveAction {
+    /// A channel has been deleted.
+    ChannelDelete,
+    /// A role has been deleted.
+    RoleDelete,
+    /// A member has been banned.
+    Ban,
+    /// A member has been kicked.
+    ///
+    /// Kicks cannot be distinguished from members leaving the guild without
+    /// the audit log, so all member removals are counted, except those
+    /// performed by the bot.
+    Kick,
+    /// A webhook has been created.
+    WebhookCreate,
+}
+
+impl DestructiveAction {
+    /// Name of the action, used in cache keys.
+    pub fn name(self) -> &'static str {
+        match self {
+            DestructiveAction::ChannelDelete => "channel_delete",
+            DestructiveAction::RoleDelete => "role_delete",
+            DestructiveAction::Ban => "ban",
+            DestructiveAction::Kick => "kick",
+            DestructiveAction::WebhookCreate => "webhook_create",
+        }
+    }
+
+    /// Get the key of the sorted set storing the recent actions of a guild.
+    fn key(self, guild_id: Id<GuildMarker>) -> String {
+        format!("antinuke:{guild_id}:{}", self.name())
+    }
+}
+
+/// Duration during which the audit log is not fetched again for an action
+/// kind, in seconds.
+const CHECK_COOLDOWN: usize = 10;
+
+/// Duration during which a member removed by the bot is not counted as a
+/// destructive action, in seconds.
+const BOT_REMOVAL_EXPIRES_AFTER: usize = 60;
+
+// Implementation of methods to query the cache.
+impl RedisClient {
+    /// Add an action to the recent destructive actions of a guild.
+    ///
+    /// The `target` is the id of the resource affected by the action. Actions
+    /// older than `window` seconds are removed, and the number of actions
+    /// within the window, including the new one, is returned.
+    #[instrument(skip(self))]
+    pub async fn push_destructive_action(
+        &self,
+        guild_id: Id<GuildMarker>,
+        action: DestructiveAction,
+        target: u64,
+        at: OffsetDateTime,
+        window: u32,
+    ) -> Result<u64, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = action.key(guild_id);
+
+        let timestamp = (at.unix_timestamp_nanos() / 1_000_000) as i64;
+        let since = timestamp - i64::from(window) * 1000;
+
+        trace!(
+            "adding {} action on {} to recent actions of guild {}",
+            action.name(),
+            target,
+            guild_id
+        );
+        let (count,): (u64,) = redis::pipe()
+            .zadd(&key, format!("{target}:{timestamp}"), timestamp)
+            .ignore()
+            .zrembyscore(&key, "-inf", since)
+            .ignore()
+            .expire(&key, window as usize)
+            .ignore()
+            .zcard(&key)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Acquire a lock for the anti-nuke protection.
+    ///
+    /// Returns `false` if the lock is already held. The lock is released after
+    /// `expires_after` seconds.
+    async fn antinuke_lock(
+        &self,
+        key: String,
+        expires_after: usize,
+    ) -> Result<bool, anyhow::Error> {
+        let mut conn = self.conn().await?;
+
+        let acquired: Option<String> = redis::cmd("SET")
+            .arg(&key)
+            .arg(1)
+            .arg("NX")
+            .arg("EX")
+            .arg(expires_after)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(acquired.is_some())
+    }
+
+    /// Acquire the lock to fetch the audit log for an action kind.
+    ///
+    /// This ensures the audit log is fetched at most once every
+    /// [`CHECK_COOLDOWN`] seconds for each action kind, since member removals
+    /// can be frequent in large guilds.
+    #[instrument(skip(self))]
+    pub async fn lock_antinuke_check(
+        &self,
+        guild_id: Id<GuildMarker>,
+        action: DestructiveAction,
+    ) -> Result<bool, anyhow::Error> {
+        let key = format!("antinuke:check:{guild_id}:{}", action.name());
+
+        self.antinuke_lock(key, CHECK_COOLDOWN).await
+    }
+
+    /// Record a member removed (kicked or banned) by the bot.
+    ///
+    /// Removals performed by the bot are not counted as destructive actions.
+    /// This must be called before the member is removed, as the removal
+    /// events may be received before the request completes.
+    #[instrument(skip(self))]
+    pub async fn push_bot_removal(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = format!("antinuke:removal:{guild_id}:{user_id}");
+
+        redis::cmd("SET")
+            .arg(&key)
+            .arg(1)
+            .arg("EX")
+            .arg(BOT_REMOVAL_EXPIRES_AFTER)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Whether a member has been recently removed by the bot.
+    #[instrument(skip(self))]
+    pub async fn is_bot_removal(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<bool, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = format!("antinuke:removal:{guild_id}:{user_id}");
+
+        let exists: bool = redis::cmd("EXISTS")
+            .arg(&key)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(exists)
+    }
+
+    /// Acquire the lock to act on a member responsible for destructive
+    /// actions.
+    ///
+    /// This ensures a member is handled only once when multiple events are
+    /// received for the same actions.
+    #[instrument(skip(self))]
+    pub async fn lock_antinuke_actor(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        expires_after: u32,
+    ) -> Result<bool, anyhow::Error> {
+        let key = format!("antinuke:actor:{guild_id}:{user_id}");
+
+        self.antinuke_lock(key, expires_after as usize).await
+    }
+}
@@ -0,0 +1,300 @@
+//! State for the auto-moderation module.
+
+use redis::AsyncCommands;
+use tracing::{instrument, trace};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+use crate::{cache::RedisClient, database::model::RuleMode};
+
+/// Hit counters of an auto-moderation rule.
+///
+/// The counters are stored in a Redis hash for each guild, with a field for
+/// each rule and mode. They are used to tune rules before enforcing them.
+///
+/// Rules are identified by [`AutomodRuleKind::name`] for built-in rules and
+/// [`RegexFilter::id`] for regex filters.
+///
+/// [`AutomodRuleKind::name`]: crate::database::model::AutomodRuleKind::name
+/// [`RegexFilter::id`]: crate::database::model::RegexFilter::id
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleHits {
+    /// Number of messages that matched the rule in [`RuleMode::Monitor`].
+    pub monitored: u64,
+    /// Number of messages that matched the rule in [`RuleMode::Enforce`].
+    pub enforced: u64,
+}
+
+impl RuleHits {
+    /// Get the key of the hash storing the counters of a guild.
+    fn key(guild_id: Id<GuildMarker>) -> String {
+        format!("automod:hits:{guild_id}")
+    }
+
+    /// Get the hash field of a rule counter.
+    fn field(rule: &str, mode: RuleMode) -> String {
+        let mode = match mode {
+            RuleMode::Disabled => "disabled",
+            RuleMode::Monitor => "monitor",
+            RuleMode::Enforce => "enforce",
+        };
+
+        format!("{rule}:{mode}")
+    }
+}
+
+/// Copies of a message posted in a guild.
+///
+/// Copies are identified by a fingerprint of the message content, and are
+/// stored in a short-lived Redis set for each [`DuplicateScope`] and
+/// fingerprint. They are used to detect the same message posted in multiple
+/// channels, or by multiple accounts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DuplicateMessages {
+    /// List of the copies.
+    pub copies: Vec<MessageCopy>,
+    /// Whether the copies have already been detected as duplicates.
+    pub flagged: bool,
+}
+
+/// Copy of a message stored in [`DuplicateMessages`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageCopy {
+    /// Author of the message.
+    pub author_id: Id<UserMarker>,
+    /// Channel where the message was sent.
+    pub channel_id: Id<ChannelMarker>,
+    /// ID of the message.
+    pub message_id: Id<MessageMarker>,
+}
+
+/// Scope of the copies stored in [`DuplicateMessages`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateScope {
+    /// Copies posted by a single author.
+    Author(Id<UserMarker>),
+    /// Copies posted by all the members of the guild.
+    Guild,
+}
+
+impl DuplicateMessages {
+    /// Member of the set marking the copies as already detected.
+    const FLAGGED: &'static str = "flagged";
+
+    /// Get the key of the set storing the copies of a message.
+    fn key(guild_id: Id<GuildMarker>, scope: DuplicateScope, fingerprint: u64) -> String {
+        match scope {
+            DuplicateScope::Author(author_id) => {
+                format!("automod:dup:{guild_id}:{author_id}:{fingerprint:x}")
+            }
+            DuplicateScope::Guild => format!("automod:dup:{guild_id}:all:{fingerprint:x}"),
+        }
+    }
+
+    /// Get the set member of a copy.
+    fn member(copy: &MessageCopy) -> String {
+        format!("{}:{}:{}", copy.author_id, copy.channel_id, copy.message_id)
+    }
+
+    /// Parse the members of the set.
+    fn from_members(members: Vec<String>) -> Self {
+        let mut duplicates = Self::default();
+
+        for member in members {
+            if member == Self::FLAGGED {
+                duplicates.flagged = true;
+                continue;
+            }
+
+            let mut ids = member
+                .split(':')
+                .map(|id| id.parse().ok().and_then(Id::<UserMarker>::new_checked));
+
+            let copy = match (ids.next(), ids.next(), ids.next(), ids.next()) {
+                (Some(Some(author)), Some(Some(channel)), Some(Some(message)), None) => {
+                    MessageCopy {
+                        author_id: author,
+                        channel_id: channel.cast(),
+                        message_id: message.cast(),
+                    }
+                }
+                _ => continue,
+            };
+
+            duplicates.copies.push(copy);
+        }
+
+        duplicates
+    }
+}
+
+// Implementation of methods to query the cache.
+impl RedisClient {
+    /// Increment the hit counter of an auto-moderation rule.
+    ///
+    /// The updated value of the counter is returned.
+    #[instrument(skip(self))]
+    pub async fn incr_automod_hits(
+        &self,
+        guild_id: Id<GuildMarker>,
+        rule: &str,
+        mode: RuleMode,
+    ) -> Result<u64, anyhow::Error> {
+        let mut conn = self.conn().await?;
+
+        trace!("incrementing automod hits for guild {}", guild_id);
+        let hits = conn
+            .hincr(RuleHits::key(guild_id), RuleHits::field(rule, mode), 1_u64)
+            .await?;
+
+        Ok(hits)
+    }
+
+    /// Get the hit counters of an auto-moderation rule.
+    #[instrument(skip(self))]
+    pub async fn automod_hits(
+        &self,
+        guild_id: Id<GuildMarker>,
+        rule: &str,
+    ) -> Result<RuleHits, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let mut pipe = redis::pipe();
+
+        pipe.hget(
+            RuleHits::key(guild_id),
+            RuleHits::field(rule, RuleMode::Monitor),
+        )
+        .hget(
+            RuleHits::key(guild_id),
+            RuleHits::field(rule, RuleMode::Enforce),
+        );
+
+        let (monitored, enforced): (Option<u64>, Option<u64>) =
+            pipe.query_async(&mut *conn).await?;
+
+        Ok(RuleHits {
+            monitored: monitored.unwrap_or_default(),
+            enforced: enforced.unwrap_or_default(),
+        })
+    }
+
+    /// Reset the hit counters of an auto-moderation rule.
+    #[instrument(skip(self))]
+    pub async fn reset_automod_hits(
+        &self,
+        guild_id: Id<GuildMarker>,
+        rule: &str,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+
+        conn.hdel(
+            RuleHits::key(guild_id),
+            &[
+                RuleHits::field(rule, RuleMode::Monitor),
+                RuleHits::field(rule, RuleMode::Enforce),
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Add a copy of a message to the copies with the same fingerprint.
+    ///
+    /// The copies expire after `expires_after` seconds without a new copy.
+    /// All the stored copies, including the new one, are returned.
+    #[instrument(skip(self))]
+    pub async fn push_duplicate(
+        &self,
+        guild_id: Id<GuildMarker>,
+        scope: DuplicateScope,
+        fingerprint: u64,
+        copy: MessageCopy,
+        expires_after: usize,
+    ) -> Result<DuplicateMessages, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = DuplicateMessages::key(guild_id, scope, fingerprint);
+
+        trace!(
+            "adding message {} to duplicates of {}",
+            copy.message_id,
+            key
+        );
+        let (members,): (Vec<String>,) = redis::pipe()
+            .sadd(&key, DuplicateMessages::member(&copy))
+            .ignore()
+            .expire(&key, expires_after)
+            .ignore()
+            .smembers(&key)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(DuplicateMessages::from_members(members))
+    }
+
+    /// Mark copies of a message as detected.
+    ///
+    /// The given copies are removed from the stored copies, so they are not
+    /// returned again by [`RedisClient::push_duplicate`].
+    #[instrument(skip(self))]
+    pub async fn flag_duplicates(
+        &self,
+        guild_id: Id<GuildMarker>,
+        scope: DuplicateScope,
+        fingerprint: u64,
+        copies: &[MessageCopy],
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = DuplicateMessages::key(guild_id, scope, fingerprint);
+        let mut pipe = redis::pipe();
+
+        for copy in copies {
+            pipe.srem(&key, DuplicateMessages::member(copy)).ignore();
+        }
+
+        pipe.sadd(&key, DuplicateMessages::FLAGGED)
+            .ignore()
+            .query_async::<_, ()>(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicates_from_members() {
+        let members = vec![
+            "1:2:3".to_string(),
+            DuplicateMessages::FLAGGED.to_string(),
+            "4:5:6".to_string(),
+            "invalid".to_string(),
+            "1:2".to_string(),
+            "0:5:6".to_string(),
+        ];
+
+        assert_eq!(
+            DuplicateMessages::from_members(members),
+            DuplicateMessages {
+                copies: vec![
+                    MessageCopy {
+                        author_id: Id::new(1),
+                        channel_id: Id::new(2),
+                        message_id: Id::new(3),
+                    },
+                    MessageCopy {
+                        author_id: Id::new(4),
+                        channel_id: Id::new(5),
+                        message_id: Id::new(6),
+                    },
+                ],
+                flagged: true,
+            }
+        );
+    }
+}
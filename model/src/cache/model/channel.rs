@@ -44,6 +44,11 @@ pub struct CachedChannel {
     pub position: Option<i32>,
     /// Amount of seconds a user has to wait between two message.
     pub rate_limit_per_user: Option<u16>,
+    /// Whether the bot is a member of the thread.
+    ///
+    /// This field is always `false` on non-thread channels.
+    #[serde(default)]
+    pub thread_member: bool,
 }
 
 impl CachedChannel {
@@ -116,3 +116,50 @@ impl RedisModel for PendingSanction {
         format!("pending:sanction:{id}")
     }
 }
+
+/// State for a pending mass action confirmation.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingMassAction {
+    /// Initial interaction ID.
+    #[serde_as(as = "IdAsU64")]
+    pub interaction_id: Id<InteractionMarker>,
+    /// Id of the guild.
+    #[serde_as(as = "IdAsU64")]
+    pub guild_id: Id<GuildMarker>,
+    /// Id of the moderator that issued the command.
+    #[serde_as(as = "IdAsU64")]
+    pub moderator_id: Id<UserMarker>,
+    /// Type of the action.
+    pub kind: MassActionKind,
+    /// Members targeted by the action.
+    #[serde_as(as = "Vec<IdAsU64>")]
+    pub members: Vec<Id<UserMarker>>,
+    /// Optional reason provided by the moderator.
+    pub reason: Option<String>,
+}
+
+/// Type of a [`PendingMassAction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MassActionKind {
+    /// Sanction the members (`/massban` and `/masskick`), either with
+    /// [`ModlogType::Kick`] or [`ModlogType::Ban`].
+    Sanction(ModlogType),
+    /// Apply the action of the name rules to the members (`/cleanup names`).
+    Names,
+}
+
+impl RedisModel for PendingMassAction {
+    type Id = str;
+
+    // Pending confirmations expires after 5 minutes
+    const EXPIRES_AFTER: Option<usize> = Some(5 * 60);
+
+    fn key(&self) -> String {
+        Self::key_from(&self.interaction_id.to_string())
+    }
+
+    fn key_from(id: &Self::Id) -> String {
+        format!("pending:mass-action:{id}")
+    }
+}
@@ -0,0 +1,265 @@
+//! Cached guild members.
+//!
+//! Members of a guild are stored in a single Redis hash, indexed by the member
+//! id. This allows to retrieve all the members of a guild at once, which is
+//! required to apply rules retroactively or to select members for mass
+//! moderation actions.
+
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use tracing::{instrument, trace, warn};
+use twilight_model::{
+    gateway::payload::incoming::MemberUpdate,
+    guild::Member,
+    id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+
+use crate::{
+    cache::RedisClient,
+    serde::{IdAsU64, TimestampAsI64},
+};
+
+/// Cached model of a [`Member`].
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CachedMember {
+    /// Id of the member.
+    #[serde_as(as = "IdAsU64")]
+    pub user_id: Id<UserMarker>,
+    /// Username of the member.
+    pub name: String,
+    /// Nickname of the member in the guild.
+    pub nick: Option<String>,
+    /// Whether the member is a bot.
+    pub bot: bool,
+    /// Roles of the member.
+    #[serde_as(as = "Vec<IdAsU64>")]
+    pub roles: Vec<Id<RoleMarker>>,
+    /// When the member joined the guild.
+    #[serde_as(as = "TimestampAsI64")]
+    pub joined_at: Timestamp,
+    /// When the member can resume communication in the guild.
+    ///
+    /// Checking if this value is [`Some`] is not enough, we should
+    /// also check that the given timestamp is not in the past.
+    #[serde_as(as = "Option<TimestampAsI64>")]
+    pub communication_disabled_until: Option<Timestamp>,
+    /// Whether the member has yet to pass the guild membership screening.
+    pub pending: bool,
+}
+
+impl CachedMember {
+    /// Get the key of the hash storing the members of a guild.
+    pub fn key_from(guild_id: Id<GuildMarker>) -> String {
+        format!("c:members:{guild_id}")
+    }
+}
+
+impl From<&Member> for CachedMember {
+    fn from(member: &Member) -> Self {
+        Self {
+            user_id: member.user.id,
+            name: member.user.name.clone(),
+            nick: member.nick.clone(),
+            bot: member.user.bot,
+            roles: member.roles.clone(),
+            joined_at: member.joined_at,
+            communication_disabled_until: member.communication_disabled_until,
+            pending: member.pending,
+        }
+    }
+}
+
+impl From<&MemberUpdate> for CachedMember {
+    fn from(member: &MemberUpdate) -> Self {
+        Self {
+            user_id: member.user.id,
+            name: member.user.name.clone(),
+            nick: member.nick.clone(),
+            bot: member.user.bot,
+            roles: member.roles.clone(),
+            joined_at: member.joined_at,
+            communication_disabled_until: member.communication_disabled_until,
+            pending: member.pending,
+        }
+    }
+}
+
+// Implementation of methods to query the cache.
+impl RedisClient {
+    /// Insert or update a guild member.
+    #[instrument(skip(self))]
+    pub async fn cache_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        member: &CachedMember,
+    ) -> Result<(), anyhow::Error> {
+        self.cache_members(guild_id, std::slice::from_ref(member))
+            .await
+    }
+
+    /// Insert or update multiple members of a guild.
+    #[instrument(skip_all)]
+    pub async fn cache_members(
+        &self,
+        guild_id: Id<GuildMarker>,
+        members: &[CachedMember],
+    ) -> Result<(), anyhow::Error> {
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = self.conn().await?;
+        let values = members
+            .iter()
+            .map(|member| Ok((member.user_id.get(), rmp_serde::to_vec_named(member)?)))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+        trace!("caching {} members in guild {}", members.len(), guild_id);
+        conn.hset_multiple::<_, _, _, ()>(CachedMember::key_from(guild_id), &values)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a guild member from the cache.
+    #[instrument(skip(self))]
+    pub async fn remove_member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+
+        trace!("removing member {} in guild {}", user_id, guild_id);
+        conn.hdel::<_, _, ()>(CachedMember::key_from(guild_id), user_id.get())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get a cached guild member.
+    #[instrument(skip(self))]
+    pub async fn member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<CachedMember>, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let value: Option<Vec<u8>> = conn
+            .hget(CachedMember::key_from(guild_id), user_id.get())
+            .await?;
+
+        match value {
+            Some(value) => Ok(Some(rmp_serde::from_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the cached members of a guild.
+    #[instrument(skip(self))]
+    pub async fn guild_members(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<CachedMember>, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let values: Vec<Vec<u8>> = conn.hvals(CachedMember::key_from(guild_id)).await?;
+
+        let members = values
+            .into_iter()
+            .filter_map(|value| match rmp_serde::from_slice(&value) {
+                Ok(member) => Some(member),
+                Err(error) => {
+                    warn!(error = ?error, "failed to deserialize cached member");
+                    None
+                }
+            })
+            .collect();
+
+        Ok(members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::user::User;
+
+    use super::*;
+
+    #[test]
+    fn test_cached_member_roundtrip() {
+        let member = CachedMember {
+            user_id: Id::new(1),
+            name: "user".to_string(),
+            nick: Some("nick".to_string()),
+            bot: false,
+            roles: vec![Id::new(2), Id::new(3)],
+            joined_at: Timestamp::from_secs(1_628_594_197).unwrap(),
+            communication_disabled_until: None,
+            pending: true,
+        };
+
+        let value = rmp_serde::to_vec_named(&member).unwrap();
+
+        assert_eq!(
+            rmp_serde::from_slice::<CachedMember>(&value).unwrap(),
+            member
+        );
+    }
+
+    #[test]
+    fn test_cached_member_from_update() {
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(1),
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_string(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+        let joined_at = Timestamp::from_secs(1_628_594_197).unwrap();
+
+        let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            guild_id: Id::new(2),
+            joined_at,
+            mute: false,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: vec![Id::new(3)],
+            user: user.clone(),
+        };
+        let update = MemberUpdate {
+            avatar: None,
+            communication_disabled_until: None,
+            guild_id: Id::new(2),
+            deaf: None,
+            joined_at,
+            mute: None,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: vec![Id::new(3)],
+            user,
+        };
+
+        assert_eq!(CachedMember::from(&member), CachedMember::from(&update));
+    }
+}
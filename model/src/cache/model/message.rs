@@ -1,11 +1,13 @@
 //! Messages cache models.
 
+use redis::AsyncCommands;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use tracing::{instrument, trace};
 use twilight_model::{
     channel::Attachment,
     id::{
-        marker::{ChannelMarker, MessageMarker, RoleMarker, UserMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
         Id,
     },
     util::Timestamp,
@@ -13,7 +15,8 @@ use twilight_model::{
 use url::Url;
 
 use crate::{
-    cache::RedisModel,
+    cache::{RedisClient, RedisModel},
+    database::model::MessagesConfig,
     serde::{IdAsU64, TimestampAsI64},
 };
 
@@ -46,6 +49,9 @@ pub struct CachedMessage {
     pub attachments: Vec<Attachment>,
     /// List of links included in the message.
     pub links: Vec<MessageLink>,
+    /// Statistics on the hidden content of the message.
+    #[serde(default)]
+    pub hidden: HiddenContent,
     /// Whether the message mentions everyone (@everyone or @here mentions)
     pub mention_everyone: bool,
     /// List of users mentioned in the message.
@@ -59,7 +65,8 @@ pub struct CachedMessage {
 impl RedisModel for CachedMessage {
     type Id = Id<MessageMarker>;
 
-    // Message expiration duration (2 minutes)
+    // Default message expiration duration (2 minutes), the duration is
+    // configured for each guild with `MessagesConfig::retention`.
     const EXPIRES_AFTER: Option<usize> = Some(2 * 60);
 
     fn key(&self) -> String {
@@ -71,6 +78,45 @@ impl RedisModel for CachedMessage {
     }
 }
 
+impl CachedMessage {
+    /// Maximum number of recent messages stored for each guild.
+    pub const RECENT_LEN: usize = 50;
+
+    /// Get the key of the list storing the recent messages of a guild.
+    fn recent_key(guild_id: Id<GuildMarker>) -> String {
+        format!("c:msg:recent:{guild_id}")
+    }
+}
+
+/// Statistics on the hidden content of a message.
+///
+/// Messages may include content that is not displayed by Discord, such as
+/// invisible characters or chains of empty spoilers (`||\u{200B}||`). These
+/// are commonly used to hide mentions or to bypass filters.
+///
+/// This type is used in [`CachedMessage`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HiddenContent {
+    /// Number of visible characters, excluding whitespaces.
+    pub visible: usize,
+    /// Number of invisible and control characters outside of spoilers.
+    ///
+    /// This includes zero-width characters, bidirectional text controls and
+    /// blank characters such as the Hangul filler.
+    pub invisible: usize,
+    /// Number of characters in empty spoilers, including the spoiler markers.
+    pub spoilers: usize,
+    /// Length of the longest chain of consecutive empty spoilers.
+    pub spoiler_chain: usize,
+}
+
+impl HiddenContent {
+    /// Total number of hidden characters.
+    pub fn hidden(&self) -> usize {
+        self.invisible + self.spoilers
+    }
+}
+
 /// Kind of message link.
 ///
 /// This type is used in [`CachedMessage`].
@@ -82,4 +128,95 @@ pub enum MessageLink {
     Media(Url),
     /// URL that does not belong to one of the previous categories
     Other(Url),
+    /// URL with a domain that imitates a protected domain
+    Phishing {
+        /// URL of the link.
+        url: Url,
+        /// Protected domain imitated by the link.
+        domain: String,
+    },
+    /// Markdown masked link (`[text](url)`)
+    Masked {
+        /// Text displayed instead of the link.
+        text: String,
+        /// Real target of the link.
+        target: Box<MessageLink>,
+    },
+}
+
+impl MessageLink {
+    /// Get the target of the link.
+    ///
+    /// Masked links return their real target, other links return themselves.
+    pub fn target(&self) -> &MessageLink {
+        match self {
+            MessageLink::Masked { target, .. } => target.target(),
+            link => link,
+        }
+    }
+
+    /// Get the URL of the link.
+    ///
+    /// For masked links, the URL of the real target is returned.
+    pub fn url(&self) -> &Url {
+        match self {
+            MessageLink::Invite(url) | MessageLink::Media(url) | MessageLink::Other(url) => url,
+            MessageLink::Phishing { url, .. } => url,
+            MessageLink::Masked { target, .. } => target.url(),
+        }
+    }
+}
+
+// Implementation of methods to query the cache.
+impl RedisClient {
+    /// Add a message to the recent messages of a guild.
+    ///
+    /// Only the ids of the last [`CachedMessage::RECENT_LEN`] messages are
+    /// kept, the messages themselves are stored with [`RedisClient::set`].
+    #[instrument(skip(self))]
+    pub async fn push_recent_message(
+        &self,
+        guild_id: Id<GuildMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = CachedMessage::recent_key(guild_id);
+
+        trace!(
+            "adding message {} to recent messages of guild {}",
+            message_id,
+            guild_id
+        );
+        redis::pipe()
+            .lpush(&key, message_id.get())
+            .ignore()
+            .ltrim(&key, 0, CachedMessage::RECENT_LEN as isize - 1)
+            .ignore()
+            .expire(&key, MessagesConfig::MAX_RETENTION as usize)
+            .ignore()
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the recent messages of a guild.
+    ///
+    /// Messages are sorted from the most recent, and messages that are no
+    /// longer cached are ignored.
+    #[instrument(skip(self))]
+    pub async fn recent_messages(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<CachedMessage>, anyhow::Error> {
+        let ids: Vec<u64> = {
+            let mut conn = self.conn().await?;
+            conn.lrange(CachedMessage::recent_key(guild_id), 0, -1)
+                .await?
+        };
+
+        let ids: Vec<Id<MessageMarker>> = ids.into_iter().filter_map(Id::new_checked).collect();
+
+        self.get_many(&ids).await
+    }
 }
@@ -9,11 +9,17 @@
 //! [`Serialize`]: serde::Serialize
 //! [`Deserialize`]: serde::Deserialize
 
+pub mod antinuke;
+pub mod automod;
 pub mod interaction;
 pub mod message;
+pub mod raid;
+pub mod risk;
 
 mod channel;
 mod guild;
+mod member;
 
 pub use channel::CachedChannel;
 pub use guild::{CachedGuild, CachedRole, CurrentMember};
+pub use member::CachedMember;
@@ -0,0 +1,323 @@
+//! State for the raid protection.
+
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use time::OffsetDateTime;
+use tracing::{instrument, trace};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{
+    cache::{RedisClient, RedisModel},
+    serde::{DateTimeAsI64, IdAsU64},
+};
+
+/// Raid mode of a guild.
+///
+/// Raid mode is stored in the cache to be shared by all the shards and kept
+/// after a restart. If raid mode is enabled for a limited duration, the cache
+/// entry expires at the end of the duration.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RaidMode {
+    /// Id of the guild.
+    #[serde_as(as = "IdAsU64")]
+    pub guild_id: Id<GuildMarker>,
+    /// Time at which raid mode was enabled.
+    #[serde_as(as = "DateTimeAsI64")]
+    pub enabled_at: OffsetDateTime,
+    /// Time at which raid mode expires.
+    #[serde_as(as = "Option<DateTimeAsI64>")]
+    pub expires_at: Option<OffsetDateTime>,
+    /// Moderator who enabled raid mode.
+    ///
+    /// [`None`] if raid mode was enabled automatically after a join wave.
+    #[serde_as(as = "Option<IdAsU64>")]
+    pub enabled_by: Option<Id<UserMarker>>,
+}
+
+impl RedisModel for RaidMode {
+    type Id = Id<GuildMarker>;
+
+    // Expiration is set when raid mode is enabled, see `RaidMode::expires_at`.
+    const EXPIRES_AFTER: Option<usize> = None;
+
+    fn key(&self) -> String {
+        Self::key_from(&self.guild_id)
+    }
+
+    fn key_from(id: &Self::Id) -> String {
+        format!("raidmode:{id}")
+    }
+}
+
+impl RaidMode {
+    /// Number of seconds before raid mode expires.
+    ///
+    /// Returns [`None`] if raid mode does not expire.
+    fn expires_after(&self) -> Option<usize> {
+        let expires_at = self.expires_at?;
+        let seconds = (expires_at - OffsetDateTime::now_utc()).whole_seconds();
+
+        Some(seconds.max(1) as usize)
+    }
+
+    /// Build the command storing the raid mode in the cache.
+    ///
+    /// If `only_new` is `true`, an existing raid mode is not replaced.
+    fn set_cmd(&self, only_new: bool) -> Result<redis::Cmd, anyhow::Error> {
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(self.key()).arg(self.serialize_model()?);
+
+        if only_new {
+            cmd.arg("NX");
+        }
+
+        if let Some(expires_after) = self.expires_after() {
+            cmd.arg("EX").arg(expires_after);
+        }
+
+        Ok(cmd)
+    }
+
+    /// Get the key of the sorted set storing the recent joins of a guild.
+    fn joins_key(guild_id: Id<GuildMarker>) -> String {
+        format!("raid:joins:{guild_id}")
+    }
+
+    /// Get the key of the sorted set storing the join history of a guild.
+    fn history_key(guild_id: Id<GuildMarker>) -> String {
+        format!("raid:history:{guild_id}")
+    }
+
+    /// Get the key of the list storing the members removed by raid mode that
+    /// have not been logged yet.
+    fn removals_key(guild_id: Id<GuildMarker>) -> String {
+        format!("raid:removals:{guild_id}")
+    }
+
+    /// Get the key set while the log of the members removed by raid mode is
+    /// scheduled.
+    fn removals_scheduled_key(guild_id: Id<GuildMarker>) -> String {
+        format!("raid:removals:scheduled:{guild_id}")
+    }
+}
+
+/// Expiration of the members removed by raid mode that have not been logged,
+/// in seconds.
+///
+/// This ensures a new batch is started if a batch has not been logged, for
+/// example after a restart.
+const REMOVALS_EXPIRES_AFTER: usize = 5 * 60;
+
+/// Expiration of the scheduled log of the members removed by raid mode, in
+/// seconds.
+///
+/// This ensures a new log is scheduled if the scheduled log has been lost, for
+/// example after a restart.
+const REMOVALS_SCHEDULED_EXPIRES_AFTER: usize = 60;
+
+// Implementation of methods to query the cache.
+impl RedisClient {
+    /// Enable raid mode if it is not already enabled.
+    ///
+    /// Returns `false` if raid mode was already enabled, in which case the
+    /// existing raid mode is kept.
+    #[instrument(skip(self))]
+    pub async fn enable_raid_mode(&self, raid_mode: &RaidMode) -> Result<bool, anyhow::Error> {
+        let mut conn = self.conn().await?;
+
+        trace!("enabling raid mode for key {}", raid_mode.key());
+        let enabled: Option<String> = raid_mode.set_cmd(true)?.query_async(&mut *conn).await?;
+
+        Ok(enabled.is_some())
+    }
+
+    /// Enable raid mode, replacing the existing raid mode if any.
+    #[instrument(skip(self))]
+    pub async fn set_raid_mode(&self, raid_mode: &RaidMode) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+
+        trace!("setting raid mode for key {}", raid_mode.key());
+        raid_mode
+            .set_cmd(false)?
+            .query_async::<_, ()>(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Disable raid mode.
+    ///
+    /// Returns `false` if raid mode was not enabled.
+    #[instrument(skip(self))]
+    pub async fn disable_raid_mode(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<bool, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = RaidMode::key_from(&guild_id);
+
+        trace!("disabling raid mode for key {}", key);
+        let deleted: u64 = conn.del(&key).await?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Add a member to the recent joins of a guild.
+    ///
+    /// Joins older than `window` seconds are removed. The members that joined
+    /// within the window, including the new one, are returned from the oldest
+    /// to the most recent join.
+    #[instrument(skip(self))]
+    pub async fn push_join(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        joined_at: OffsetDateTime,
+        window: u32,
+    ) -> Result<Vec<Id<UserMarker>>, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = RaidMode::joins_key(guild_id);
+
+        let timestamp = (joined_at.unix_timestamp_nanos() / 1_000_000) as i64;
+        let since = timestamp - i64::from(window) * 1000;
+
+        trace!(
+            "adding member {} to recent joins of guild {}",
+            user_id,
+            guild_id
+        );
+        let (members,): (Vec<u64>,) = redis::pipe()
+            .zadd(&key, user_id.get(), timestamp)
+            .ignore()
+            .zrembyscore(&key, "-inf", since)
+            .ignore()
+            .expire(&key, window as usize)
+            .ignore()
+            .zrange(&key, 0, -1)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(members.into_iter().filter_map(Id::new_checked).collect())
+    }
+
+    /// Add a member to the join history of a guild.
+    ///
+    /// The join history is used to target members that joined during a raid.
+    /// Joins older than `retention` seconds are removed.
+    #[instrument(skip(self))]
+    pub async fn push_join_history(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        joined_at: OffsetDateTime,
+        retention: u32,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = RaidMode::history_key(guild_id);
+
+        let timestamp = (joined_at.unix_timestamp_nanos() / 1_000_000) as i64;
+        let since = timestamp - i64::from(retention) * 1000;
+
+        trace!(
+            "adding member {} to join history of guild {}",
+            user_id,
+            guild_id
+        );
+        redis::pipe()
+            .zadd(&key, user_id.get(), timestamp)
+            .ignore()
+            .zrembyscore(&key, "-inf", since)
+            .ignore()
+            .expire(&key, retention as usize)
+            .ignore()
+            .query_async::<_, ()>(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Add a member removed by raid mode to the members to log.
+    ///
+    /// Removals are logged by batches. Returns `true` if no log of the batch
+    /// is scheduled, in which case the batch must be logged later with
+    /// [`take_raid_removals`].
+    ///
+    /// [`take_raid_removals`]: Self::take_raid_removals
+    #[instrument(skip(self))]
+    pub async fn push_raid_removal(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<bool, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = RaidMode::removals_key(guild_id);
+        let scheduled_key = RaidMode::removals_scheduled_key(guild_id);
+
+        let (scheduled,): (Option<String>,) = redis::pipe()
+            .rpush(&key, user_id.get())
+            .ignore()
+            .expire(&key, REMOVALS_EXPIRES_AFTER)
+            .ignore()
+            .cmd("SET")
+            .arg(&scheduled_key)
+            .arg(1)
+            .arg("NX")
+            .arg("EX")
+            .arg(REMOVALS_SCHEDULED_EXPIRES_AFTER)
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(scheduled.is_some())
+    }
+
+    /// Get and clear the batch of members removed by raid mode.
+    ///
+    /// Members are returned in the order they were removed. The next removal
+    /// schedules a new log.
+    #[instrument(skip(self))]
+    pub async fn take_raid_removals(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<Id<UserMarker>>, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = RaidMode::removals_key(guild_id);
+        let scheduled_key = RaidMode::removals_scheduled_key(guild_id);
+
+        let (members,): (Vec<u64>,) = redis::pipe()
+            .atomic()
+            .lrange(&key, 0, -1)
+            .del(&[&key, &scheduled_key])
+            .ignore()
+            .query_async(&mut *conn)
+            .await?;
+
+        Ok(members.into_iter().filter_map(Id::new_checked).collect())
+    }
+
+    /// Get the members that joined a guild between two dates.
+    ///
+    /// Members are returned from the oldest to the most recent join. Only
+    /// joins kept in the join history are returned.
+    #[instrument(skip(self))]
+    pub async fn joins_between(
+        &self,
+        guild_id: Id<GuildMarker>,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> Result<Vec<Id<UserMarker>>, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = RaidMode::history_key(guild_id);
+
+        let from = (from.unix_timestamp_nanos() / 1_000_000) as i64;
+        let to = (to.unix_timestamp_nanos() / 1_000_000) as i64;
+
+        let members: Vec<u64> = conn.zrangebyscore(&key, from, to).await?;
+
+        Ok(members.into_iter().filter_map(Id::new_checked).collect())
+    }
+}
@@ -0,0 +1,71 @@
+//! Risk score of new members.
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{cache::RedisModel, serde::IdAsU64};
+
+/// Risk score of a member, computed when the member joins a guild.
+///
+/// The score ranges from `0` to [`MemberRisk::MAX_SCORE`], and is computed from
+/// the [`RiskSignal`] detected for the member.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MemberRisk {
+    /// Id of the guild.
+    #[serde_as(as = "IdAsU64")]
+    pub guild_id: Id<GuildMarker>,
+    /// Id of the member.
+    #[serde_as(as = "IdAsU64")]
+    pub member_id: Id<UserMarker>,
+    /// Risk score of the member.
+    pub score: u8,
+    /// Signals that contributed to the score.
+    pub signals: Vec<RiskSignal>,
+}
+
+impl MemberRisk {
+    /// Maximum risk score.
+    pub const MAX_SCORE: u8 = 100;
+}
+
+impl RedisModel for MemberRisk {
+    type Id = (Id<GuildMarker>, Id<UserMarker>);
+
+    // Risk scores are only relevant for recent members.
+    const EXPIRES_AFTER: Option<usize> = Some(30 * 24 * 60 * 60);
+
+    fn key(&self) -> String {
+        Self::key_from(&(self.guild_id, self.member_id))
+    }
+
+    fn key_from(id: &Self::Id) -> String {
+        format!(
+            "risk:{guild}:{member}",
+            guild = id.0.get(),
+            member = id.1.get()
+        )
+    }
+}
+
+/// Signal contributing to the risk score of a member.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskSignal {
+    /// The account has been created less than a day ago.
+    NewAccount,
+    /// The account has been created less than a week ago.
+    RecentAccount,
+    /// The account uses the default avatar.
+    DefaultAvatar,
+    /// The username looks like a random string.
+    RandomName,
+    /// The username contains a lot of digits.
+    NumericName,
+    /// The member joined during a join wave.
+    JoinWave,
+}
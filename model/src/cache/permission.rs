@@ -5,7 +5,7 @@
 
 use std::cmp::Ordering;
 
-use anyhow::{anyhow, Context};
+use anyhow::Context;
 use tracing::{instrument, trace};
 use twilight_model::{
     channel::ChannelType,
@@ -18,6 +18,7 @@ use twilight_model::{
 use twilight_util::permission_calculator::PermissionCalculator;
 
 use super::{
+    error::CacheMiss,
     model::{CachedChannel, CachedGuild, CachedRole},
     redis::{RedisClient, RedisModel},
 };
@@ -39,7 +40,7 @@ impl<'a> GuildPermissions<'a> {
         if let Some(guild) = redis.get::<CachedGuild>(&guild_id).await? {
             Ok(Self { redis, guild })
         } else {
-            Err(anyhow!("guild not found in cache"))
+            Err(CacheMiss::Guild(guild_id).into())
         }
     }
 
@@ -63,10 +64,7 @@ impl<'a> GuildPermissions<'a> {
 /// Calculate the permissions of a member with information from the cache.
 pub struct CachePermissions<'a> {
     redis: &'a RedisClient,
-    guild_id: Id<GuildMarker>,
-    member_id: Id<UserMarker>,
-    member_roles: MemberRoles,
-    is_owner: bool,
+    member: MemberPermissions,
 }
 
 impl<'a> CachePermissions<'a> {
@@ -78,18 +76,13 @@ impl<'a> CachePermissions<'a> {
         member_id: Id<UserMarker>,
         member_roles: &[Id<RoleMarker>],
     ) -> Result<CachePermissions<'a>, anyhow::Error> {
-        let guild_id = guild_permissions.guild.id;
-        let is_owner = member_id == guild_permissions.guild.owner_id;
-
+        let guild = &guild_permissions.guild;
         let member_roles =
-            MemberRoles::query(guild_permissions.redis, guild_id, member_roles.iter()).await?;
+            MemberRoles::query(guild_permissions.redis, guild.id, member_roles.iter()).await?;
 
         Ok(Self {
             redis: guild_permissions.redis,
-            guild_id,
-            member_id,
-            member_roles,
-            is_owner,
+            member: MemberPermissions::new(guild, member_id, member_roles),
         })
     }
 
@@ -97,69 +90,34 @@ impl<'a> CachePermissions<'a> {
     pub(crate) async fn current_member(
         guild_permissions: &GuildPermissions<'a>,
     ) -> Result<CachePermissions<'a>, anyhow::Error> {
-        let member = guild_permissions
-            .guild
+        let guild = &guild_permissions.guild;
+        let member = guild
             .current_member
             .as_ref()
-            .context("current member not found in cache")?;
-
-        let guild_id = guild_permissions.guild.id;
-        let is_owner = member.id == guild_permissions.guild.owner_id;
+            .ok_or(CacheMiss::CurrentMember(guild.id))?;
 
         let member_roles =
-            MemberRoles::query(guild_permissions.redis, guild_id, member.roles.iter()).await?;
+            MemberRoles::query(guild_permissions.redis, guild.id, member.roles.iter()).await?;
 
         Ok(Self {
             redis: guild_permissions.redis,
-            guild_id,
-            member_id: member.id,
-            member_roles,
-            is_owner,
+            member: MemberPermissions::new(guild, member.id, member_roles),
         })
     }
 
     /// Checks if a user is the owner of a guild.
     pub fn is_owner(&self) -> bool {
-        self.is_owner
+        self.member.is_owner()
     }
 
     /// Returns the highest role of a user.
     pub fn highest_role(&self) -> RoleOrdering {
-        if self.member_roles.roles.is_empty() {
-            RoleOrdering::from(&self.member_roles.everyone)
-        } else {
-            let mut roles: Vec<_> = self
-                .member_roles
-                .roles
-                .iter()
-                .map(RoleOrdering::from)
-                .collect();
-            roles.sort();
-
-            *roles.last().unwrap() // SAFETY: roles is not empty
-        }
+        self.member.highest_role()
     }
 
     /// Calculate the permissions of the user in the guild.
     pub fn guild(&self) -> Permissions {
-        // Owners have all permissions
-        if self.is_owner {
-            return Permissions::all();
-        }
-
-        // TODO: extract this into a function
-        let everyone_role = self.member_roles.everyone.permissions;
-        let member_roles = self
-            .member_roles
-            .roles
-            .iter()
-            .map(|role| (role.id, role.permissions))
-            .collect::<Vec<_>>();
-
-        let calculator =
-            PermissionCalculator::new(self.guild_id, self.member_id, everyone_role, &member_roles);
-
-        calculator.root()
+        self.member.guild()
     }
 
     /// Calculate the permissions of the user in a given channel.
@@ -170,45 +128,156 @@ impl<'a> CachePermissions<'a> {
         &self,
         channel: Id<ChannelMarker>,
     ) -> Result<(Permissions, ChannelType), anyhow::Error> {
-        let mut channel = self
+        let channel = self
             .redis
             .get::<CachedChannel>(&channel)
             .await?
-            .context("channel not found in cache")?;
+            .ok_or(CacheMiss::Channel(channel))?;
 
-        // If the channel is a thread, get the parent channel.
-        if channel.is_thread() {
-            if let Some(parent_id) = channel.parent_id {
-                channel = self
-                    .redis
+        // Threads inherit the permission overwrites of their parent channel.
+        let parent = match channel.parent_id {
+            Some(parent_id) if channel.is_thread() => Some(
+                self.redis
                     .get::<CachedChannel>(&parent_id)
                     .await?
-                    .context("parent channel not found in cache")?;
-            }
+                    .ok_or(CacheMiss::Channel(parent_id))?,
+            ),
+            _ => None,
+        };
+
+        let permissions = self.member.channel(&channel, parent.as_ref());
+
+        Ok((permissions, channel.kind))
+    }
+
+    /// Get the resolved permissions of the member.
+    pub(crate) fn resolved(&self) -> &MemberPermissions {
+        &self.member
+    }
+}
+
+/// Permissions of a member resolved from cached data.
+///
+/// This type performs the calculations of [`CachePermissions`] without
+/// querying the cache.
+#[derive(Debug, Clone)]
+pub(crate) struct MemberPermissions {
+    guild_id: Id<GuildMarker>,
+    member_id: Id<UserMarker>,
+    member_roles: MemberRoles,
+    is_owner: bool,
+    is_current_member: bool,
+}
+
+impl MemberPermissions {
+    /// Initialize a new [`MemberPermissions`].
+    pub(crate) fn new(
+        guild: &CachedGuild,
+        member_id: Id<UserMarker>,
+        member_roles: MemberRoles,
+    ) -> Self {
+        Self {
+            guild_id: guild.id,
+            member_id,
+            member_roles,
+            is_owner: member_id == guild.owner_id,
+            is_current_member: guild.current_member.as_ref().map(|current| current.id)
+                == Some(member_id),
         }
+    }
 
-        // TODO: extract this into a function
-        let everyone_role = self.member_roles.everyone.permissions;
-        let member_roles = self
-            .member_roles
+    /// Returns the id of the member.
+    pub(crate) fn member_id(&self) -> Id<UserMarker> {
+        self.member_id
+    }
+
+    /// Checks if the member is the owner of the guild.
+    pub(crate) fn is_owner(&self) -> bool {
+        self.is_owner
+    }
+
+    /// Returns the highest role of the member.
+    pub(crate) fn highest_role(&self) -> RoleOrdering {
+        self.member_roles
             .roles
             .iter()
-            .map(|role| (role.id, role.permissions))
-            .collect::<Vec<_>>();
+            .map(RoleOrdering::from)
+            .max()
+            .unwrap_or_else(|| RoleOrdering::from(&self.member_roles.everyone))
+    }
+
+    /// Calculate the permissions of the member in the guild.
+    pub(crate) fn guild(&self) -> Permissions {
+        // Owners have all permissions
+        if self.is_owner {
+            return Permissions::all();
+        }
 
-        let calculator =
-            PermissionCalculator::new(self.guild_id, self.member_id, everyone_role, &member_roles);
+        let member_roles = self.member_roles.permissions();
 
-        let kind = channel.kind;
-        let permissions =
-            calculator.in_channel(kind, &channel.permission_overwrites.unwrap_or_default());
+        self.calculator(&member_roles).root()
+    }
 
-        Ok((permissions, kind))
+    /// Calculate the permissions of the member in a channel.
+    ///
+    /// If the channel is a thread, its `parent` channel must be provided since
+    /// threads do not have their own permission overwrites. Private threads
+    /// are only visible to their members and to members with the
+    /// [`MANAGE_THREADS`] permission.
+    ///
+    /// Only the thread membership of the bot is cached, so it is only checked
+    /// when computing the permissions of the bot. Other members are assumed
+    /// to be members of the thread.
+    ///
+    /// [`MANAGE_THREADS`]: Permissions::MANAGE_THREADS
+    pub(crate) fn channel(
+        &self,
+        channel: &CachedChannel,
+        parent: Option<&CachedChannel>,
+    ) -> Permissions {
+        if self.is_owner {
+            return Permissions::all();
+        }
+
+        let source = match parent {
+            Some(parent) if channel.is_thread() => parent,
+            _ => channel,
+        };
+
+        let member_roles = self.member_roles.permissions();
+        let overwrites = source.permission_overwrites.as_deref().unwrap_or_default();
+        let permissions = self
+            .calculator(&member_roles)
+            .in_channel(source.kind, overwrites);
+
+        if channel.kind == ChannelType::GuildPrivateThread
+            && self.is_current_member
+            && !channel.thread_member
+            && !permissions.contains(Permissions::MANAGE_THREADS)
+        {
+            return Permissions::empty();
+        }
+
+        permissions
+    }
+
+    /// Initialize a [`PermissionCalculator`] for the member.
+    fn calculator<'a>(
+        &self,
+        member_roles: &'a [(Id<RoleMarker>, Permissions)],
+    ) -> PermissionCalculator<'a> {
+        PermissionCalculator::new(
+            self.guild_id,
+            self.member_id,
+            self.member_roles.everyone.permissions,
+            member_roles,
+        )
     }
 }
 
 /// List of resolved roles of a member.
-struct MemberRoles {
+#[derive(Debug, Clone)]
+pub(crate) struct MemberRoles {
     /// Everyone role
     pub everyone: CachedRole,
     /// List of roles of the user
@@ -216,6 +285,35 @@ struct MemberRoles {
 }
 
 impl MemberRoles {
+    /// Initialize [`MemberRoles`] from a list of roles.
+    ///
+    /// The list must contain the everyone role, which has the same id as the
+    /// guild.
+    pub(crate) fn new(
+        guild_id: Id<GuildMarker>,
+        member_roles: impl IntoIterator<Item = CachedRole>,
+    ) -> Result<MemberRoles, anyhow::Error> {
+        let everyone_id = guild_id.cast();
+
+        // Filter everyone role and other roles
+        let mut everyone_role = None;
+        let mut roles = Vec::new();
+
+        for role in member_roles {
+            if role.id == everyone_id {
+                everyone_role = Some(role);
+            } else {
+                roles.push(role)
+            }
+        }
+
+        if let Some(everyone) = everyone_role {
+            Ok(MemberRoles { everyone, roles })
+        } else {
+            Err(CacheMiss::Role(everyone_id).into())
+        }
+    }
+
     /// Query roles of a member in the cache.
     async fn query(
         redis: &RedisClient,
@@ -236,25 +334,20 @@ impl MemberRoles {
             .await
             .context("failed to query user roles")?;
 
-        // Filter everyone role and other roles
-        let mut everyone_role = None;
-        let mut roles = Vec::new();
+        let roles = result
+            .into_iter()
+            .map(CachedRole::deserialize_model)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        for value in result {
-            let role = CachedRole::deserialize_model(value)?;
-
-            if role.id == everyone_id {
-                everyone_role = Some(role);
-            } else {
-                roles.push(role)
-            }
-        }
+        Self::new(guild_id, roles)
+    }
 
-        if let Some(everyone) = everyone_role {
-            Ok(MemberRoles { everyone, roles })
-        } else {
-            Err(anyhow!("everyone role not found in cache"))
-        }
+    /// Get the permissions of each role of the member.
+    fn permissions(&self) -> Vec<(Id<RoleMarker>, Permissions)> {
+        self.roles
+            .iter()
+            .map(|role| (role.id, role.permissions))
+            .collect()
     }
 }
 
@@ -353,4 +446,139 @@ mod tests {
         assert_eq!(Ordering::Equal, role_a.cmp(&role_a));
         assert_eq!(Ordering::Equal, role_b.cmp(&role_b));
     }
+
+    mod resolved {
+        use twilight_model::channel::ChannelType;
+
+        use super::super::*;
+        use crate::cache::{error::CacheMiss, fixtures::*};
+
+        #[test]
+        fn test_guild_permissions() {
+            let permissions = bot().guild();
+
+            assert!(permissions.contains(Permissions::MANAGE_ROLES | Permissions::SEND_MESSAGES));
+            assert!(!permissions.contains(Permissions::BAN_MEMBERS));
+        }
+
+        #[test]
+        fn test_owner_permissions() {
+            let owner = member(OWNER_ID, Vec::new());
+
+            assert_eq!(owner.guild(), Permissions::all());
+            assert_eq!(owner.channel(&locked_channel(), None), Permissions::all());
+        }
+
+        #[test]
+        fn test_highest_role() {
+            let everyone_only = member(MEMBER_ID, Vec::new());
+            let moderator = member(MODERATOR_ID, vec![member_role(), moderator_role()]);
+
+            assert_eq!(
+                everyone_only.highest_role(),
+                RoleOrdering::from(&everyone())
+            );
+            assert_eq!(
+                moderator.highest_role(),
+                RoleOrdering::from(&moderator_role())
+            );
+        }
+
+        #[test]
+        fn test_channel_overwrites() {
+            let text = bot().channel(&text_channel(), None);
+            let locked = bot().channel(&locked_channel(), None);
+            let member_locked =
+                member(MEMBER_ID, vec![member_role()]).channel(&locked_channel(), None);
+
+            assert!(text.contains(Permissions::SEND_MESSAGES));
+            assert!(!locked.contains(Permissions::SEND_MESSAGES));
+            assert!(locked.contains(Permissions::SEND_MESSAGES_IN_THREADS));
+            assert!(!member_locked
+                .intersects(Permissions::SEND_MESSAGES | Permissions::SEND_MESSAGES_IN_THREADS));
+        }
+
+        #[test]
+        fn test_thread_parent_overwrites() {
+            let parent = locked_channel();
+            let thread = thread(
+                PUBLIC_THREAD,
+                ChannelType::GuildPublicThread,
+                &parent,
+                false,
+            );
+
+            let permissions = bot().channel(&thread, Some(&parent));
+
+            assert!(permissions.contains(Permissions::SEND_MESSAGES_IN_THREADS));
+            assert!(!permissions.contains(Permissions::SEND_MESSAGES));
+        }
+
+        #[test]
+        fn test_private_thread_membership() {
+            let parent = text_channel();
+            let joined = thread(
+                PRIVATE_THREAD,
+                ChannelType::GuildPrivateThread,
+                &parent,
+                true,
+            );
+            let not_joined = thread(
+                PRIVATE_THREAD,
+                ChannelType::GuildPrivateThread,
+                &parent,
+                false,
+            );
+
+            assert!(bot()
+                .channel(&joined, Some(&parent))
+                .contains(Permissions::VIEW_CHANNEL));
+            assert!(bot().channel(&not_joined, Some(&parent)).is_empty());
+        }
+
+        #[test]
+        fn test_private_thread_other_member() {
+            let parent = text_channel();
+            let thread = thread(
+                PRIVATE_THREAD,
+                ChannelType::GuildPrivateThread,
+                &parent,
+                false,
+            );
+
+            // The thread membership only describes the bot.
+            assert!(member(MEMBER_ID, vec![member_role()])
+                .channel(&thread, Some(&parent))
+                .contains(Permissions::VIEW_CHANNEL));
+        }
+
+        #[test]
+        fn test_private_thread_manage_threads() {
+            let parent = text_channel();
+            let thread = thread(
+                PRIVATE_THREAD,
+                ChannelType::GuildPrivateThread,
+                &parent,
+                false,
+            );
+            let manager = member(
+                MEMBER_ID,
+                vec![role(MEMBER_ROLE, 1, Permissions::MANAGE_THREADS)],
+            );
+
+            assert!(manager
+                .channel(&thread, Some(&parent))
+                .contains(Permissions::VIEW_CHANNEL));
+        }
+
+        #[test]
+        fn test_member_roles_missing_everyone() {
+            let error = MemberRoles::new(GUILD_ID, [member_role()]).unwrap_err();
+
+            assert_eq!(
+                error.downcast::<CacheMiss>().unwrap(),
+                CacheMiss::Role(GUILD_ID.cast())
+            );
+        }
+    }
 }
@@ -5,14 +5,14 @@ use tracing::error;
 use twilight_model::{
     gateway::payload::incoming::{
         ChannelCreate, ChannelDelete, ChannelUpdate, GuildCreate, GuildDelete, GuildUpdate,
-        MemberAdd, MemberUpdate, RoleCreate, RoleDelete, RoleUpdate, ThreadCreate, ThreadDelete,
-        ThreadUpdate, UnavailableGuild,
+        MemberAdd, MemberChunk, MemberRemove, MemberUpdate, RoleCreate, RoleDelete, RoleUpdate,
+        ThreadCreate, ThreadDelete, ThreadMembersUpdate, ThreadUpdate, UnavailableGuild,
     },
     id::{marker::ApplicationMarker, Id},
 };
 
 use crate::cache::{
-    model::{CachedChannel, CachedGuild, CachedRole, CurrentMember},
+    model::{CachedChannel, CachedGuild, CachedMember, CachedRole, CurrentMember},
     RedisClient, RedisModel,
 };
 
@@ -71,6 +71,7 @@ impl UpdateCache for GuildDelete {
             let mut conn = redis.conn().await?;
             let mut pipe = redis::pipe();
             pipe.del(CachedGuild::key_from(&self.id));
+            pipe.del(CachedMember::key_from(self.id));
 
             for channel in &guild.channels {
                 pipe.del(CachedChannel::key_from(channel));
@@ -152,7 +153,7 @@ impl UpdateCache for ChannelCreate {
                 let mut conn = redis.conn().await?;
 
                 if CachedChannel::is_cached(self.kind) {
-                    match super::resource::cache_guild_channel(&mut pipe, self) {
+                    match super::resource::cache_guild_channel(&mut pipe, self, false) {
                         Ok(_) => {
                             guild.channels.insert(self.id);
                             pipe.set(guild.key(), guild.serialize_model()?);
@@ -216,7 +217,7 @@ impl UpdateCache for ChannelUpdate {
         let mut pipe = redis::pipe();
         let mut conn = redis.conn().await?;
 
-        match super::resource::cache_guild_channel(&mut pipe, self) {
+        match super::resource::cache_guild_channel(&mut pipe, self, false) {
             Ok(_) => pipe.query_async(&mut *conn).await?,
             Err(error) => {
                 error!(error = ?error, "failed to cache guild channel");
@@ -241,7 +242,7 @@ impl UpdateCache for ThreadCreate {
                 let mut pipe = redis::pipe();
                 let mut conn = redis.conn().await?;
 
-                match super::resource::cache_guild_channel(&mut pipe, self) {
+                match super::resource::cache_guild_channel(&mut pipe, self, self.member.is_some()) {
                     Ok(_) => {
                         guild.channels.insert(self.id);
                         pipe.set(guild.key(), guild.serialize_model()?);
@@ -299,10 +300,18 @@ impl UpdateCache for ThreadUpdate {
             return Ok(()); // Ensure the channel is in a guild.
         }
 
+        // Thread updates do not include the bot thread member, so the thread
+        // membership is kept from the cached channel.
+        let thread_member = redis
+            .get::<CachedChannel>(&self.id)
+            .await?
+            .map(|channel| channel.thread_member)
+            .unwrap_or_default();
+
         let mut pipe = redis::pipe();
         let mut conn = redis.conn().await?;
 
-        match super::resource::cache_guild_channel(&mut pipe, self) {
+        match super::resource::cache_guild_channel(&mut pipe, self, thread_member) {
             Ok(_) => pipe.query_async(&mut *conn).await?,
             Err(error) => {
                 error!(error = ?error, "failed to cache guild channel");
@@ -313,6 +322,39 @@ impl UpdateCache for ThreadUpdate {
     }
 }
 
+#[async_trait]
+impl UpdateCache for ThreadMembersUpdate {
+    const NAME: &'static str = "ThreadMembersUpdate";
+
+    async fn update(
+        &self,
+        redis: &RedisClient,
+        current_user: Id<ApplicationMarker>,
+    ) -> Result<(), anyhow::Error> {
+        let current_user = current_user.cast();
+
+        // Only the bot membership is cached.
+        let thread_member = if self
+            .added_members
+            .iter()
+            .any(|member| member.user_id == Some(current_user))
+        {
+            true
+        } else if self.removed_member_ids.contains(&current_user) {
+            false
+        } else {
+            return Ok(());
+        };
+
+        if let Some(mut channel) = redis.get::<CachedChannel>(&self.id).await? {
+            channel.thread_member = thread_member;
+            redis.set(&channel).await?;
+        }
+
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl UpdateCache for RoleCreate {
     const NAME: &'static str = "RoleCreate";
@@ -391,6 +433,10 @@ impl UpdateCache for MemberAdd {
         redis: &RedisClient,
         current_user: Id<ApplicationMarker>,
     ) -> Result<(), anyhow::Error> {
+        redis
+            .cache_member(self.guild_id, &CachedMember::from(&self.0))
+            .await?;
+
         if self.user.id != current_user.cast() {
             // Only cache bot user
             return Ok(());
@@ -420,6 +466,10 @@ impl UpdateCache for MemberUpdate {
         redis: &RedisClient,
         current_user: Id<ApplicationMarker>,
     ) -> Result<(), anyhow::Error> {
+        redis
+            .cache_member(self.guild_id, &CachedMember::from(self))
+            .await?;
+
         if self.user.id != current_user.cast() {
             // Only cache bot user
             return Ok(());
@@ -438,3 +488,39 @@ impl UpdateCache for MemberUpdate {
         Ok(())
     }
 }
+
+#[async_trait]
+impl UpdateCache for MemberRemove {
+    const NAME: &'static str = "MemberRemove";
+
+    async fn update(
+        &self,
+        redis: &RedisClient,
+        _current_user: Id<ApplicationMarker>,
+    ) -> Result<(), anyhow::Error> {
+        redis.remove_member(self.guild_id, self.user.id).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UpdateCache for MemberChunk {
+    const NAME: &'static str = "MemberChunk";
+
+    async fn update(
+        &self,
+        redis: &RedisClient,
+        _current_user: Id<ApplicationMarker>,
+    ) -> Result<(), anyhow::Error> {
+        let members = self
+            .members
+            .iter()
+            .map(CachedMember::from)
+            .collect::<Vec<_>>();
+
+        redis.cache_members(self.guild_id, &members).await?;
+
+        Ok(())
+    }
+}
@@ -18,7 +18,7 @@ use twilight_model::{
 };
 
 use crate::cache::{
-    model::{CachedChannel, CachedGuild, CachedRole, CurrentMember},
+    model::{CachedChannel, CachedGuild, CachedMember, CachedRole, CurrentMember},
     RedisModel,
 };
 
@@ -31,9 +31,9 @@ pub fn cache_guild(
     let mut channels = HashSet::with_capacity(guild.channels.len());
     let mut roles = HashSet::with_capacity(guild.roles.len());
 
-    for channel in &guild.channels {
+    for channel in guild.channels.iter().chain(&guild.threads) {
         if CachedChannel::is_cached(channel.kind) {
-            match cache_guild_channel(pipe, channel) {
+            match cache_guild_channel(pipe, channel, channel.member.is_some()) {
                 Ok(_) => {
                     channels.insert(channel.id);
                 }
@@ -50,6 +50,26 @@ pub fn cache_guild(
         roles.insert(role.id);
     }
 
+    // Members received with the guild replace the cached ones. Large guilds
+    // only send a subset of their members, the others are received with
+    // `MemberChunk` events, which must be requested once the guild has been
+    // cached.
+    let members = guild
+        .members
+        .iter()
+        .map(|member| {
+            let cached = CachedMember::from(member);
+
+            Ok((member.user.id.get(), rmp_serde::to_vec_named(&cached)?))
+        })
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+    pipe.del(CachedMember::key_from(guild.id));
+
+    if !members.is_empty() {
+        pipe.hset_multiple(CachedMember::key_from(guild.id), &members);
+    }
+
     // Find the bot current member.
     let current_member = guild
         .members
@@ -100,7 +120,15 @@ pub fn cache_role(
     Ok(())
 }
 
-pub fn cache_guild_channel(pipe: &mut Pipeline, channel: &Channel) -> Result<(), anyhow::Error> {
+/// Insert a guild channel or thread into the cache.
+///
+/// The `thread_member` argument indicates whether the bot is a member of the
+/// thread, since this information is not sent with all thread events.
+pub fn cache_guild_channel(
+    pipe: &mut Pipeline,
+    channel: &Channel,
+    thread_member: bool,
+) -> Result<(), anyhow::Error> {
     if CachedChannel::is_cached(channel.kind) {
         let cached = CachedChannel {
             id: channel.id,
@@ -111,6 +139,7 @@ pub fn cache_guild_channel(pipe: &mut Pipeline, channel: &Channel) -> Result<(),
             permission_overwrites: channel.permission_overwrites.clone(),
             position: channel.position,
             rate_limit_per_user: channel.rate_limit_per_user,
+            thread_member,
         };
 
         pipe.set(cached.key(), cached.serialize_model()?);
@@ -64,6 +64,35 @@ impl RedisClient {
         value.map(RedisModel::deserialize_model).transpose()
     }
 
+    /// Get multiple values from Redis.
+    ///
+    /// Values that are not found in the cache are ignored.
+    #[instrument(skip(self))]
+    pub async fn get_many<T: RedisModel>(&self, ids: &[T::Id]) -> Result<Vec<T>, anyhow::Error>
+    where
+        T::Id: Sized,
+    {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self.conn().await?;
+        let mut pipe = redis::pipe();
+
+        trace!("getting {} values", ids.len());
+        for id in ids {
+            pipe.get(T::key_from(id));
+        }
+
+        let values: Vec<Option<Vec<u8>>> = pipe.query_async(&mut *conn).await?;
+
+        values
+            .into_iter()
+            .flatten()
+            .map(RedisModel::deserialize_model)
+            .collect()
+    }
+
     /// Set a value in Redis.
     #[instrument(skip(self))]
     pub async fn set<T: RedisModel>(&self, value: &T) -> Result<(), anyhow::Error> {
@@ -81,6 +110,40 @@ impl RedisClient {
         Ok(())
     }
 
+    /// Set a value in Redis with a custom expiration duration.
+    ///
+    /// The expiration duration is in seconds and overrides
+    /// [`RedisModel::EXPIRES_AFTER`].
+    #[instrument(skip(self))]
+    pub async fn set_ex<T: RedisModel>(
+        &self,
+        value: &T,
+        expires_after: usize,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = value.key();
+
+        trace!(value = ?value, "setting value for key {} ({}s)", key, expires_after);
+        conn.set_ex(key, value.serialize_model()?, expires_after)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete a value from Redis.
+    ///
+    /// Returns `false` if the value was not found.
+    #[instrument(skip(self))]
+    pub async fn delete<T: RedisModel>(&self, id: &T::Id) -> Result<bool, anyhow::Error> {
+        let mut conn = self.conn().await?;
+        let key = T::key_from(id);
+
+        trace!("deleting value for key {}", key);
+        let deleted: u64 = conn.del(&key).await?;
+
+        Ok(deleted > 0)
+    }
+
     /// Run a `PING` command to check if Redis is connected.
     pub async fn ping(&self) -> Result<(), anyhow::Error> {
         let mut conn = self.conn().await?;
@@ -32,6 +32,44 @@ pub struct BotConfig {
     /// Logging configuration.
     #[serde(flatten, default)]
     pub log: shared::LogConfig,
+    /// Domains protected against phishing.
+    ///
+    /// Links to lookalike domains of these domains are flagged as phishing.
+    /// The list is comma-separated, and defaults to
+    /// [`DEFAULT_PROTECTED_DOMAINS`].
+    #[serde(default = "default_protected_domains")]
+    pub protected_domains: Vec<String>,
+    /// Whether the members of each guild are requested on `GuildCreate`.
+    ///
+    /// Large guilds only send a subset of their members with the guild, the
+    /// others are received in chunks when requested. Defaults to `false`.
+    ///
+    /// When disabled, the members cache of large guilds only contains the
+    /// members sent with the guild and those who joined or were updated
+    /// since. Features iterating over cached members (such as mass actions)
+    /// then only see a part of the guild, and permission checks fetch the
+    /// missing members from the HTTP API.
+    #[serde(default)]
+    pub request_members: bool,
+}
+
+/// Default domains protected against phishing.
+pub const DEFAULT_PROTECTED_DOMAINS: [&str; 7] = [
+    "discord.com",
+    "discord.gg",
+    "discord.gift",
+    "discordapp.com",
+    "discordapp.net",
+    "steamcommunity.com",
+    "steampowered.com",
+];
+
+/// Default domains protected against phishing.
+fn default_protected_domains() -> Vec<String> {
+    DEFAULT_PROTECTED_DOMAINS
+        .into_iter()
+        .map(String::from)
+        .collect()
 }
 
 /// Base web api configuration model.
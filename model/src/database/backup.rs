@@ -0,0 +1,246 @@
+//! Models for the `backups` collection.
+
+use anyhow::{anyhow, Context};
+use mongodb::{
+    bson::{doc, oid::ObjectId, Bson, Document},
+    options, Cursor,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::{permission_overwrite::PermissionOverwriteType, ChannelType},
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::DbClient;
+use crate::serde::{DateTimeAsBson, IdAsI64};
+
+/// Snapshot of the structure of a guild.
+///
+/// This type represent a snapshot stored in the `backups` collection of the
+/// database. Snapshots contain the roles and channels of the guild, and are
+/// used to restore the guild structure after a nuke.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Backup {
+    /// Unique ID of the snapshot.
+    #[serde(rename = "_id")]
+    pub id: Option<ObjectId>,
+    /// Guild of the snapshot.
+    #[serde_as(as = "IdAsI64")]
+    pub guild_id: Id<GuildMarker>,
+    /// Version of the snapshot.
+    ///
+    /// Versions are incremented for each new snapshot of the guild, and are
+    /// used to identify snapshots in commands.
+    pub version: u32,
+    /// Date of the snapshot.
+    #[serde_as(as = "DateTimeAsBson")]
+    pub created_at: OffsetDateTime,
+    /// Member that created the snapshot.
+    ///
+    /// [`None`] if the snapshot was created automatically.
+    #[serde_as(as = "Option<IdAsI64>")]
+    #[serde(default)]
+    pub created_by: Option<Id<UserMarker>>,
+    /// Roles of the guild.
+    pub roles: Vec<BackupRole>,
+    /// Channels of the guild, threads excluded.
+    pub channels: Vec<BackupChannel>,
+}
+
+impl Backup {
+    /// Name of the MongoDB collection.
+    pub const COLLECTION: &'static str = "backups";
+
+    /// Name of the MongoDB collection storing the latest version of the
+    /// snapshots of each guild.
+    pub const VERSIONS_COLLECTION: &'static str = "backup_versions";
+
+    /// Maximum number of snapshots kept for each guild.
+    ///
+    /// Oldest snapshots are deleted when a new snapshot is created.
+    pub const MAX_BACKUPS: u32 = 10;
+}
+
+/// Role stored in a [`Backup`].
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BackupRole {
+    /// Id of the role.
+    #[serde_as(as = "IdAsI64")]
+    pub id: Id<RoleMarker>,
+    /// Name of the role.
+    pub name: String,
+    /// Color of the role.
+    pub color: u32,
+    /// Position of the role.
+    pub position: i64,
+    /// Permissions of the role.
+    pub permissions: Permissions,
+    /// Whether the role is managed.
+    ///
+    /// Managed roles cannot be created and are not restored.
+    pub managed: bool,
+}
+
+/// Channel stored in a [`Backup`].
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BackupChannel {
+    /// Id of the channel.
+    #[serde_as(as = "IdAsI64")]
+    pub id: Id<ChannelMarker>,
+    /// Type of the channel.
+    pub kind: ChannelType,
+    /// Name of the channel.
+    pub name: String,
+    /// Id of the parent category.
+    #[serde_as(as = "Option<IdAsI64>")]
+    #[serde(default)]
+    pub parent_id: Option<Id<ChannelMarker>>,
+    /// Sorting position of the channel.
+    pub position: Option<i32>,
+    /// Amount of seconds a user has to wait between two message.
+    pub rate_limit_per_user: Option<u16>,
+    /// Permission overwrites of the channel.
+    pub permission_overwrites: Vec<BackupOverwrite>,
+}
+
+/// Permission overwrite stored in a [`BackupChannel`].
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BackupOverwrite {
+    /// Id of the role or member.
+    #[serde_as(as = "IdAsI64")]
+    pub id: Id<GenericMarker>,
+    /// Type of the overwrite.
+    pub kind: PermissionOverwriteType,
+    /// Allowed permissions.
+    pub allow: Permissions,
+    /// Denied permissions.
+    pub deny: Permissions,
+}
+
+// Implementation of methods to query the database.
+impl DbClient {
+    /// Insert a new [`Backup`] in the database.
+    ///
+    /// The version of the snapshot is set to the next version of the guild,
+    /// and the oldest snapshots are deleted to keep at most
+    /// [`Backup::MAX_BACKUPS`] snapshots.
+    pub async fn create_backup(&self, backup: &mut Backup) -> Result<ObjectId, anyhow::Error> {
+        let collection = self.db().collection::<Backup>(Backup::COLLECTION);
+
+        let latest = self.latest_backup(backup.guild_id).await?;
+        let latest = latest.map(|latest| latest.version).unwrap_or_default();
+        backup.version = self.next_backup_version(backup.guild_id, latest).await?;
+
+        let result = collection.insert_one(&*backup, None).await?;
+
+        if backup.version > Backup::MAX_BACKUPS {
+            let query = doc! {
+                "guild_id": backup.guild_id.get() as i64,
+                "version": { "$lte": backup.version - Backup::MAX_BACKUPS },
+            };
+
+            collection.delete_many(query, None).await?;
+        }
+
+        match result.inserted_id {
+            Bson::ObjectId(id) => Ok(id),
+            other => Err(anyhow!("expected object id, got {:?}", other)),
+        }
+    }
+
+    /// Increment the version of the snapshots of a guild.
+    ///
+    /// The version is incremented atomically, so that snapshots created
+    /// concurrently get distinct versions. The `latest` version of the stored
+    /// snapshots is used if it is higher than the stored version, such as for
+    /// snapshots created before versions were stored.
+    async fn next_backup_version(
+        &self,
+        guild_id: Id<GuildMarker>,
+        latest: u32,
+    ) -> Result<u32, anyhow::Error> {
+        let query = doc! { "_id": guild_id.get() as i64 };
+        let current = doc! { "$max": [{ "$ifNull": ["$version", 0_i64] }, i64::from(latest)] };
+        let update = vec![doc! { "$set": { "version": { "$add": [current, 1_i64] } } }];
+        let options = options::FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(options::ReturnDocument::After)
+            .build();
+
+        let versions = self
+            .db()
+            .collection::<Document>(Backup::VERSIONS_COLLECTION)
+            .find_one_and_update(query, update, options)
+            .await?
+            .context("no backup version sent by the database")?;
+
+        Ok(versions.get_i64("version")?.try_into()?)
+    }
+
+    /// Get a [`Backup`] of a guild with its version.
+    pub async fn get_backup(
+        &self,
+        guild_id: Id<GuildMarker>,
+        version: u32,
+    ) -> Result<Option<Backup>, anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64, "version": version };
+
+        let backup = self
+            .db()
+            .collection::<Backup>(Backup::COLLECTION)
+            .find_one(query, None)
+            .await?;
+
+        Ok(backup)
+    }
+
+    /// Get the most recent [`Backup`] of a guild.
+    pub async fn latest_backup(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<Backup>, anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64 };
+        let options = options::FindOneOptions::builder()
+            .sort(doc! { "version": -1 })
+            .build();
+
+        let backup = self
+            .db()
+            .collection::<Backup>(Backup::COLLECTION)
+            .find_one(query, options)
+            .await?;
+
+        Ok(backup)
+    }
+
+    /// Find the [`Backup`]s of a guild, from the most recent to the oldest.
+    pub async fn find_backups(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Cursor<Backup>, anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64 };
+        let options = options::FindOptions::builder()
+            .sort(doc! { "version": -1 })
+            .build();
+
+        let cursor = self
+            .db()
+            .collection::<Backup>(Backup::COLLECTION)
+            .find(query, options)
+            .await?;
+
+        Ok(cursor)
+    }
+}
@@ -2,6 +2,8 @@ use std::time::Duration;
 
 use mongodb::{bson::doc, options, Client, Database};
 
+use super::guild::GuildConfigCache;
+
 /// Wrapper around a MongoDB [`Client`].
 ///
 /// This type wraps an underlying MongoDB connection pool and exposes high-level
@@ -9,11 +11,15 @@ use mongodb::{bson::doc, options, Client, Database};
 ///
 /// It type can be cheaply cloned because the underlying [`Client`] uses [`Arc`].
 ///
+/// Guild configurations are kept in an in-process cache, see
+/// [`DbClient::get_guild`] for more information.
+///
 /// [`Arc`]: std::sync::Arc
 #[derive(Debug, Clone)]
 pub struct DbClient {
     client: Client,
     database: String,
+    pub(super) guilds: GuildConfigCache,
 }
 
 impl DbClient {
@@ -29,7 +35,11 @@ impl DbClient {
         config.default_database = Some(database.clone());
 
         let client = Client::with_options(config)?;
-        Ok(Self { client, database })
+        Ok(Self {
+            client,
+            database,
+            guilds: GuildConfigCache::default(),
+        })
     }
 
     /// Return a clone of the underlying client.
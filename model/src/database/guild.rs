@@ -1,9 +1,15 @@
 //! Models for the `guilds` collection.
 
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+    time::{Duration, Instant},
+};
+
 use anyhow::Context;
 use mongodb::{
     bson::{doc, to_document},
-    options,
+    options, Cursor,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none};
@@ -43,6 +49,36 @@ pub struct GuildConfig {
     /// The captcha module configuration.
     #[serde(default)]
     pub captcha: CaptchaConfig,
+    /// The auto-moderation module configuration.
+    #[serde(default)]
+    pub automod: AutomodConfig,
+    /// The message logs configuration.
+    #[serde(default)]
+    pub messages: MessagesConfig,
+    /// The raid protection configuration.
+    #[serde(default)]
+    pub raid: RaidConfig,
+    /// The account age gate configuration.
+    #[serde(default)]
+    pub account_age: AccountAgeConfig,
+    /// The risk scoring configuration.
+    #[serde(default)]
+    pub risk: RiskConfig,
+    /// The username and nickname rules configuration.
+    #[serde(default)]
+    pub names: NamesConfig,
+    /// The anti-nuke protection configuration.
+    #[serde(default)]
+    pub antinuke: AntinukeConfig,
+    /// The guild structure backups configuration.
+    #[serde(default)]
+    pub backup: BackupConfig,
+    /// The quarantine configuration.
+    #[serde(default)]
+    pub quarantine: QuarantineConfig,
+    /// The role persistence configuration.
+    #[serde(default)]
+    pub persist: PersistConfig,
 }
 
 fn default_lang() -> String {
@@ -61,6 +97,16 @@ impl GuildConfig {
             lang: default_lang(),
             moderation: ModerationConfig::default(),
             captcha: CaptchaConfig::default(),
+            automod: AutomodConfig::default(),
+            messages: MessagesConfig::default(),
+            raid: RaidConfig::default(),
+            account_age: AccountAgeConfig::default(),
+            risk: RiskConfig::default(),
+            names: NamesConfig::default(),
+            antinuke: AntinukeConfig::default(),
+            backup: BackupConfig::default(),
+            quarantine: QuarantineConfig::default(),
+            persist: PersistConfig::default(),
         }
     }
 }
@@ -131,13 +177,563 @@ impl CaptchaConfig {
     pub const MAX_VERIFIED_ROLES_LEN: usize = 5;
 }
 
+/// Configuration for the message logs.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct MessagesConfig {
+    /// Channel where edited and deleted messages are logged.
+    ///
+    /// Message logs are disabled if no channel is set.
+    #[serde_as(as = "Option<IdAsI64>")]
+    pub logs: Option<Id<ChannelMarker>>,
+    /// Duration messages are kept in the cache, in seconds.
+    ///
+    /// Only messages that are still cached can be logged when edited or
+    /// deleted.
+    pub retention: u32,
+}
+
+impl MessagesConfig {
+    /// Default cache retention duration (2 minutes).
+    pub const DEFAULT_RETENTION: u32 = 2 * 60;
+
+    /// Maximum cache retention duration (24 hours).
+    pub const MAX_RETENTION: u32 = 24 * 60 * 60;
+}
+
+impl Default for MessagesConfig {
+    fn default() -> Self {
+        Self {
+            logs: None,
+            retention: Self::DEFAULT_RETENTION,
+        }
+    }
+}
+
+/// Configuration for the raid protection.
+///
+/// When raid mode is enabled, members joining the guild are removed with the
+/// configured [`RaidAction`].
+///
+/// Joins are counted over a sliding window. When the number of joins within
+/// the window reaches the configured threshold, raid mode is enabled
+/// automatically.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct RaidConfig {
+    /// Action performed on members joining while raid mode is enabled.
+    pub action: RaidAction,
+    /// Whether join waves are detected.
+    pub detection: bool,
+    /// Number of joins within the window that triggers raid mode.
+    pub joins: u32,
+    /// Duration of the join counter window, in seconds.
+    pub window: u32,
+    /// Duration of raid mode when enabled automatically, in seconds.
+    pub duration: u32,
+    /// Duration during which joins are kept for mass actions, in seconds.
+    pub history: u32,
+}
+
+impl RaidConfig {
+    /// Default number of joins that triggers raid mode.
+    pub const DEFAULT_JOINS: u32 = 10;
+
+    /// Default duration of the join counter window (10 seconds).
+    pub const DEFAULT_WINDOW: u32 = 10;
+
+    /// Default duration of raid mode when enabled automatically (10 minutes).
+    pub const DEFAULT_DURATION: u32 = 10 * 60;
+
+    /// Maximum number of joins that triggers raid mode.
+    pub const MAX_JOINS: u32 = 100;
+
+    /// Maximum duration of the join counter window (5 minutes).
+    pub const MAX_WINDOW: u32 = 5 * 60;
+
+    /// Maximum duration of raid mode when enabled automatically (24 hours).
+    pub const MAX_DURATION: u32 = 24 * 60 * 60;
+
+    /// Default duration during which joins are kept (24 hours).
+    pub const DEFAULT_HISTORY: u32 = 24 * 60 * 60;
+
+    /// Maximum duration during which joins are kept (7 days).
+    pub const MAX_HISTORY: u32 = 7 * 24 * 60 * 60;
+}
+
+impl Default for RaidConfig {
+    fn default() -> Self {
+        Self {
+            action: RaidAction::Kick,
+            detection: false,
+            joins: Self::DEFAULT_JOINS,
+            window: Self::DEFAULT_WINDOW,
+            duration: Self::DEFAULT_DURATION,
+            history: Self::DEFAULT_HISTORY,
+        }
+    }
+}
+
+/// Action performed on members joining while raid mode is enabled.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RaidAction {
+    /// Kick the member.
+    Kick,
+    /// Ban the member.
+    Ban,
+}
+
+/// Configuration for the account age gate.
+///
+/// Members joining the guild with an account younger than the configured age
+/// are handled with the configured [`AccountAgeAction`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct AccountAgeConfig {
+    /// Whether the account age gate is enabled.
+    pub enabled: bool,
+    /// Minimum age of the accounts, in seconds.
+    pub min_age: u32,
+    /// Action performed on accounts younger than the minimum age.
+    pub action: AccountAgeAction,
+}
+
+impl AccountAgeConfig {
+    /// Default minimum age of the accounts (7 days).
+    pub const DEFAULT_MIN_AGE: u32 = 7 * 24 * 60 * 60;
+
+    /// Maximum minimum age of the accounts (90 days).
+    pub const MAX_MIN_AGE: u32 = 90 * 24 * 60 * 60;
+}
+
+impl Default for AccountAgeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_age: Self::DEFAULT_MIN_AGE,
+            action: AccountAgeAction::Kick,
+        }
+    }
+}
+
+/// Action performed on accounts younger than the minimum age.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountAgeAction {
+    /// Kick the member.
+    Kick,
+    /// Ban the member.
+    Ban,
+    /// Require the member to complete the captcha.
+    Captcha,
+    /// Quarantine the member (see [`QuarantineConfig`]).
+    Quarantine,
+}
+
+/// Configuration for the risk scoring of new members.
+///
+/// A risk score is computed for each member joining the guild, and can be used
+/// to require the captcha only for risky members or to quarantine them.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct RiskConfig {
+    /// Channel where joins are logged with the risk score of the member.
+    #[serde_as(as = "Option<IdAsI64>")]
+    pub logs: Option<Id<ChannelMarker>>,
+    /// Minimum risk score requiring the captcha.
+    ///
+    /// If set to `0`, the captcha is required for all members.
+    pub captcha: u8,
+    /// Minimum risk score for members to be quarantined.
+    ///
+    /// Members are quarantined with the role configured in
+    /// [`QuarantineConfig::role`].
+    pub quarantine: Option<u8>,
+}
+
+/// Configuration for the username and nickname rules.
+///
+/// Members whose username or nickname matches one of the configured
+/// [`NameRule`] are handled with the configured [`NameAction`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct NamesConfig {
+    /// Action performed on members with a matching name.
+    pub action: NameAction,
+    /// Configured name rules.
+    pub rules: Vec<NameRule>,
+}
+
+impl NamesConfig {
+    /// Maximum number of name rules per guild.
+    pub const MAX_RULES: usize = 20;
+}
+
+impl Default for NamesConfig {
+    fn default() -> Self {
+        Self {
+            action: NameAction::Rename,
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// Action performed on members with a name matching a [`NameRule`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NameAction {
+    /// Replace the nickname of the member.
+    Rename,
+    /// Kick the member.
+    Kick,
+    /// Ban the member.
+    Ban,
+}
+
+/// Rule matched against the usernames and nicknames of members.
+///
+/// Names are normalized before being matched (converted to ASCII and
+/// lowercase), so rules are case-insensitive and match common lookalike
+/// characters.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct NameRule {
+    /// Kind of the rule.
+    pub kind: NameRuleKind,
+    /// Pattern of the rule.
+    pub pattern: String,
+}
+
+impl NameRule {
+    /// Maximum length of a rule pattern.
+    pub const MAX_PATTERN_LEN: usize = 100;
+}
+
+/// Kind of a [`NameRule`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NameRuleKind {
+    /// The name contains the pattern.
+    Substring,
+    /// The whole name matches the pattern, where `*` matches any sequence of
+    /// characters, `?` any character and `#` any digit.
+    Wildcard,
+    /// The name matches the regex pattern.
+    Regex,
+}
+
+/// Configuration for the anti-nuke protection.
+///
+/// Destructive actions (channel and role deletions, bans, kicks and webhook
+/// creations) are counted for each guild. When more than `threshold` actions of
+/// the same kind are performed within `window` seconds, the member responsible
+/// for them is handled with the configured [`AntinukeAction`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct AntinukeConfig {
+    /// Whether the anti-nuke protection is enabled.
+    pub enabled: bool,
+    /// Number of actions of the same kind that trigger the protection.
+    pub threshold: u32,
+    /// Time window in which actions are counted, in seconds.
+    pub window: u32,
+    /// Action performed on the member responsible for the actions.
+    pub action: AntinukeAction,
+}
+
+impl AntinukeConfig {
+    /// Default number of actions that trigger the protection.
+    pub const DEFAULT_THRESHOLD: u32 = 5;
+
+    /// Default time window (10 seconds).
+    pub const DEFAULT_WINDOW: u32 = 10;
+
+    /// Maximum number of actions that trigger the protection.
+    pub const MAX_THRESHOLD: u32 = 50;
+
+    /// Maximum time window (5 minutes).
+    pub const MAX_WINDOW: u32 = 5 * 60;
+}
+
+impl Default for AntinukeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: Self::DEFAULT_THRESHOLD,
+            window: Self::DEFAULT_WINDOW,
+            action: AntinukeAction::StripRoles,
+        }
+    }
+}
+
+/// Action performed on the member responsible for destructive actions.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AntinukeAction {
+    /// Remove the roles of the member.
+    StripRoles,
+    /// Quarantine the member (see [`QuarantineConfig`]).
+    ///
+    /// The removed roles are saved and can be restored with `/unquarantine`.
+    Quarantine,
+}
+
+/// Configuration for the guild structure backups.
+///
+/// Snapshots of the guild roles and channels can be created manually with the
+/// `/backup` command, or automatically every `interval` seconds.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// Interval between automatic snapshots, in seconds.
+    ///
+    /// Automatic snapshots are disabled if [`None`].
+    pub interval: Option<u32>,
+}
+
+impl BackupConfig {
+    /// Minimum interval between automatic snapshots (1 hour).
+    pub const MIN_INTERVAL: u32 = 60 * 60;
+
+    /// Maximum interval between automatic snapshots (30 days).
+    pub const MAX_INTERVAL: u32 = 30 * 24 * 60 * 60;
+}
+
+/// Configuration for the quarantine.
+///
+/// Quarantined members have their roles removed and are given the quarantine
+/// role, which is hidden from all the channels of the guild. The removed roles
+/// are saved in the `quarantines` collection and restored when the member is
+/// released from quarantine.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct QuarantineConfig {
+    /// Role given to quarantined members.
+    #[serde_as(as = "Option<IdAsI64>")]
+    pub role: Option<Id<RoleMarker>>,
+}
+
+/// Configuration for the role persistence.
+///
+/// The roles of a member are saved when it leaves the guild, and reapplied
+/// when it joins again. The quarantine and captcha unverified roles are always
+/// persisted, so that sanctions cannot be removed by leaving the guild.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct PersistConfig {
+    /// Additional roles to persist, such as a mute role.
+    #[serde_as(as = "Vec<IdAsI64>")]
+    pub roles: Vec<Id<RoleMarker>>,
+    /// Whether all the roles of returning members are restored.
+    pub all: bool,
+}
+
+impl PersistConfig {
+    /// Max length of the `roles` field.
+    pub const MAX_ROLES_LEN: usize = 10;
+}
+
+/// Configuration for the auto-moderation module.
+///
+/// Each filter of the auto-moderation module is configured with an
+/// [`AutomodRule`], identified by a [`AutomodRuleKind`].
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct AutomodConfig {
+    /// Filter for Discord invite links.
+    pub invites: AutomodRule,
+    /// Filter for phishing links.
+    pub phishing: AutomodRule,
+    /// Filter for messages with hidden content.
+    pub hidden: AutomodRule,
+    /// Filter for messages posted in multiple channels.
+    pub duplicates: AutomodRule,
+    /// Filter for messages posted by multiple accounts.
+    pub coordinated: AutomodRule,
+    /// Custom regex filters.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub regex: Vec<RegexFilter>,
+}
+
+impl AutomodConfig {
+    /// Get the configuration of a rule.
+    pub fn rule(&self, kind: AutomodRuleKind) -> &AutomodRule {
+        match kind {
+            AutomodRuleKind::Invites => &self.invites,
+            AutomodRuleKind::Phishing => &self.phishing,
+            AutomodRuleKind::Hidden => &self.hidden,
+            AutomodRuleKind::Duplicates => &self.duplicates,
+            AutomodRuleKind::Coordinated => &self.coordinated,
+        }
+    }
+
+    /// Get a mutable reference to the configuration of a rule.
+    pub fn rule_mut(&mut self, kind: AutomodRuleKind) -> &mut AutomodRule {
+        match kind {
+            AutomodRuleKind::Invites => &mut self.invites,
+            AutomodRuleKind::Phishing => &mut self.phishing,
+            AutomodRuleKind::Hidden => &mut self.hidden,
+            AutomodRuleKind::Duplicates => &mut self.duplicates,
+            AutomodRuleKind::Coordinated => &mut self.coordinated,
+        }
+    }
+}
+
+/// Configuration of an auto-moderation rule.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct AutomodRule {
+    /// Mode of the rule.
+    pub mode: RuleMode,
+    /// Action performed when a message matches the rule.
+    pub action: AutomodAction,
+    /// Duration of the timeout in seconds.
+    ///
+    /// This is only used with [`AutomodAction::Timeout`].
+    pub timeout: u32,
+}
+
+impl AutomodRule {
+    /// Default duration of the timeout (10 minutes).
+    pub const DEFAULT_TIMEOUT: u32 = 10 * 60;
+
+    /// Maximum duration of the timeout (28 days).
+    pub const MAX_TIMEOUT: u32 = 28 * 24 * 60 * 60;
+}
+
+impl Default for AutomodRule {
+    fn default() -> Self {
+        Self {
+            mode: RuleMode::Disabled,
+            action: AutomodAction::Delete,
+            timeout: Self::DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+/// Custom regex filter of the auto-moderation module.
+///
+/// Regex filters are configured by each guild and are evaluated after the
+/// built-in rules.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct RegexFilter {
+    /// Name of the filter, unique in a guild.
+    pub name: String,
+    /// Regex pattern matched against the message content.
+    pub pattern: String,
+    /// Configuration of the filter.
+    pub rule: AutomodRule,
+}
+
+impl RegexFilter {
+    /// Maximum number of regex filters per guild.
+    pub const MAX_FILTERS: usize = 10;
+
+    /// Maximum length of a filter name.
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Maximum length of a regex pattern.
+    pub const MAX_PATTERN_LEN: usize = 256;
+
+    /// Identifier of the filter.
+    ///
+    /// This identifier is used in the cache, and should not be shown to
+    /// users.
+    pub fn id(&self) -> String {
+        format!("regex:{}", self.name)
+    }
+}
+
+/// Mode of an auto-moderation rule.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleMode {
+    /// The rule is not evaluated.
+    Disabled,
+    /// The rule is evaluated, but no action is performed.
+    ///
+    /// Matching messages are reported in the logs channel with the action that
+    /// would have been performed. This allows to tune the rule before enforcing
+    /// it.
+    Monitor,
+    /// The rule is evaluated and the configured action is performed.
+    Enforce,
+}
+
+/// Action performed when a message matches an auto-moderation rule.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AutomodAction {
+    /// Delete the message.
+    Delete,
+    /// Delete the message and timeout its author.
+    Timeout,
+    /// Delete the message and quarantine its author.
+    Quarantine,
+}
+
+/// Identifier of an auto-moderation rule.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum AutomodRuleKind {
+    /// Discord invite links filter.
+    Invites,
+    /// Phishing links filter.
+    Phishing,
+    /// Hidden content filter (invisible characters and spoiler chains).
+    Hidden,
+    /// Cross-channel duplicate messages filter.
+    Duplicates,
+    /// Coordinated multi-account spam filter.
+    Coordinated,
+}
+
+impl AutomodRuleKind {
+    /// List of all rules.
+    pub const ALL: [AutomodRuleKind; 5] = [
+        AutomodRuleKind::Invites,
+        AutomodRuleKind::Phishing,
+        AutomodRuleKind::Hidden,
+        AutomodRuleKind::Duplicates,
+        AutomodRuleKind::Coordinated,
+    ];
+
+    /// Name of the rule.
+    ///
+    /// This name is used as an identifier (e.g. in the cache), and should not
+    /// be shown to users.
+    pub fn name(self) -> &'static str {
+        match self {
+            AutomodRuleKind::Invites => "invites",
+            AutomodRuleKind::Phishing => "phishing",
+            AutomodRuleKind::Hidden => "hidden",
+            AutomodRuleKind::Duplicates => "duplicates",
+            AutomodRuleKind::Coordinated => "coordinated",
+        }
+    }
+}
+
 // Implementation of methods to query the database.
 impl DbClient {
     /// Get the [`GuildConfig`] for a given guild_id, if it exists.
+    ///
+    /// Configurations are cached in memory for [`GuildConfigCache::TTL`], and
+    /// the cached value is replaced when calling [`DbClient::update_guild`].
+    /// Guilds without configuration are cached too, so this method can be used
+    /// when processing frequent events such as messages.
     pub async fn get_guild(
         &self,
         guild_id: Id<GuildMarker>,
     ) -> Result<Option<GuildConfig>, anyhow::Error> {
+        if let Some(guild) = self.guilds.get(guild_id) {
+            return Ok(guild);
+        }
+
         let query = GuildQuery { id: guild_id };
 
         let guild = self
@@ -146,6 +742,8 @@ impl DbClient {
             .find_one(to_document(&query)?, None)
             .await?;
 
+        self.guilds.insert(guild_id, guild.clone());
+
         Ok(guild)
     }
 
@@ -154,6 +752,10 @@ impl DbClient {
         &self,
         guild_id: Id<GuildMarker>,
     ) -> Result<GuildConfig, anyhow::Error> {
+        if let Some(Some(guild)) = self.guilds.get(guild_id) {
+            return Ok(guild);
+        }
+
         let query = GuildQuery { id: guild_id };
         let default_guild = GuildConfig::new(guild_id);
         let options = options::FindOneAndUpdateOptions::builder()
@@ -169,9 +771,12 @@ impl DbClient {
                 doc! { "$setOnInsert": to_document(&default_guild)? },
                 options,
             )
-            .await?;
+            .await?
+            .context("no guild sent by the database")?;
 
-        guild.context("no guild sent by the database")
+        self.guilds.insert(guild_id, Some(guild.clone()));
+
+        Ok(guild)
     }
 
     /// Update or insert a [`GuildConfig`] in the database.
@@ -184,8 +789,115 @@ impl DbClient {
             .replace_one(to_document(&query)?, guild, options)
             .await?;
 
+        self.guilds.insert(guild.id, Some(guild.clone()));
+
         Ok(())
     }
+
+    /// Find the [`GuildConfig`] of guilds with automatic backups enabled.
+    pub async fn find_scheduled_backups(&self) -> Result<Cursor<GuildConfig>, anyhow::Error> {
+        let query = doc! { "backup.interval": { "$ne": null } };
+
+        let cursor = self
+            .db()
+            .collection::<GuildConfig>(GuildConfig::COLLECTION)
+            .find(query, None)
+            .await?;
+
+        Ok(cursor)
+    }
+}
+
+/// In-process cache of [`GuildConfig`].
+///
+/// Configurations are only updated by the bot with [`DbClient::update_guild`],
+/// which replaces the cached value. Entries expire after [`Self::TTL`] to pick
+/// up changes made directly in the database.
+#[derive(Debug, Clone, Default)]
+pub(super) struct GuildConfigCache {
+    guilds: Arc<RwLock<HashMap<Id<GuildMarker>, CachedGuildConfig>>>,
+}
+
+/// Cached configuration with its insertion time.
+type CachedGuildConfig = (Instant, Option<GuildConfig>);
+
+impl GuildConfigCache {
+    /// Duration during which a cached configuration is valid.
+    pub const TTL: Duration = Duration::from_secs(5 * 60);
+
+    /// Get a cached configuration.
+    ///
+    /// Returns [`None`] if the guild is not cached, or `Some(None)` if the
+    /// guild has no configuration. Expired entries are removed from the cache.
+    fn get(&self, guild_id: Id<GuildMarker>) -> Option<Option<GuildConfig>> {
+        {
+            let guilds = self.guilds.read().unwrap_or_else(PoisonError::into_inner);
+
+            match guilds.get(&guild_id) {
+                Some((inserted, guild)) if inserted.elapsed() < Self::TTL => {
+                    return Some(guild.clone())
+                }
+                Some(_) => {}
+                None => return None,
+            }
+        }
+
+        let mut guilds = self.guilds.write().unwrap_or_else(PoisonError::into_inner);
+
+        // The entry may have been refreshed since the read lock was released.
+        if let Some((inserted, guild)) = guilds.get(&guild_id) {
+            if inserted.elapsed() < Self::TTL {
+                return Some(guild.clone());
+            }
+
+            guilds.remove(&guild_id);
+        }
+
+        None
+    }
+
+    /// Insert a configuration in the cache.
+    fn insert(&self, guild_id: Id<GuildMarker>, guild: Option<GuildConfig>) {
+        let mut guilds = self.guilds.write().unwrap_or_else(PoisonError::into_inner);
+
+        guilds.insert(guild_id, (Instant::now(), guild));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guild_config_cache() {
+        let cache = GuildConfigCache::default();
+        let guild_id = Id::new(1);
+
+        assert_eq!(cache.get(guild_id), None);
+
+        cache.insert(guild_id, None);
+        assert_eq!(cache.get(guild_id), Some(None));
+
+        let config = GuildConfig::new(guild_id);
+        cache.insert(guild_id, Some(config.clone()));
+        assert_eq!(cache.get(guild_id), Some(Some(config)));
+    }
+
+    #[test]
+    fn test_guild_config_cache_expired() {
+        let cache = GuildConfigCache::default();
+        let guild_id = Id::new(1);
+        let expired = Instant::now() - GuildConfigCache::TTL;
+
+        cache
+            .guilds
+            .write()
+            .unwrap()
+            .insert(guild_id, (expired, None));
+
+        assert_eq!(cache.get(guild_id), None);
+        assert!(cache.guilds.read().unwrap().is_empty());
+    }
 }
 
 /// Query a guild with its guild_id
@@ -7,15 +7,25 @@
 //! The following collections are used:
 //! - `guilds` ([GuildConfig]): configuration for guilds that uses the bot
 //! - `modlogs` ([Modlog]): moderation logs
+//! - `backups` ([Backup]): snapshots of the structure of guilds
+//! - `backup_versions`: latest version of the snapshots of each guild
+//! - `quarantines` ([Quarantine]): roles of quarantined members
+//! - `persisted_roles` ([PersistedRoles]): roles of members that left a guild
 //!
 //! Each collection name is exported as an associated constant.
 //!
+//! [Backup]: backup::Backup
 //! [GuildConfig]: guild::GuildConfig
 //! [Modlog]: modlog::Modlog
+//! [PersistedRoles]: persist::PersistedRoles
+//! [Quarantine]: quarantine::Quarantine
 
+mod backup;
 mod client;
 mod guild;
 mod modlog;
+mod persist;
+mod quarantine;
 
 pub use client::DbClient;
 
@@ -25,7 +35,16 @@ pub mod model {
     //! See the [module documentation](crate::database) for more information.
 
     pub use super::{
-        guild::{CaptchaConfig, GuildConfig, ModerationConfig},
+        backup::{Backup, BackupChannel, BackupOverwrite, BackupRole},
+        guild::{
+            AccountAgeAction, AccountAgeConfig, AntinukeAction, AntinukeConfig, AutomodAction,
+            AutomodConfig, AutomodRule, AutomodRuleKind, BackupConfig, CaptchaConfig, GuildConfig,
+            MessagesConfig, ModerationConfig, NameAction, NameRule, NameRuleKind, NamesConfig,
+            PersistConfig, QuarantineConfig, RaidAction, RaidConfig, RegexFilter, RiskConfig,
+            RuleMode,
+        },
         modlog::{Modlog, ModlogType, ModlogUser},
+        persist::PersistedRoles,
+        quarantine::Quarantine,
     };
 }
@@ -13,6 +13,7 @@ use twilight_model::{
         marker::{GuildMarker, UserMarker},
         Id,
     },
+    user::User,
     util::ImageHash,
 };
 
@@ -79,6 +80,17 @@ pub struct ModlogUser {
     pub avatar: Option<ImageHash>,
 }
 
+impl From<&User> for ModlogUser {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name.clone(),
+            discriminator: user.discriminator,
+            avatar: user.avatar,
+        }
+    }
+}
+
 // Implementation of methods to query the database.
 impl DbClient {
     /// Insert a new [`Modlog`] in the database.
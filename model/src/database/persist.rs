@@ -0,0 +1,91 @@
+//! Models for the `persisted_roles` collection.
+
+use mongodb::{bson::doc, options};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use super::DbClient;
+use crate::serde::{DateTimeAsBson, IdAsI64};
+
+/// Roles of a member that left a guild.
+///
+/// This type represent the roles saved when a member leaves a guild, stored in
+/// the `persisted_roles` collection of the database. The roles are reapplied
+/// when the member joins the guild again.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PersistedRoles {
+    /// Guild the member left.
+    #[serde_as(as = "IdAsI64")]
+    pub guild_id: Id<GuildMarker>,
+    /// User that left the guild.
+    #[serde_as(as = "IdAsI64")]
+    pub user_id: Id<UserMarker>,
+    /// Roles of the member when it left the guild.
+    #[serde_as(as = "Vec<IdAsI64>")]
+    pub roles: Vec<Id<RoleMarker>>,
+    /// Date at which the member left the guild.
+    #[serde_as(as = "DateTimeAsBson")]
+    pub date: OffsetDateTime,
+}
+
+impl PersistedRoles {
+    /// Name of the MongoDB collection.
+    pub const COLLECTION: &'static str = "persisted_roles";
+}
+
+impl DbClient {
+    /// Insert or replace the [`PersistedRoles`] of a member.
+    pub async fn set_persisted_roles(&self, roles: &PersistedRoles) -> Result<(), anyhow::Error> {
+        let query = doc! {
+            "guild_id": roles.guild_id.get() as i64,
+            "user_id": roles.user_id.get() as i64,
+        };
+        let options = options::ReplaceOptions::builder().upsert(true).build();
+
+        self.db()
+            .collection::<PersistedRoles>(PersistedRoles::COLLECTION)
+            .replace_one(query, roles, options)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the [`PersistedRoles`] of a member.
+    pub async fn get_persisted_roles(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<PersistedRoles>, anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64, "user_id": user_id.get() as i64 };
+
+        let roles = self
+            .db()
+            .collection::<PersistedRoles>(PersistedRoles::COLLECTION)
+            .find_one(query, None)
+            .await?;
+
+        Ok(roles)
+    }
+
+    /// Delete the [`PersistedRoles`] of a member.
+    pub async fn delete_persisted_roles(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(), anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64, "user_id": user_id.get() as i64 };
+
+        self.db()
+            .collection::<PersistedRoles>(PersistedRoles::COLLECTION)
+            .delete_one(query, None)
+            .await?;
+
+        Ok(())
+    }
+}
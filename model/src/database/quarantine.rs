@@ -0,0 +1,103 @@
+//! Models for the `quarantines` collection.
+
+use mongodb::{bson::doc, options};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use super::DbClient;
+use crate::serde::{DateTimeAsBson, IdAsI64};
+
+/// Quarantined member.
+///
+/// This type represent a quarantined member stored in the `quarantines`
+/// collection of the database. The roles removed from the member are saved to
+/// be restored when the member is released from quarantine.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Quarantine {
+    /// Guild of the quarantined member.
+    #[serde_as(as = "IdAsI64")]
+    pub guild_id: Id<GuildMarker>,
+    /// Quarantined user.
+    #[serde_as(as = "IdAsI64")]
+    pub user_id: Id<UserMarker>,
+    /// Roles removed from the member.
+    #[serde_as(as = "Vec<IdAsI64>")]
+    pub roles: Vec<Id<RoleMarker>>,
+    /// Moderator that quarantined the member.
+    ///
+    /// This is [`None`] if the member has been quarantined automatically.
+    #[serde_as(as = "Option<IdAsI64>")]
+    #[serde(default)]
+    pub moderator_id: Option<Id<UserMarker>>,
+    /// Optional reason of the quarantine.
+    pub reason: Option<String>,
+    /// Date of the quarantine.
+    #[serde_as(as = "DateTimeAsBson")]
+    pub date: OffsetDateTime,
+}
+
+impl Quarantine {
+    /// Name of the MongoDB collection.
+    pub const COLLECTION: &'static str = "quarantines";
+}
+
+impl DbClient {
+    /// Insert or replace the [`Quarantine`] of a member.
+    pub async fn set_quarantine(&self, quarantine: &Quarantine) -> Result<(), anyhow::Error> {
+        let query = doc! {
+            "guild_id": quarantine.guild_id.get() as i64,
+            "user_id": quarantine.user_id.get() as i64,
+        };
+        let options = options::ReplaceOptions::builder().upsert(true).build();
+
+        self.db()
+            .collection::<Quarantine>(Quarantine::COLLECTION)
+            .replace_one(query, quarantine, options)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the [`Quarantine`] of a member.
+    pub async fn get_quarantine(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<Quarantine>, anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64, "user_id": user_id.get() as i64 };
+
+        let quarantine = self
+            .db()
+            .collection::<Quarantine>(Quarantine::COLLECTION)
+            .find_one(query, None)
+            .await?;
+
+        Ok(quarantine)
+    }
+
+    /// Delete the [`Quarantine`] of a member.
+    ///
+    /// Returns `false` if the member was not quarantined.
+    pub async fn delete_quarantine(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<bool, anyhow::Error> {
+        let query = doc! { "guild_id": guild_id.get() as i64, "user_id": user_id.get() as i64 };
+
+        let result = self
+            .db()
+            .collection::<Quarantine>(Quarantine::COLLECTION)
+            .delete_one(query, None)
+            .await?;
+
+        Ok(result.deleted_count > 0)
+    }
+}
@@ -0,0 +1,94 @@
+use mongodb::bson::{self, oid::ObjectId, DateTime};
+use pretty_assertions::assert_eq;
+use raidprotect_model::database::model::{Backup, BackupChannel, BackupOverwrite, BackupRole};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::{permission_overwrite::PermissionOverwriteType, ChannelType},
+    guild::Permissions,
+    id::Id,
+};
+
+#[test]
+fn test_backup_bson() {
+    let backup = Backup {
+        id: Some(ObjectId::parse_str("62aca55a551e9a0102351bda").unwrap()),
+        guild_id: Id::new(1),
+        version: 3,
+        created_at: OffsetDateTime::from_unix_timestamp(1_628_594_197_123).unwrap(),
+        created_by: None,
+        roles: vec![BackupRole {
+            id: Id::new(2),
+            name: "moderator".to_string(),
+            color: 0xff0000,
+            position: 4,
+            permissions: Permissions::KICK_MEMBERS | Permissions::BAN_MEMBERS,
+            managed: false,
+        }],
+        channels: vec![
+            BackupChannel {
+                id: Id::new(3),
+                kind: ChannelType::GuildCategory,
+                name: "general".to_string(),
+                parent_id: None,
+                position: Some(0),
+                rate_limit_per_user: None,
+                permission_overwrites: Vec::new(),
+            },
+            BackupChannel {
+                id: Id::new(4),
+                kind: ChannelType::GuildText,
+                name: "chat".to_string(),
+                parent_id: Some(Id::new(3)),
+                position: Some(1),
+                rate_limit_per_user: Some(5),
+                permission_overwrites: vec![BackupOverwrite {
+                    id: Id::new(2),
+                    kind: PermissionOverwriteType::Role,
+                    allow: Permissions::MANAGE_MESSAGES,
+                    deny: Permissions::empty(),
+                }],
+            },
+        ],
+    };
+
+    let expected = bson::doc! {
+        "_id": ObjectId::parse_str("62aca55a551e9a0102351bda").unwrap(),
+        "guild_id": 1_i64,
+        "version": 3_i64,
+        "created_at": DateTime::from_millis(1_628_594_197_123),
+        "roles": [{
+            "id": 2_i64,
+            "name": "moderator",
+            "color": 0xff0000_i64,
+            "position": 4_i64,
+            "permissions": "6",
+            "managed": false,
+        }],
+        "channels": [
+            {
+                "id": 3_i64,
+                "kind": 4_i32,
+                "name": "general",
+                "position": 0_i32,
+                "permission_overwrites": [],
+            },
+            {
+                "id": 4_i64,
+                "kind": 0_i32,
+                "name": "chat",
+                "parent_id": 3_i64,
+                "position": 1_i32,
+                "rate_limit_per_user": 5_i32,
+                "permission_overwrites": [{
+                    "id": 2_i64,
+                    "kind": 0_i32,
+                    "allow": "8192",
+                    "deny": "0",
+                }],
+            },
+        ],
+    };
+
+    assert_eq!(bson::to_document(&backup).unwrap(), expected);
+    assert_eq!(bson::from_document::<Backup>(expected).unwrap(), backup);
+}
@@ -1,6 +1,11 @@
 use mongodb::bson;
 use pretty_assertions::assert_eq;
-use raidprotect_model::database::model::{CaptchaConfig, GuildConfig, ModerationConfig};
+use raidprotect_model::database::model::{
+    AccountAgeAction, AccountAgeConfig, AntinukeAction, AntinukeConfig, AutomodAction,
+    AutomodConfig, AutomodRule, BackupConfig, CaptchaConfig, GuildConfig, MessagesConfig,
+    ModerationConfig, NameAction, NameRule, NameRuleKind, NamesConfig, PersistConfig,
+    QuarantineConfig, RaidAction, RaidConfig, RegexFilter, RiskConfig, RuleMode,
+};
 use serde_test::{assert_tokens, Token};
 use twilight_model::id::Id;
 
@@ -13,7 +18,7 @@ fn test_guild_default() {
         &[
             Token::Struct {
                 name: "GuildConfig",
-                len: 5,
+                len: 15,
             },
             Token::Str("_id"),
             Token::I64(1),
@@ -39,6 +44,216 @@ fn test_guild_default() {
             Token::Str("enabled"),
             Token::Bool(false),
             Token::StructEnd,
+            Token::Str("automod"),
+            Token::Struct {
+                name: "AutomodConfig",
+                len: 5,
+            },
+            Token::Str("invites"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "disabled",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::Str("phishing"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "disabled",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::Str("hidden"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "disabled",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::Str("duplicates"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "disabled",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::Str("coordinated"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "disabled",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::Str("messages"),
+            Token::Struct {
+                name: "MessagesConfig",
+                len: 1,
+            },
+            Token::Str("retention"),
+            Token::U32(120),
+            Token::StructEnd,
+            Token::Str("raid"),
+            Token::Struct {
+                name: "RaidConfig",
+                len: 6,
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "RaidAction",
+                variant: "kick",
+            },
+            Token::Str("detection"),
+            Token::Bool(false),
+            Token::Str("joins"),
+            Token::U32(10),
+            Token::Str("window"),
+            Token::U32(10),
+            Token::Str("duration"),
+            Token::U32(600),
+            Token::Str("history"),
+            Token::U32(86400),
+            Token::StructEnd,
+            Token::Str("account_age"),
+            Token::Struct {
+                name: "AccountAgeConfig",
+                len: 3,
+            },
+            Token::Str("enabled"),
+            Token::Bool(false),
+            Token::Str("min_age"),
+            Token::U32(604800),
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AccountAgeAction",
+                variant: "kick",
+            },
+            Token::StructEnd,
+            Token::Str("risk"),
+            Token::Struct {
+                name: "RiskConfig",
+                len: 3,
+            },
+            Token::Str("logs"),
+            Token::None,
+            Token::Str("captcha"),
+            Token::U8(0),
+            Token::Str("quarantine"),
+            Token::None,
+            Token::StructEnd,
+            Token::Str("names"),
+            Token::Struct {
+                name: "NamesConfig",
+                len: 2,
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "NameAction",
+                variant: "rename",
+            },
+            Token::Str("rules"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::StructEnd,
+            Token::Str("antinuke"),
+            Token::Struct {
+                name: "AntinukeConfig",
+                len: 4,
+            },
+            Token::Str("enabled"),
+            Token::Bool(false),
+            Token::Str("threshold"),
+            Token::U32(5),
+            Token::Str("window"),
+            Token::U32(10),
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AntinukeAction",
+                variant: "strip_roles",
+            },
+            Token::StructEnd,
+            Token::Str("backup"),
+            Token::Struct {
+                name: "BackupConfig",
+                len: 1,
+            },
+            Token::Str("interval"),
+            Token::None,
+            Token::StructEnd,
+            Token::Str("quarantine"),
+            Token::Struct {
+                name: "QuarantineConfig",
+                len: 1,
+            },
+            Token::Str("role"),
+            Token::None,
+            Token::StructEnd,
+            Token::Str("persist"),
+            Token::Struct {
+                name: "PersistConfig",
+                len: 2,
+            },
+            Token::Str("roles"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("all"),
+            Token::Bool(false),
+            Token::StructEnd,
             Token::StructEnd,
         ],
     );
@@ -63,6 +278,87 @@ fn test_guild_full() {
             verified_roles: vec![Id::new(8), Id::new(9)],
             logs: Some(Id::new(10)),
         },
+        automod: AutomodConfig {
+            invites: AutomodRule {
+                mode: RuleMode::Monitor,
+                action: AutomodAction::Timeout,
+                timeout: 60,
+            },
+            phishing: AutomodRule {
+                mode: RuleMode::Enforce,
+                action: AutomodAction::Timeout,
+                timeout: 3600,
+            },
+            hidden: AutomodRule {
+                mode: RuleMode::Monitor,
+                action: AutomodAction::Delete,
+                timeout: 600,
+            },
+            duplicates: AutomodRule {
+                mode: RuleMode::Enforce,
+                action: AutomodAction::Timeout,
+                timeout: 86400,
+            },
+            coordinated: AutomodRule {
+                mode: RuleMode::Monitor,
+                action: AutomodAction::Delete,
+                timeout: 600,
+            },
+            regex: vec![RegexFilter {
+                name: "scam".to_string(),
+                pattern: "free nitro".to_string(),
+                rule: AutomodRule {
+                    mode: RuleMode::Enforce,
+                    action: AutomodAction::Delete,
+                    timeout: 600,
+                },
+            }],
+        },
+        messages: MessagesConfig {
+            logs: Some(Id::new(11)),
+            retention: 3600,
+        },
+        raid: RaidConfig {
+            action: RaidAction::Ban,
+            detection: true,
+            joins: 15,
+            window: 30,
+            duration: 1800,
+            history: 3600,
+        },
+        account_age: AccountAgeConfig {
+            enabled: true,
+            min_age: 86400,
+            action: AccountAgeAction::Quarantine,
+        },
+        risk: RiskConfig {
+            logs: Some(Id::new(13)),
+            captcha: 40,
+            quarantine: Some(80),
+        },
+        names: NamesConfig {
+            action: NameAction::Kick,
+            rules: vec![NameRule {
+                kind: NameRuleKind::Wildcard,
+                pattern: "discord-staff-####".to_string(),
+            }],
+        },
+        antinuke: AntinukeConfig {
+            enabled: true,
+            threshold: 3,
+            window: 30,
+            action: AntinukeAction::Quarantine,
+        },
+        backup: BackupConfig {
+            interval: Some(86400),
+        },
+        quarantine: QuarantineConfig {
+            role: Some(Id::new(12)),
+        },
+        persist: PersistConfig {
+            roles: vec![Id::new(13)],
+            all: true,
+        },
     };
 
     assert_tokens(
@@ -70,7 +366,7 @@ fn test_guild_full() {
         &[
             Token::Struct {
                 name: "GuildConfig",
-                len: 5,
+                len: 15,
             },
             Token::Str("_id"),
             Token::I64(1),
@@ -121,6 +417,268 @@ fn test_guild_full() {
             Token::Some,
             Token::I64(10),
             Token::StructEnd,
+            // automod
+            Token::Str("automod"),
+            Token::Struct {
+                name: "AutomodConfig",
+                len: 6,
+            },
+            Token::Str("invites"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "monitor",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "timeout",
+            },
+            Token::Str("timeout"),
+            Token::U32(60),
+            Token::StructEnd,
+            Token::Str("phishing"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "enforce",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "timeout",
+            },
+            Token::Str("timeout"),
+            Token::U32(3600),
+            Token::StructEnd,
+            Token::Str("hidden"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "monitor",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::Str("duplicates"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "enforce",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "timeout",
+            },
+            Token::Str("timeout"),
+            Token::U32(86400),
+            Token::StructEnd,
+            Token::Str("coordinated"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "monitor",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::Str("regex"),
+            Token::Seq { len: Some(1) },
+            Token::Struct {
+                name: "RegexFilter",
+                len: 3,
+            },
+            Token::Str("name"),
+            Token::Str("scam"),
+            Token::Str("pattern"),
+            Token::Str("free nitro"),
+            Token::Str("rule"),
+            Token::Struct {
+                name: "AutomodRule",
+                len: 3,
+            },
+            Token::Str("mode"),
+            Token::UnitVariant {
+                name: "RuleMode",
+                variant: "enforce",
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AutomodAction",
+                variant: "delete",
+            },
+            Token::Str("timeout"),
+            Token::U32(600),
+            Token::StructEnd,
+            Token::StructEnd,
+            Token::SeqEnd,
+            Token::StructEnd,
+            // messages
+            Token::Str("messages"),
+            Token::Struct {
+                name: "MessagesConfig",
+                len: 2,
+            },
+            Token::Str("logs"),
+            Token::Some,
+            Token::I64(11),
+            Token::Str("retention"),
+            Token::U32(3600),
+            Token::StructEnd,
+            Token::Str("raid"),
+            Token::Struct {
+                name: "RaidConfig",
+                len: 6,
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "RaidAction",
+                variant: "ban",
+            },
+            Token::Str("detection"),
+            Token::Bool(true),
+            Token::Str("joins"),
+            Token::U32(15),
+            Token::Str("window"),
+            Token::U32(30),
+            Token::Str("duration"),
+            Token::U32(1800),
+            Token::Str("history"),
+            Token::U32(3600),
+            Token::StructEnd,
+            Token::Str("account_age"),
+            Token::Struct {
+                name: "AccountAgeConfig",
+                len: 3,
+            },
+            Token::Str("enabled"),
+            Token::Bool(true),
+            Token::Str("min_age"),
+            Token::U32(86400),
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AccountAgeAction",
+                variant: "quarantine",
+            },
+            Token::StructEnd,
+            Token::Str("risk"),
+            Token::Struct {
+                name: "RiskConfig",
+                len: 3,
+            },
+            Token::Str("logs"),
+            Token::Some,
+            Token::I64(13),
+            Token::Str("captcha"),
+            Token::U8(40),
+            Token::Str("quarantine"),
+            Token::Some,
+            Token::U8(80),
+            Token::StructEnd,
+            Token::Str("names"),
+            Token::Struct {
+                name: "NamesConfig",
+                len: 2,
+            },
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "NameAction",
+                variant: "kick",
+            },
+            Token::Str("rules"),
+            Token::Seq { len: Some(1) },
+            Token::Struct {
+                name: "NameRule",
+                len: 2,
+            },
+            Token::Str("kind"),
+            Token::UnitVariant {
+                name: "NameRuleKind",
+                variant: "wildcard",
+            },
+            Token::Str("pattern"),
+            Token::Str("discord-staff-####"),
+            Token::StructEnd,
+            Token::SeqEnd,
+            Token::StructEnd,
+            Token::Str("antinuke"),
+            Token::Struct {
+                name: "AntinukeConfig",
+                len: 4,
+            },
+            Token::Str("enabled"),
+            Token::Bool(true),
+            Token::Str("threshold"),
+            Token::U32(3),
+            Token::Str("window"),
+            Token::U32(30),
+            Token::Str("action"),
+            Token::UnitVariant {
+                name: "AntinukeAction",
+                variant: "quarantine",
+            },
+            Token::StructEnd,
+            Token::Str("backup"),
+            Token::Struct {
+                name: "BackupConfig",
+                len: 1,
+            },
+            Token::Str("interval"),
+            Token::Some,
+            Token::U32(86400),
+            Token::StructEnd,
+            Token::Str("quarantine"),
+            Token::Struct {
+                name: "QuarantineConfig",
+                len: 1,
+            },
+            Token::Str("role"),
+            Token::Some,
+            Token::I64(12),
+            Token::StructEnd,
+            Token::Str("persist"),
+            Token::Struct {
+                name: "PersistConfig",
+                len: 2,
+            },
+            Token::Str("roles"),
+            Token::Seq { len: Some(1) },
+            Token::I64(13),
+            Token::SeqEnd,
+            Token::Str("all"),
+            Token::Bool(true),
+            Token::StructEnd,
             Token::StructEnd,
         ],
     );
@@ -145,6 +703,87 @@ fn test_guild_bson() {
             verified_roles: vec![Id::new(8), Id::new(9)],
             logs: Some(Id::new(10)),
         },
+        automod: AutomodConfig {
+            invites: AutomodRule {
+                mode: RuleMode::Monitor,
+                action: AutomodAction::Timeout,
+                timeout: 60,
+            },
+            phishing: AutomodRule {
+                mode: RuleMode::Enforce,
+                action: AutomodAction::Timeout,
+                timeout: 3600,
+            },
+            hidden: AutomodRule {
+                mode: RuleMode::Monitor,
+                action: AutomodAction::Delete,
+                timeout: 600,
+            },
+            duplicates: AutomodRule {
+                mode: RuleMode::Enforce,
+                action: AutomodAction::Timeout,
+                timeout: 86400,
+            },
+            coordinated: AutomodRule {
+                mode: RuleMode::Monitor,
+                action: AutomodAction::Delete,
+                timeout: 600,
+            },
+            regex: vec![RegexFilter {
+                name: "scam".to_string(),
+                pattern: "free nitro".to_string(),
+                rule: AutomodRule {
+                    mode: RuleMode::Enforce,
+                    action: AutomodAction::Delete,
+                    timeout: 600,
+                },
+            }],
+        },
+        messages: MessagesConfig {
+            logs: Some(Id::new(11)),
+            retention: 3600,
+        },
+        raid: RaidConfig {
+            action: RaidAction::Ban,
+            detection: true,
+            joins: 15,
+            window: 30,
+            duration: 1800,
+            history: 3600,
+        },
+        account_age: AccountAgeConfig {
+            enabled: true,
+            min_age: 86400,
+            action: AccountAgeAction::Quarantine,
+        },
+        risk: RiskConfig {
+            logs: Some(Id::new(13)),
+            captcha: 40,
+            quarantine: Some(80),
+        },
+        names: NamesConfig {
+            action: NameAction::Kick,
+            rules: vec![NameRule {
+                kind: NameRuleKind::Wildcard,
+                pattern: "discord-staff-####".to_string(),
+            }],
+        },
+        antinuke: AntinukeConfig {
+            enabled: true,
+            threshold: 3,
+            window: 30,
+            action: AntinukeAction::Quarantine,
+        },
+        backup: BackupConfig {
+            interval: Some(86400),
+        },
+        quarantine: QuarantineConfig {
+            role: Some(Id::new(12)),
+        },
+        persist: PersistConfig {
+            roles: vec![Id::new(13)],
+            all: true,
+        },
     };
 
     let expected = bson::doc! {
@@ -164,6 +803,87 @@ fn test_guild_bson() {
             "verified_roles": [8_i64, 9_i64],
             "logs": 10_i64,
         },
+        "automod": {
+            "invites": {
+                "mode": "monitor",
+                "action": "timeout",
+                "timeout": 60_i64,
+            },
+            "phishing": {
+                "mode": "enforce",
+                "action": "timeout",
+                "timeout": 3600_i64,
+            },
+            "hidden": {
+                "mode": "monitor",
+                "action": "delete",
+                "timeout": 600_i64,
+            },
+            "duplicates": {
+                "mode": "enforce",
+                "action": "timeout",
+                "timeout": 86400_i64,
+            },
+            "coordinated": {
+                "mode": "monitor",
+                "action": "delete",
+                "timeout": 600_i64,
+            },
+            "regex": [{
+                "name": "scam",
+                "pattern": "free nitro",
+                "rule": {
+                    "mode": "enforce",
+                    "action": "delete",
+                    "timeout": 600_i64,
+                },
+            }],
+        },
+        "messages": {
+            "logs": 11_i64,
+            "retention": 3600_i64,
+        },
+        "raid": {
+            "action": "ban",
+            "detection": true,
+            "joins": 15_i64,
+            "window": 30_i64,
+            "duration": 1800_i64,
+            "history": 3600_i64,
+        },
+        "account_age": {
+            "enabled": true,
+            "min_age": 86400_i64,
+            "action": "quarantine",
+        },
+        "risk": {
+            "logs": 13_i64,
+            "captcha": 40_i32,
+            "quarantine": 80_i32,
+        },
+        "names": {
+            "action": "kick",
+            "rules": [{
+                "kind": "wildcard",
+                "pattern": "discord-staff-####",
+            }],
+        },
+        "antinuke": {
+            "enabled": true,
+            "threshold": 3_i64,
+            "window": 30_i64,
+            "action": "quarantine",
+        },
+        "backup": {
+            "interval": 86400_i64,
+        },
+        "quarantine": {
+            "role": 12_i64,
+        },
+        "persist": {
+            "roles": [13_i64],
+            "all": true,
+        },
     };
 
     assert_eq!(bson::to_document(&guild).unwrap(), expected);
@@ -0,0 +1,57 @@
+use mongodb::bson::{self, DateTime};
+use pretty_assertions::assert_eq;
+use raidprotect_model::database::model::Quarantine;
+use time::OffsetDateTime;
+use twilight_model::id::Id;
+
+#[test]
+fn test_quarantine_bson() {
+    let quarantine = Quarantine {
+        guild_id: Id::new(1),
+        user_id: Id::new(2),
+        roles: vec![Id::new(3), Id::new(4)],
+        moderator_id: Some(Id::new(5)),
+        reason: Some("compromised account".to_string()),
+        date: OffsetDateTime::from_unix_timestamp(1_628_594_197_123).unwrap(),
+    };
+
+    let expected = bson::doc! {
+        "guild_id": 1_i64,
+        "user_id": 2_i64,
+        "roles": [3_i64, 4_i64],
+        "moderator_id": 5_i64,
+        "reason": "compromised account",
+        "date": DateTime::from_millis(1_628_594_197_123),
+    };
+
+    assert_eq!(bson::to_document(&quarantine).unwrap(), expected);
+    assert_eq!(
+        bson::from_document::<Quarantine>(expected).unwrap(),
+        quarantine
+    );
+}
+
+#[test]
+fn test_quarantine_automatic_bson() {
+    let quarantine = Quarantine {
+        guild_id: Id::new(1),
+        user_id: Id::new(2),
+        roles: Vec::new(),
+        moderator_id: None,
+        reason: None,
+        date: OffsetDateTime::from_unix_timestamp(1_628_594_197_123).unwrap(),
+    };
+
+    let expected = bson::doc! {
+        "guild_id": 1_i64,
+        "user_id": 2_i64,
+        "roles": [],
+        "date": DateTime::from_millis(1_628_594_197_123),
+    };
+
+    assert_eq!(bson::to_document(&quarantine).unwrap(), expected);
+    assert_eq!(
+        bson::from_document::<Quarantine>(expected).unwrap(),
+        quarantine
+    );
+}
@@ -9,14 +9,18 @@ use raidprotect_model::{
     config::BotConfig,
     database::DbClient,
 };
-use tracing::{info, info_span, instrument, trace};
+use tracing::{error, info, info_span, instrument, trace};
 use twilight_gateway::{cluster::Events, Cluster, Intents};
 use twilight_http::Client as HttpClient;
 use twilight_model::{
     gateway::{
-        payload::outgoing::update_presence::UpdatePresencePayload,
+        event::Event,
+        payload::outgoing::{
+            request_guild_members::RequestGuildMembers, update_presence::UpdatePresencePayload,
+        },
         presence::{ActivityType, MinimalActivity, Status},
     },
+    guild::Guild,
     id::{
         marker::{ApplicationMarker, GuildMarker},
         Id,
@@ -24,7 +28,10 @@ use twilight_model::{
 };
 
 use crate::{
-    event::ProcessEvent, interaction::register_commands, util::shutdown::ShutdownSubscriber,
+    event::ProcessEvent,
+    feature::{backup::schedule_backups, phishing::PhishingDetector},
+    interaction::register_commands,
+    util::shutdown::ShutdownSubscriber,
 };
 
 /// Discord shards cluster.
@@ -39,6 +46,8 @@ pub struct ShardCluster {
     events: Events,
     /// Shared cluster state
     state: Arc<ClusterState>,
+    /// Whether guild members are requested on `GuildCreate`
+    request_members: bool,
 }
 
 impl ShardCluster {
@@ -74,10 +83,12 @@ impl ShardCluster {
 
         let intents = Intents::GUILDS
             | Intents::GUILD_MEMBERS
+            | Intents::GUILD_BANS
+            | Intents::GUILD_WEBHOOKS
             | Intents::GUILD_MESSAGES
             | Intents::MESSAGE_CONTENT;
 
-        let (cluster, events) = Cluster::builder(config.token, intents)
+        let (cluster, events) = Cluster::builder(config.token.clone(), intents)
             .http_client(http.clone())
             .presence(presence())
             .build()
@@ -85,7 +96,12 @@ impl ShardCluster {
 
         info!("started cluster with {} shards", cluster.shards().len());
 
-        let state = ClusterState::new(redis, mongodb, http, current_user);
+        if !config.request_members {
+            info!("guild members are not requested, the members cache of large guilds is partial");
+        }
+
+        let phishing = PhishingDetector::new(&config.protected_domains);
+        let state = ClusterState::new(redis, mongodb, http, current_user, phishing);
 
         register_commands(&state, application.id).await;
 
@@ -93,6 +109,7 @@ impl ShardCluster {
             cluster: Arc::new(cluster),
             events,
             state: Arc::new(state),
+            request_members: config.request_members,
         })
     }
 
@@ -107,6 +124,9 @@ impl ShardCluster {
             cluster.up().await;
         });
 
+        // Start the scheduled backups
+        tokio::spawn(schedule_backups(self.state.clone()));
+
         // Handle incoming events
         tokio::select! {
             _ = self.handle_events() => {},
@@ -118,19 +138,50 @@ impl ShardCluster {
 
     /// Handle incoming events
     async fn handle_events(&mut self) {
-        while let Some((_shard_id, event)) = self.events.next().await {
+        while let Some((shard_id, event)) = self.events.next().await {
             let span = info_span!("handle_event");
 
             span.in_scope(|| {
                 trace!(event = ?event, "received event");
 
+                let members_request = match &event {
+                    Event::GuildCreate(guild) if self.request_members => members_request(guild),
+                    _ => None,
+                };
+
                 let state = self.state.clone();
-                tokio::spawn(event.process(state));
+                let cluster = self.cluster.clone();
+
+                tokio::spawn(async move {
+                    event.process(state).await;
+
+                    // Members are requested once the guild has been cached,
+                    // since caching the guild replaces its cached members.
+                    if let Some(request) = members_request {
+                        if let Err(error) = cluster.command(shard_id, &request).await {
+                            error!(error = ?error, guild = ?request.d.guild_id, "failed to request guild members");
+                        }
+                    }
+                });
             });
         }
     }
 }
 
+/// Build the request of the members of a guild that were not received with
+/// the guild.
+///
+/// The members are received with `MemberChunk` events.
+fn members_request(guild: &Guild) -> Option<RequestGuildMembers> {
+    let member_count = guild.member_count.unwrap_or_default();
+
+    if guild.unavailable || guild.members.len() as u64 >= member_count {
+        return None;
+    }
+
+    Some(RequestGuildMembers::builder(guild.id).query("", None))
+}
+
 /// Get the bot presence.
 fn presence() -> UpdatePresencePayload {
     let activity = MinimalActivity {
@@ -161,6 +212,8 @@ pub struct ClusterState {
     http: Arc<HttpClient>,
     /// Bot user id
     current_user: Id<ApplicationMarker>,
+    /// Phishing domains detector
+    phishing: PhishingDetector,
 }
 
 impl ClusterState {
@@ -170,12 +223,14 @@ impl ClusterState {
         mongodb: DbClient,
         http: Arc<HttpClient>,
         current_user: Id<ApplicationMarker>,
+        phishing: PhishingDetector,
     ) -> Self {
         Self {
             redis,
             mongodb,
             http,
             current_user,
+            phishing,
         }
     }
 
@@ -203,4 +258,9 @@ impl ClusterState {
     pub fn current_user(&self) -> Id<ApplicationMarker> {
         self.current_user
     }
+
+    /// Get the cluster [`PhishingDetector`]
+    pub fn phishing(&self) -> &PhishingDetector {
+        &self.phishing
+    }
 }
@@ -0,0 +1,248 @@
+//! Anti-nuke protection event processing.
+//!
+//! Destructive actions are counted when the following events are received:
+//!
+//! - `ChannelDelete` and `RoleDelete` for channel and role deletions,
+//! - `BanAdd` for bans,
+//! - `MemberRemove` for kicks (members leaving are counted too, the audit log
+//!   is used to filter them out),
+//! - `WebhooksUpdate` for webhook creations.
+//!
+//! Members kicked or banned by the bot itself are not counted.
+//!
+//! When the number of actions of the same kind reaches the configured
+//! threshold, the audit log is fetched to identify the member responsible for
+//! the actions. Its roles are removed (within the limits of the bot's role
+//! hierarchy) and the owner of the guild is alerted.
+
+use raidprotect_model::{
+    cache::model::{antinuke::DestructiveAction, CachedGuild, CachedRole},
+    database::model::{AntinukeAction, GuildConfig},
+};
+use time::OffsetDateTime;
+use tracing::{debug, error};
+use twilight_http::request::AuditLogReason;
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::embed::Embed,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    feature::{
+        antinuke::{
+            action_kind_name, action_name, audit_log_type, find_actor, roles_to_keep, window_start,
+        },
+        quarantine::quarantine,
+    },
+    interaction::embed::COLOR_RED,
+    translations::Lang,
+    util::{send_direct_message, send_log},
+};
+
+/// Maximum number of audit log entries fetched.
+const AUDIT_LOG_LIMIT: u16 = 100;
+
+/// Duration during which a member is not handled again, in seconds.
+const ACTOR_COOLDOWN: u32 = 5 * 60;
+
+/// Count a destructive action.
+///
+/// The `target` is the id of the resource affected by the action.
+pub async fn destructive_action(
+    guild_id: Id<GuildMarker>,
+    action: DestructiveAction,
+    target: u64,
+    state: &ClusterState,
+) {
+    if let Err(error) = destructive_action_inner(guild_id, action, target, state).await {
+        error!(error = ?error, guild = ?guild_id, action = ?action, "error while processing anti-nuke protection");
+    }
+}
+
+async fn destructive_action_inner(
+    guild_id: Id<GuildMarker>,
+    action: DestructiveAction,
+    target: u64,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    // This runs on frequent events such as member leaves, the configuration is
+    // read from the in-memory cache of the database client.
+    let config = match state.mongodb().get_guild(guild_id).await? {
+        Some(config) if config.antinuke.enabled => config,
+        _ => return Ok(()),
+    };
+
+    // Members kicked or banned by the bot are not counted, so that raid
+    // protections do not trigger the anti-nuke protection.
+    if matches!(action, DestructiveAction::Kick | DestructiveAction::Ban)
+        && state
+            .redis()
+            .is_bot_removal(guild_id, Id::new(target))
+            .await?
+    {
+        return Ok(());
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let window = config.antinuke.window;
+    let count = state
+        .redis()
+        .push_destructive_action(guild_id, action, target, now, window)
+        .await?;
+
+    if count < config.antinuke.threshold.into()
+        || !state.redis().lock_antinuke_check(guild_id, action).await?
+    {
+        return Ok(());
+    }
+
+    let audit_log = state
+        .cache_http(guild_id)
+        .audit_log()
+        .await?
+        .action_type(audit_log_type(action))
+        .limit(AUDIT_LOG_LIMIT)?
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    let since = window_start(now, window);
+    let (actor, count) = match find_actor(&audit_log.entries, since, config.antinuke.threshold) {
+        Some(actor) => actor,
+        None => return Ok(()),
+    };
+
+    let guild = match state.redis().get::<CachedGuild>(&guild_id).await? {
+        Some(guild) => guild,
+        None => return Ok(()),
+    };
+
+    // The owner cannot be sanctioned, and the bot does not sanction itself.
+    if actor == state.current_user().cast() || actor == guild.owner_id {
+        debug!(guild = ?guild_id, actor = ?actor, "ignoring destructive actions of exempted member");
+        return Ok(());
+    }
+
+    if !state
+        .redis()
+        .lock_antinuke_actor(guild_id, actor, ACTOR_COOLDOWN)
+        .await?
+    {
+        return Ok(());
+    }
+
+    let lang = Lang::from(&*config.lang);
+    let result = strip_roles(guild_id, actor, &config, state).await;
+
+    let description = match &result {
+        Ok(()) => lang.antinuke_alert_description(
+            count,
+            actor,
+            action_kind_name(action, lang),
+            actor.mention(),
+            window,
+        ),
+        Err(_) => lang.antinuke_alert_failed(
+            count,
+            actor,
+            action_kind_name(action, lang),
+            actor.mention(),
+            window,
+        ),
+    };
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.antinuke_alert_title(&guild.name))
+        .description(description)
+        .field(EmbedFieldBuilder::new(
+            lang.antinuke_alert_action(),
+            action_name(config.antinuke.action, lang),
+        ))
+        .build();
+
+    alert(guild.owner_id, &config, embed, state).await;
+    result
+}
+
+/// Remove the roles of the member responsible for destructive actions.
+///
+/// The member roles are read from the cache, and only fetched from the API
+/// if the member is not cached. Roles that cannot be removed by the bot are
+/// kept. With the [`AntinukeAction::Quarantine`] action, the member is
+/// quarantined so that its roles can be restored with `/unquarantine`.
+async fn strip_roles(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let member_roles = match state.redis().member(guild_id, user_id).await? {
+        Some(member) => member.roles,
+        None => {
+            state
+                .http()
+                .guild_member(guild_id, user_id)
+                .exec()
+                .await?
+                .model()
+                .await?
+                .roles
+        }
+    };
+
+    if config.antinuke.action == AntinukeAction::Quarantine && config.quarantine.role.is_some() {
+        quarantine(
+            guild_id,
+            user_id,
+            &member_roles,
+            None,
+            lang.antinuke_reason(),
+            config,
+            state,
+        )
+        .await?;
+
+        return Ok(());
+    }
+
+    let bot_highest = state
+        .redis()
+        .permissions(guild_id)
+        .await?
+        .current_member()
+        .await?
+        .highest_role();
+
+    let roles = state.redis().get_many::<CachedRole>(&member_roles).await?;
+    let keep = roles_to_keep(&roles, bot_highest);
+
+    state
+        .cache_http(guild_id)
+        .update_guild_member_roles(user_id, &keep)
+        .await?
+        .reason(lang.antinuke_reason())?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+/// Alert the owner of the guild in direct message and in the logs channel.
+async fn alert(owner_id: Id<UserMarker>, config: &GuildConfig, embed: Embed, state: &ClusterState) {
+    if let Err(error) = send_direct_message(state, owner_id, embed.clone()).await {
+        debug!(error = ?error, owner = ?owner_id, "failed to alert guild owner in direct message");
+    }
+
+    if let Err(error) = send_log(state, config, embed).await {
+        error!(error = ?error, guild = ?config.id, "failed to send anti-nuke log");
+    }
+}
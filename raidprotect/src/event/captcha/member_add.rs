@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use raidprotect_model::cache::model::interaction::PendingCaptcha;
+use raidprotect_model::cache::model::{interaction::PendingCaptcha, risk::MemberRisk};
 use time::{Duration, OffsetDateTime};
 use tracing::{debug, error, instrument};
 use twilight_http::request::AuditLogReason;
@@ -11,13 +11,20 @@ use twilight_model::guild::Member;
 use crate::{cluster::ClusterState, feature::captcha, translations::Lang};
 
 /// Handle `MemberAdd` event.
-pub async fn member_add(member: &Member, state: Arc<ClusterState>) {
-    if let Err(error) = member_add_inner(member, state).await {
+///
+/// If `required` is `true`, the captcha is required regardless of the risk
+/// score of the member.
+pub async fn member_add(member: &Member, required: bool, state: Arc<ClusterState>) {
+    if let Err(error) = member_add_inner(member, required, state).await {
         error!(error = ?error, member = ?member, "error while processing `MemberAdd` event");
     }
 }
 
-async fn member_add_inner(member: &Member, state: Arc<ClusterState>) -> Result<(), anyhow::Error> {
+async fn member_add_inner(
+    member: &Member,
+    required: bool,
+    state: Arc<ClusterState>,
+) -> Result<(), anyhow::Error> {
     // Ensure the member has joined recently to ignore members sent on bot
     // startup.
     let now = OffsetDateTime::now_utc();
@@ -35,6 +42,29 @@ async fn member_add_inner(member: &Member, state: Arc<ClusterState>) -> Result<(
         return Ok(());
     }
 
+    // A captcha may still be pending if the member left and joined again. It
+    // is kept so that leaving does not reset the captcha attempts.
+    let pending = state
+        .redis()
+        .get::<PendingCaptcha>(&(member.guild_id, member.user.id))
+        .await?;
+
+    // Members with a risk score below the threshold do not need to complete
+    // the captcha. The captcha is required if the score is unknown, or if
+    // required by the account age gate.
+    if !required && pending.is_none() && config.risk.captcha > 0 {
+        let score = state
+            .redis()
+            .get::<MemberRisk>(&(member.guild_id, member.user.id))
+            .await?
+            .map(|risk| risk.score);
+
+        if let Some(score) = score.filter(|score| *score < config.risk.captcha) {
+            debug!(score = score, "captcha not required for member");
+            return Ok(());
+        }
+    }
+
     let role = match config.captcha.role {
         Some(role) => role,
         None => {
@@ -58,6 +88,12 @@ async fn member_add_inner(member: &Member, state: Arc<ClusterState>) -> Result<(
         return Ok(());
     }
 
+    if pending.is_some() {
+        debug!("captcha already pending for member");
+
+        return Ok(());
+    }
+
     // Store the captcha in redis.
     let pending_captcha = PendingCaptcha {
         guild_id: member.guild_id,
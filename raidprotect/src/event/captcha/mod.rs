@@ -4,6 +4,10 @@
 //! following events are handled:
 //!
 //! - `MemberAdd`: when a member joins the server, the unverified role is added.
+//!   Members with a risk score below the configured threshold are not required
+//!   to complete the captcha, unless sent to the captcha by the account age
+//!   gate. A captcha still pending from a previous join is
+//!   kept, so leaving the guild does not reset the captcha attempts.
 
 mod member_add;
 
@@ -0,0 +1,223 @@
+//! Auto-moderation actions.
+//!
+//! This module performs the action configured for a rule and reports it in the
+//! guild logs channel.
+
+use raidprotect_model::{
+    cache::model::message::CachedMessage,
+    database::model::{AutomodAction, AutomodRule, GuildConfig, RuleMode},
+};
+use time::{Duration, OffsetDateTime};
+use tracing::warn;
+use twilight_http::request::AuditLogReason;
+use twilight_mention::Mention;
+use twilight_model::{
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+
+use super::MatchedRule;
+use crate::{
+    cluster::ClusterState,
+    feature::quarantine,
+    interaction::embed::{COLOR_RED, COLOR_TRANSPARENT},
+    translations::Lang,
+    util::{guild_logs_channel, TextProcessExt},
+};
+
+/// Perform the action of a rule on a message.
+pub async fn enforce(
+    state: &ClusterState,
+    config: &GuildConfig,
+    message: &CachedMessage,
+    matched: &MatchedRule<'_>,
+    lang: Lang,
+) -> Result<(), anyhow::Error> {
+    let rule = matched.rule;
+    let reason = lang.automod_reason(&matched.name);
+
+    delete_message(state, config.id, message.channel_id, message.id, &reason).await?;
+
+    for copy in &matched.copies {
+        if let Err(error) =
+            delete_message(state, config.id, copy.channel_id, copy.message_id, &reason).await
+        {
+            warn!(error = ?error, message = ?copy.message_id, "failed to delete message copy");
+        }
+    }
+
+    match rule.action {
+        AutomodAction::Delete => {}
+        AutomodAction::Timeout => {
+            timeout(state, config.id, message.author_id, rule, &reason).await?;
+        }
+        AutomodAction::Quarantine => {
+            quarantine(state, config, message.author_id, &reason).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete a message.
+pub async fn delete_message(
+    state: &ClusterState,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    message_id: Id<MessageMarker>,
+    reason: &str,
+) -> Result<(), anyhow::Error> {
+    state
+        .cache_http(guild_id)
+        .delete_message(channel_id, message_id)
+        .await?
+        .reason(reason)?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+/// Timeout a member for the duration configured in a rule.
+pub async fn timeout(
+    state: &ClusterState,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    rule: &AutomodRule,
+    reason: &str,
+) -> Result<(), anyhow::Error> {
+    let until = OffsetDateTime::now_utc() + Duration::seconds(rule.timeout.into());
+
+    state
+        .cache_http(guild_id)
+        .timeout_guild_member(user_id, Some(Timestamp::from_secs(until.unix_timestamp())?))
+        .await?
+        .reason(reason)?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+/// Quarantine a member.
+///
+/// The member is fetched to save its roles. Nothing is done if the quarantine
+/// role is not configured.
+pub async fn quarantine(
+    state: &ClusterState,
+    config: &GuildConfig,
+    user_id: Id<UserMarker>,
+    reason: &str,
+) -> Result<(), anyhow::Error> {
+    if config.quarantine.role.is_none() {
+        warn!(guild = ?config.id, "quarantine role not configured for auto-moderation");
+
+        return Ok(());
+    }
+
+    let member = state
+        .http()
+        .guild_member(config.id, user_id)
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    quarantine::quarantine(
+        config.id,
+        user_id,
+        &member.roles,
+        None,
+        reason,
+        config,
+        state,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Send a log entry for a message that matched a rule.
+///
+/// In monitor mode, the log entry contains the action that would have been
+/// performed and the current hit count of the rule.
+pub async fn log(
+    state: &ClusterState,
+    config: &GuildConfig,
+    message: &CachedMessage,
+    matched: &MatchedRule<'_>,
+    hits: u64,
+    lang: Lang,
+) -> Result<(), anyhow::Error> {
+    let rule = matched.rule;
+    let user = message.author_id.mention();
+    let channel = message.channel_id.mention();
+    let minutes = (rule.timeout / 60).max(1);
+
+    let (description, color) = match (rule.mode, rule.action) {
+        (RuleMode::Monitor, AutomodAction::Delete) => (
+            lang.automod_log_monitor_delete(channel, user),
+            COLOR_TRANSPARENT,
+        ),
+        (RuleMode::Monitor, AutomodAction::Timeout) => (
+            lang.automod_log_monitor_timeout(channel, minutes, user),
+            COLOR_TRANSPARENT,
+        ),
+        (RuleMode::Monitor, AutomodAction::Quarantine) => (
+            lang.automod_log_monitor_quarantine(channel, user),
+            COLOR_TRANSPARENT,
+        ),
+        (_, AutomodAction::Delete) => (lang.automod_log_delete(channel, user), COLOR_RED),
+        (_, AutomodAction::Timeout) => {
+            (lang.automod_log_timeout(channel, minutes, user), COLOR_RED)
+        }
+        (_, AutomodAction::Quarantine) => (lang.automod_log_quarantine(channel, user), COLOR_RED),
+    };
+
+    let mut embed = EmbedBuilder::new()
+        .title(lang.automod_log_title(&matched.name))
+        .color(color)
+        .description(description);
+
+    if !message.content.is_empty() {
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.automod_log_content(),
+            message.content.truncate(1024),
+        ));
+    }
+
+    if !matched.copies.is_empty() {
+        let channels = matched
+            .copies
+            .iter()
+            .map(|copy| copy.channel_id.mention().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.automod_log_copies(),
+            channels.truncate(1024),
+        ));
+    }
+
+    if rule.mode == RuleMode::Monitor {
+        embed = embed.footer(EmbedFooterBuilder::new(lang.automod_log_monitor_hits(hits)));
+    }
+
+    let guild_id = config.id;
+    let logs_channel = guild_logs_channel(state, guild_id, config.logs_chan, lang).await?;
+
+    state
+        .cache_http(guild_id)
+        .create_message(logs_channel)
+        .await?
+        .embeds(&[embed.build()])?
+        .exec()
+        .await?;
+
+    Ok(())
+}
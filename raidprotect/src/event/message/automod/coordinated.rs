@@ -0,0 +1,329 @@
+//! Coordinated spam.
+//!
+//! Raids often use many accounts posting the same message. Messages are
+//! identified by a fingerprint of their words, and the messages posted by all
+//! the members of a guild are stored in the cache for a short time. Numbers are
+//! ignored in the fingerprint, so messages that only differ by mentions or
+//! random numbers have the same fingerprint.
+//!
+//! When messages with the same fingerprint have been posted by at least
+//! [`COORDINATED_AUTHORS`] accounts within [`COORDINATED_WINDOW`] seconds, the
+//! rule is applied to all the messages and their authors, and a single summary
+//! is sent in the logs channel. The following messages with the same
+//! fingerprint are handled without sending a new summary, until the
+//! fingerprint expires from the cache.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use raidprotect_model::{
+    cache::model::{
+        automod::{DuplicateScope, MessageCopy},
+        message::CachedMessage,
+    },
+    database::model::{AutomodAction, AutomodRule, AutomodRuleKind, GuildConfig, RuleMode},
+};
+use tracing::{debug, warn};
+use twilight_mention::Mention;
+use twilight_model::id::{
+    marker::{RoleMarker, UserMarker},
+    Id,
+};
+use twilight_util::{
+    builder::embed::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder},
+    snowflake::Snowflake,
+};
+
+use super::{action, duplicates::message_copy, is_moderator};
+use crate::{
+    cluster::ClusterState,
+    feature::automod::rule_name,
+    interaction::embed::{COLOR_RED, COLOR_TRANSPARENT},
+    translations::Lang,
+    util::{guild_logs_channel, TextProcessExt},
+};
+
+/// Minimum number of distinct accounts for messages to be coordinated spam.
+pub const COORDINATED_AUTHORS: usize = 5;
+
+/// Duration during which messages are stored, in seconds.
+pub const COORDINATED_WINDOW: u64 = 60;
+
+/// Minimum number of words of a message.
+///
+/// Short messages such as greetings are commonly sent by many members at the
+/// same time, and are ignored.
+const MIN_WORDS: usize = 3;
+
+/// Evaluate the coordinated spam rule against a message.
+pub async fn coordinated(
+    message: &CachedMessage,
+    config: &GuildConfig,
+    roles: Option<&[Id<RoleMarker>]>,
+    lang: Lang,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let kind = AutomodRuleKind::Coordinated;
+    let rule = config.automod.rule(kind);
+
+    if rule.mode == RuleMode::Disabled {
+        return Ok(());
+    }
+
+    let fingerprint = match fingerprint(message) {
+        Some(fingerprint) => fingerprint,
+        None => return Ok(()),
+    };
+
+    let guild_id = config.id;
+    let duplicates = state
+        .redis()
+        .push_duplicate(
+            guild_id,
+            DuplicateScope::Guild,
+            fingerprint,
+            message_copy(message),
+            COORDINATED_WINDOW as usize,
+        )
+        .await?;
+
+    // The cache entry expires after the window without new messages, older
+    // messages are ignored.
+    let since = message.id.timestamp() - (COORDINATED_WINDOW * 1000) as i64;
+    let copies: Vec<_> = duplicates
+        .copies
+        .into_iter()
+        .filter(|copy| copy.message_id.timestamp() >= since)
+        .collect();
+
+    let authors: HashSet<_> = copies.iter().map(|copy| copy.author_id).collect();
+
+    if !duplicates.flagged && authors.len() < COORDINATED_AUTHORS {
+        return Ok(());
+    }
+
+    state
+        .redis()
+        .flag_duplicates(guild_id, DuplicateScope::Guild, fingerprint, &copies)
+        .await?;
+
+    // Moderators are not affected by auto-moderation.
+    let mut participants = Vec::with_capacity(authors.len());
+    for author_id in authors {
+        let roles = if author_id == message.author_id {
+            roles
+        } else {
+            None
+        };
+
+        match is_moderator(guild_id, author_id, roles, state).await {
+            Ok(false) => participants.push(author_id),
+            Ok(true) => {}
+            Err(error) => {
+                warn!(error = ?error, user = ?author_id, "failed to check coordinated spam participant")
+            }
+        }
+    }
+
+    participants.sort_unstable();
+
+    let copies: Vec<_> = copies
+        .into_iter()
+        .filter(|copy| participants.contains(&copy.author_id))
+        .collect();
+
+    if copies.is_empty() {
+        return Ok(());
+    }
+
+    debug!(guild = ?guild_id, participants = participants.len(), mode = ?rule.mode, "detected coordinated spam");
+
+    let hits = state
+        .redis()
+        .incr_automod_hits(guild_id, kind.name(), rule.mode)
+        .await?;
+
+    let name = rule_name(kind, lang);
+
+    if rule.mode == RuleMode::Enforce {
+        let reason = lang.automod_reason(name);
+
+        enforce(state, config, &copies, &participants, rule, &reason).await?;
+    }
+
+    // A single summary is sent when the spam is detected.
+    if duplicates.flagged {
+        return Ok(());
+    }
+
+    log(state, config, message, &copies, &participants, hits, lang).await
+}
+
+/// Compute the fingerprint of a message.
+///
+/// The fingerprint is computed from the words of the message converted to
+/// lowercase, ignoring numbers. Messages with less than [`MIN_WORDS`] words
+/// have no fingerprint.
+fn fingerprint(message: &CachedMessage) -> Option<u64> {
+    let words: Vec<_> = message
+        .words
+        .iter()
+        .filter(|word| !word.chars().all(|c| c.is_ascii_digit()))
+        .map(|word| word.to_lowercase())
+        .collect();
+
+    if words.len() < MIN_WORDS {
+        return None;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    words.hash(&mut hasher);
+
+    Some(hasher.finish())
+}
+
+/// Delete the messages and timeout or quarantine the participants.
+async fn enforce(
+    state: &ClusterState,
+    config: &GuildConfig,
+    copies: &[MessageCopy],
+    participants: &[Id<UserMarker>],
+    rule: &AutomodRule,
+    reason: &str,
+) -> Result<(), anyhow::Error> {
+    for copy in copies {
+        if let Err(error) =
+            action::delete_message(state, config.id, copy.channel_id, copy.message_id, reason).await
+        {
+            warn!(error = ?error, message = ?copy.message_id, "failed to delete coordinated spam message");
+        }
+    }
+
+    for user_id in participants {
+        let result = match rule.action {
+            AutomodAction::Delete => continue,
+            AutomodAction::Timeout => {
+                action::timeout(state, config.id, *user_id, rule, reason).await
+            }
+            AutomodAction::Quarantine => action::quarantine(state, config, *user_id, reason).await,
+        };
+
+        if let Err(error) = result {
+            warn!(error = ?error, user = ?user_id, "failed to sanction coordinated spam participant");
+        }
+    }
+
+    Ok(())
+}
+
+/// Send a summary of the coordinated spam in the logs channel.
+async fn log(
+    state: &ClusterState,
+    config: &GuildConfig,
+    message: &CachedMessage,
+    copies: &[MessageCopy],
+    participants: &[Id<UserMarker>],
+    hits: u64,
+    lang: Lang,
+) -> Result<(), anyhow::Error> {
+    let kind = AutomodRuleKind::Coordinated;
+    let rule = config.automod.rule(kind);
+    let members = participants.len();
+    let messages = copies.len();
+    let minutes = (rule.timeout / 60).max(1);
+
+    let (description, color) = match (rule.mode, rule.action) {
+        (RuleMode::Monitor, _) => (
+            lang.automod_coordinated_monitor(members, messages),
+            COLOR_TRANSPARENT,
+        ),
+        (_, AutomodAction::Delete) => (
+            lang.automod_coordinated_delete(members, messages),
+            COLOR_RED,
+        ),
+        (_, AutomodAction::Timeout) => (
+            lang.automod_coordinated_timeout(members, messages, minutes),
+            COLOR_RED,
+        ),
+        (_, AutomodAction::Quarantine) => (
+            lang.automod_coordinated_quarantine(members, messages),
+            COLOR_RED,
+        ),
+    };
+
+    let mentions = participants
+        .iter()
+        .map(|user_id| user_id.mention().to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut embed = EmbedBuilder::new()
+        .title(lang.automod_log_title(rule_name(kind, lang)))
+        .color(color)
+        .description(description)
+        .field(EmbedFieldBuilder::new(
+            lang.automod_coordinated_members(),
+            mentions.truncate(1024),
+        ));
+
+    if !message.content.is_empty() {
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.automod_log_content(),
+            message.content.truncate(1024),
+        ));
+    }
+
+    if rule.mode == RuleMode::Monitor {
+        embed = embed.footer(EmbedFooterBuilder::new(lang.automod_log_monitor_hits(hits)));
+    }
+
+    let guild_id = config.id;
+    let logs_channel = guild_logs_channel(state, guild_id, config.logs_chan, lang).await?;
+
+    state
+        .cache_http(guild_id)
+        .create_message(logs_channel)
+        .await?
+        .embeds(&[embed.build()])?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::fixtures;
+
+    fn message(words: &[&str]) -> CachedMessage {
+        CachedMessage {
+            words: words.iter().map(|word| word.to_string()).collect(),
+            ..fixtures::message(&words.join(" "))
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignore_numbers() {
+        assert_eq!(
+            fingerprint(&message(&["Free", "nitro", "here", "123456"])),
+            fingerprint(&message(&["free", "NITRO", "here", "987654"]))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_different() {
+        assert_ne!(
+            fingerprint(&message(&["free", "nitro", "here"])),
+            fingerprint(&message(&["free", "nitro", "there"]))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_short() {
+        assert_eq!(fingerprint(&message(&["hello", "everyone"])), None);
+        assert_eq!(fingerprint(&message(&["hello", "everyone", "42"])), None);
+    }
+}
@@ -0,0 +1,180 @@
+//! Cross-channel duplicate messages.
+//!
+//! A common raid pattern is an account posting the same message in every
+//! channel of a guild within a few seconds. Each message is identified by a
+//! fingerprint of its content, and the copies posted by the same author are
+//! stored in the cache for a short time.
+//!
+//! A message matches the rule when copies of the message have been posted in
+//! at least [`DUPLICATE_CHANNELS`] distinct channels within
+//! [`DUPLICATE_WINDOW`] seconds. All the copies are deleted when the rule is
+//! enforced, and the following copies are matched until the fingerprint
+//! expires from the cache.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use raidprotect_model::cache::model::{
+    automod::{DuplicateScope, MessageCopy},
+    message::CachedMessage,
+};
+use twilight_model::id::{marker::GuildMarker, Id};
+use twilight_util::snowflake::Snowflake;
+
+use crate::cluster::ClusterState;
+
+/// Minimum number of distinct channels for a message to be a duplicate.
+pub const DUPLICATE_CHANNELS: usize = 3;
+
+/// Duration during which copies of a message are stored, in seconds.
+pub const DUPLICATE_WINDOW: u64 = 30;
+
+/// Find the copies of a duplicate message.
+///
+/// If the message has been posted in multiple channels, the other copies of
+/// the message are returned. Otherwise, [`None`] is returned.
+pub async fn find_copies(
+    message: &CachedMessage,
+    guild_id: Id<GuildMarker>,
+    state: &ClusterState,
+) -> Result<Option<Vec<MessageCopy>>, anyhow::Error> {
+    let fingerprint = match fingerprint(message) {
+        Some(fingerprint) => fingerprint,
+        None => return Ok(None),
+    };
+
+    let scope = DuplicateScope::Author(message.author_id);
+    let duplicates = state
+        .redis()
+        .push_duplicate(
+            guild_id,
+            scope,
+            fingerprint,
+            message_copy(message),
+            DUPLICATE_WINDOW as usize,
+        )
+        .await?;
+
+    // The cache entry expires after the window without new copies, older
+    // copies are ignored.
+    let since = message.id.timestamp() - (DUPLICATE_WINDOW * 1000) as i64;
+    let copies: Vec<_> = duplicates
+        .copies
+        .into_iter()
+        .filter(|copy| copy.message_id.timestamp() >= since)
+        .collect();
+
+    let channels: HashSet<_> = copies.iter().map(|copy| copy.channel_id).collect();
+
+    if !duplicates.flagged && channels.len() < DUPLICATE_CHANNELS {
+        return Ok(None);
+    }
+
+    state
+        .redis()
+        .flag_duplicates(guild_id, scope, fingerprint, &copies)
+        .await?;
+
+    Ok(Some(
+        copies
+            .into_iter()
+            .filter(|copy| copy.message_id != message.id)
+            .collect(),
+    ))
+}
+
+/// Get the [`MessageCopy`] of a message.
+pub fn message_copy(message: &CachedMessage) -> MessageCopy {
+    MessageCopy {
+        author_id: message.author_id,
+        channel_id: message.channel_id,
+        message_id: message.id,
+    }
+}
+
+/// Compute the fingerprint of a message.
+///
+/// The fingerprint is computed from the normalized words of the message and
+/// the names of its attachments. Messages without content and attachments
+/// have no fingerprint.
+fn fingerprint(message: &CachedMessage) -> Option<u64> {
+    if message.words.is_empty() && message.attachments.is_empty() {
+        return None;
+    }
+
+    let mut hasher = DefaultHasher::new();
+
+    for word in &message.words {
+        word.to_lowercase().hash(&mut hasher);
+    }
+
+    let mut attachments: Vec<_> = message
+        .attachments
+        .iter()
+        .map(|attachment| attachment.filename.to_lowercase())
+        .collect();
+    attachments.sort_unstable();
+    attachments.hash(&mut hasher);
+
+    Some(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::channel::Attachment;
+
+    use super::*;
+    use crate::feature::fixtures;
+
+    fn message(words: &[&str], attachments: &[&str]) -> CachedMessage {
+        let attachments = attachments
+            .iter()
+            .enumerate()
+            .map(|(index, filename)| Attachment {
+                content_type: None,
+                ephemeral: false,
+                filename: filename.to_string(),
+                description: None,
+                height: None,
+                id: Id::new(index as u64 + 1),
+                proxy_url: String::new(),
+                size: 1024,
+                url: String::new(),
+                width: None,
+            })
+            .collect();
+
+        CachedMessage {
+            words: words.iter().map(|word| word.to_string()).collect(),
+            attachments,
+            ..fixtures::message(&words.join(" "))
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_normalized() {
+        assert_eq!(
+            fingerprint(&message(&["Free", "NITRO"], &["a.png", "b.png"])),
+            fingerprint(&message(&["free", "nitro"], &["B.png", "a.png"]))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_different() {
+        assert_ne!(
+            fingerprint(&message(&["free", "nitro"], &[])),
+            fingerprint(&message(&["freenitro"], &[]))
+        );
+        assert_ne!(
+            fingerprint(&message(&["free", "nitro"], &[])),
+            fingerprint(&message(&["free", "nitro"], &["a.png"]))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_empty() {
+        assert_eq!(fingerprint(&message(&[], &[])), None);
+    }
+}
@@ -0,0 +1,96 @@
+//! Regex filters.
+//!
+//! Regex filters are compiled once and cached in memory for each guild. The
+//! cached filters are compiled again when the guild configuration changes.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use once_cell::sync::Lazy;
+use raidprotect_model::database::model::RegexFilter;
+use regex::Regex;
+use tracing::warn;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::feature::automod::compile_regex;
+
+/// Compiled regex filters of each guild.
+static CACHE: Lazy<RwLock<HashMap<Id<GuildMarker>, Arc<CompiledFilters>>>> =
+    Lazy::new(Default::default);
+
+/// Compiled regex filters of a guild.
+#[derive(Debug)]
+pub struct CompiledFilters {
+    /// Source patterns, used to detect configuration changes.
+    patterns: Vec<String>,
+    /// Compiled patterns, in the same order as the configuration.
+    ///
+    /// Patterns that failed to compile are [`None`].
+    regexes: Vec<Option<Regex>>,
+}
+
+impl CompiledFilters {
+    fn new(filters: &[RegexFilter]) -> Self {
+        let regexes = filters
+            .iter()
+            .map(|filter| match compile_regex(&filter.pattern) {
+                Ok(regex) => Some(regex),
+                Err(error) => {
+                    warn!(error = ?error, filter = %filter.name, "failed to compile regex filter");
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            patterns: filters.iter().map(|f| f.pattern.clone()).collect(),
+            regexes,
+        }
+    }
+
+    /// Whether the compiled filters correspond to the configured filters.
+    fn is_current(&self, filters: &[RegexFilter]) -> bool {
+        self.patterns.len() == filters.len()
+            && self
+                .patterns
+                .iter()
+                .zip(filters)
+                .all(|(pattern, filter)| *pattern == filter.pattern)
+    }
+
+    /// Whether the filter at the given position matches the content.
+    pub fn is_match(&self, index: usize, content: &str) -> bool {
+        match self.regexes.get(index) {
+            Some(Some(regex)) => regex.is_match(content),
+            _ => false,
+        }
+    }
+}
+
+/// Get the compiled regex filters of a guild.
+pub fn compiled(guild_id: Id<GuildMarker>, filters: &[RegexFilter]) -> Arc<CompiledFilters> {
+    let cached = CACHE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&guild_id)
+        .cloned();
+
+    if let Some(cached) = cached {
+        if cached.is_current(filters) {
+            return cached;
+        }
+    }
+
+    let compiled = Arc::new(CompiledFilters::new(filters));
+    let mut cache = CACHE.write().unwrap_or_else(PoisonError::into_inner);
+
+    if filters.is_empty() {
+        cache.remove(&guild_id);
+    } else {
+        cache.insert(guild_id, compiled.clone());
+    }
+
+    compiled
+}
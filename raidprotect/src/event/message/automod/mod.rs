@@ -0,0 +1,220 @@
+//! Auto-moderation.
+//!
+//! This module evaluates the auto-moderation rules configured for a guild
+//! against incoming messages. Each rule is configured with one of the following
+//! [`RuleMode`]:
+//!
+//! - `disabled`: the rule is not evaluated.
+//! - `monitor`: the rule is evaluated, but no action is performed. A log entry
+//!   with the action that would have been performed is sent in the logs channel.
+//! - `enforce`: the configured action is performed and logged.
+//!
+//! Each time a rule matches a message, the hit counter of the rule is
+//! incremented. These counters allow administrators to tune the rules in
+//! monitor mode before enforcing them.
+//!
+//! Built-in rules are evaluated first, followed by the custom regex filters of
+//! the guild. Cross-channel duplicates and coordinated spam are evaluated last,
+//! as they require to store the message in the cache.
+//!
+//! Messages sent by members with the `MANAGE_MESSAGES` permission are not
+//! checked.
+
+mod action;
+mod coordinated;
+mod duplicates;
+mod filter;
+mod rules;
+
+use raidprotect_model::{
+    cache::model::{automod::MessageCopy, message::CachedMessage},
+    database::model::{AutomodRule, AutomodRuleKind, GuildConfig, RuleMode},
+};
+use tracing::{debug, error};
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    cluster::ClusterState,
+    feature::automod::{regex_filter_name, rule_name},
+    translations::Lang,
+};
+
+/// Auto-moderation rule that matched a message.
+#[derive(Debug)]
+pub struct MatchedRule<'a> {
+    /// Identifier of the rule, used for the hit counters.
+    pub id: String,
+    /// Localized name of the rule.
+    pub name: String,
+    /// Configuration of the rule.
+    pub rule: &'a AutomodRule,
+    /// Other copies of the message, deleted along with the message.
+    pub copies: Vec<MessageCopy>,
+}
+
+/// Evaluate the auto-moderation rules against a message.
+///
+/// The `roles` argument is the list of roles of the message author, used to
+/// ignore messages sent by moderators. If the roles are not known (e.g. for
+/// message updates), the member is fetched from the Discord API when a rule
+/// matches the message.
+pub async fn automod(
+    message: &CachedMessage,
+    config: &GuildConfig,
+    roles: Option<&[Id<RoleMarker>]>,
+    state: &ClusterState,
+) {
+    if let Err(error) = automod_inner(message, config, roles, state).await {
+        error!(error = ?error, message = ?message.id, "error while processing auto-moderation rules");
+    }
+}
+
+async fn automod_inner(
+    message: &CachedMessage,
+    config: &GuildConfig,
+    roles: Option<&[Id<RoleMarker>]>,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let guild_id = config.id;
+    let lang = Lang::from(&*config.lang);
+
+    // Only the first matching rule is applied to avoid performing multiple
+    // actions on the same message.
+    let matched = match find_match(message, config, lang) {
+        Some(matched) => matched,
+        None => match find_duplicates(message, config, lang, state).await? {
+            Some(matched) => matched,
+            None => return coordinated::coordinated(message, config, roles, lang, state).await,
+        },
+    };
+
+    // Moderators are not affected by auto-moderation.
+    if is_moderator(guild_id, message.author_id, roles, state).await? {
+        return Ok(());
+    }
+
+    let mode = matched.rule.mode;
+    debug!(message = ?message.id, rule = %matched.id, mode = ?mode, "message matched auto-moderation rule");
+
+    let hits = state
+        .redis()
+        .incr_automod_hits(guild_id, &matched.id, mode)
+        .await?;
+
+    if mode == RuleMode::Enforce {
+        action::enforce(state, config, message, &matched, lang).await?;
+    }
+
+    action::log(state, config, message, &matched, hits, lang).await
+}
+
+/// Whether a member has the `MANAGE_MESSAGES` permission.
+///
+/// If the roles of the member are not known, they are read from the cache.
+/// The member is only fetched from the Discord API if it is not cached.
+async fn is_moderator(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    roles: Option<&[Id<RoleMarker>]>,
+    state: &ClusterState,
+) -> Result<bool, anyhow::Error> {
+    let roles = match roles {
+        Some(roles) => roles.to_vec(),
+        None => match state.redis().member(guild_id, user_id).await? {
+            Some(member) => member.roles,
+            None => {
+                state
+                    .http()
+                    .guild_member(guild_id, user_id)
+                    .exec()
+                    .await?
+                    .model()
+                    .await?
+                    .roles
+            }
+        },
+    };
+
+    let permissions = state
+        .redis()
+        .permissions(guild_id)
+        .await?
+        .member(user_id, &roles)
+        .await?;
+
+    Ok(permissions.guild().contains(Permissions::MANAGE_MESSAGES))
+}
+
+/// Find the first enabled rule that matches a message.
+fn find_match<'a>(
+    message: &CachedMessage,
+    config: &'a GuildConfig,
+    lang: Lang,
+) -> Option<MatchedRule<'a>> {
+    let builtin = AutomodRuleKind::ALL
+        .into_iter()
+        .filter(|kind| config.automod.rule(*kind).mode != RuleMode::Disabled)
+        .find(|kind| rules::is_match(*kind, message));
+
+    if let Some(kind) = builtin {
+        return Some(MatchedRule {
+            id: kind.name().to_string(),
+            name: rule_name(kind, lang).to_string(),
+            rule: config.automod.rule(kind),
+            copies: Vec::new(),
+        });
+    }
+
+    if config.automod.regex.is_empty() {
+        return None;
+    }
+
+    let compiled = filter::compiled(config.id, &config.automod.regex);
+
+    config
+        .automod
+        .regex
+        .iter()
+        .enumerate()
+        .filter(|(_, filter)| filter.rule.mode != RuleMode::Disabled)
+        .find(|(index, _)| compiled.is_match(*index, &message.content))
+        .map(|(_, filter)| MatchedRule {
+            id: filter.id(),
+            name: regex_filter_name(&filter.name, lang),
+            rule: &filter.rule,
+            copies: Vec::new(),
+        })
+}
+
+/// Find whether a message has been posted in multiple channels.
+async fn find_duplicates<'a>(
+    message: &CachedMessage,
+    config: &'a GuildConfig,
+    lang: Lang,
+    state: &ClusterState,
+) -> Result<Option<MatchedRule<'a>>, anyhow::Error> {
+    let kind = AutomodRuleKind::Duplicates;
+    let rule = config.automod.rule(kind);
+
+    if rule.mode == RuleMode::Disabled {
+        return Ok(None);
+    }
+
+    let copies = match duplicates::find_copies(message, config.id, state).await? {
+        Some(copies) => copies,
+        None => return Ok(None),
+    };
+
+    Ok(Some(MatchedRule {
+        id: kind.name().to_string(),
+        name: rule_name(kind, lang).to_string(),
+        rule,
+        copies,
+    }))
+}
@@ -0,0 +1,105 @@
+//! Auto-moderation rules.
+//!
+//! Each [`AutomodRuleKind`] has a function that checks whether a parsed
+//! message matches the rule.
+
+use raidprotect_model::{
+    cache::model::message::{CachedMessage, HiddenContent, MessageLink},
+    database::model::AutomodRuleKind,
+};
+
+/// Minimum number of hidden characters for the hidden content rule.
+///
+/// This avoids matching short messages with a few invisible characters, such
+/// as emojis using zero-width joiners.
+const HIDDEN_MIN_LEN: usize = 20;
+
+/// Minimum ratio between hidden and visible characters for the hidden content
+/// rule.
+const HIDDEN_RATIO: usize = 3;
+
+/// Minimum number of consecutive empty spoilers for the hidden content rule.
+///
+/// Long chains of empty spoilers are used to hide content placed after them
+/// (such as links), regardless of the length of the visible content.
+const HIDDEN_SPOILER_CHAIN: usize = 10;
+
+/// Check whether a message matches a rule.
+pub fn is_match(kind: AutomodRuleKind, message: &CachedMessage) -> bool {
+    match kind {
+        AutomodRuleKind::Invites => invites(message),
+        AutomodRuleKind::Phishing => phishing(message),
+        AutomodRuleKind::Hidden => hidden(message),
+        // These rules are evaluated using the cache, see the `duplicates` and
+        // `coordinated` modules.
+        AutomodRuleKind::Duplicates | AutomodRuleKind::Coordinated => false,
+    }
+}
+
+/// The message contains a Discord invite link.
+///
+/// Masked links are checked against their real target.
+fn invites(message: &CachedMessage) -> bool {
+    message
+        .links
+        .iter()
+        .any(|link| matches!(link.target(), MessageLink::Invite(_)))
+}
+
+/// The message contains a link to a domain that imitates a protected domain.
+fn phishing(message: &CachedMessage) -> bool {
+    message
+        .links
+        .iter()
+        .any(|link| matches!(link.target(), MessageLink::Phishing { .. }))
+}
+
+/// The visible content of the message is small compared to its hidden content
+/// (invisible characters and empty spoilers), or the message contains a long
+/// chain of empty spoilers.
+fn hidden(message: &CachedMessage) -> bool {
+    is_hidden(&message.hidden)
+}
+
+fn is_hidden(content: &HiddenContent) -> bool {
+    let hidden = content.hidden();
+
+    content.spoiler_chain >= HIDDEN_SPOILER_CHAIN
+        || (hidden >= HIDDEN_MIN_LEN && hidden >= content.visible * HIDDEN_RATIO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_ratio() {
+        let content = HiddenContent {
+            visible: 5,
+            invisible: 30,
+            ..Default::default()
+        };
+
+        assert!(is_hidden(&content));
+        assert!(!is_hidden(&HiddenContent {
+            visible: 20,
+            ..content
+        }));
+    }
+
+    #[test]
+    fn test_hidden_spoiler_chain() {
+        let content = HiddenContent {
+            visible: 200,
+            invisible: 0,
+            spoilers: 50,
+            spoiler_chain: 10,
+        };
+
+        assert!(is_hidden(&content));
+        assert!(!is_hidden(&HiddenContent {
+            spoiler_chain: 9,
+            ..content
+        }));
+    }
+}
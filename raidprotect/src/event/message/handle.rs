@@ -1,11 +1,24 @@
 use std::{collections::HashMap, sync::Arc};
 
 use once_cell::sync::Lazy;
+use raidprotect_model::{
+    cache::model::message::CachedMessage,
+    database::model::{GuildConfig, MessagesConfig},
+};
 use tracing::{error, info};
-use twilight_model::channel::Message;
+use twilight_model::{
+    channel::Message,
+    gateway::payload::incoming::{MessageDelete, MessageDeleteBulk, MessageUpdate},
+    id::{marker::GuildMarker, Id},
+};
 use twilight_util::builder::embed::EmbedBuilder;
 
-use super::parser::parse_message;
+use super::{
+    automod::automod,
+    logs,
+    parser::{parse_message, parse_message_update},
+    ALLOWED_MESSAGES_TYPES,
+};
 use crate::{cluster::ClusterState, interaction::embed::COLOR_TRANSPARENT, translations::Lang};
 
 /// A mapping between old and new commands
@@ -32,8 +45,29 @@ static OLD_COMMANDS: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
 /// This method will forward message to the cache and various auto-moderation
 /// modules.
 pub async fn handle_message(message: Message, state: Arc<ClusterState>) {
-    let parsed = parse_message(&message);
-    state.redis().set(&parsed).await.ok();
+    let parsed = parse_message(&message, state.phishing());
+    let config = match message.guild_id {
+        Some(guild_id) => guild_config(guild_id, &state).await,
+        None => None,
+    };
+
+    cache_message(&parsed, config.as_ref(), &state).await;
+
+    if let Some(guild_id) = message.guild_id {
+        if let Err(error) = state
+            .redis()
+            .push_recent_message(guild_id, message.id)
+            .await
+        {
+            error!(error = ?error, message = ?message.id, "failed to add recent message");
+        }
+    }
+
+    if let (Some(config), false) = (&config, message.author.bot) {
+        let roles = message.member.as_ref().map(|member| &*member.roles);
+
+        automod(&parsed, config, roles, &state).await;
+    }
 
     if is_old_command(&message.content) {
         let message = message.clone();
@@ -45,6 +79,156 @@ pub async fn handle_message(message: Message, state: Arc<ClusterState>) {
     info!("received message: {}", message.content) // Debug util real implementation
 }
 
+/// Handle incoming [`MessageUpdate`].
+///
+/// The cached message is updated with the new content, and auto-moderation
+/// rules are evaluated again to prevent them from being bypassed by editing
+/// a message. If the previous version of the message is cached, the edit is
+/// logged in the message logs channel.
+pub async fn handle_message_update(update: MessageUpdate, state: Arc<ClusterState>) {
+    let guild_id = match update.guild_id {
+        Some(guild_id) => guild_id,
+        None => return,
+    };
+
+    let is_bot = update.author.as_ref().map(|author| author.bot);
+    let is_allowed = update
+        .kind
+        .map(|kind| ALLOWED_MESSAGES_TYPES.contains(&kind));
+
+    if is_bot == Some(true) || is_allowed == Some(false) {
+        return;
+    }
+
+    let cached = match state.redis().get::<CachedMessage>(&update.id).await {
+        Ok(cached) => cached,
+        Err(error) => {
+            error!(error = ?error, message = ?update.id, "failed to get cached message");
+            None
+        }
+    };
+
+    let parsed = match parse_message_update(&update, cached.as_ref(), state.phishing()) {
+        Some(parsed) => parsed,
+        None => return,
+    };
+
+    // Updates that do not change the content (e.g. embeds being loaded) are
+    // ignored.
+    if cached
+        .as_ref()
+        .map(|cached| cached.content == parsed.content)
+        == Some(true)
+    {
+        return;
+    }
+
+    let config = guild_config(guild_id, &state).await;
+    cache_message(&parsed, config.as_ref(), &state).await;
+
+    let config = match config {
+        Some(config) => config,
+        None => return,
+    };
+
+    if let Some(cached) = &cached {
+        logs::message_update(cached, &parsed, &config, &state).await;
+    }
+
+    automod(&parsed, &config, None, &state).await;
+}
+
+/// Handle incoming [`MessageDelete`].
+///
+/// If the deleted message is cached, it is logged in the message logs channel.
+pub async fn handle_message_delete(event: MessageDelete, state: Arc<ClusterState>) {
+    let guild_id = match event.guild_id {
+        Some(guild_id) => guild_id,
+        None => return,
+    };
+
+    let message = match state.redis().get::<CachedMessage>(&event.id).await {
+        Ok(Some(message)) => message,
+        Ok(None) => return,
+        Err(error) => {
+            error!(error = ?error, message = ?event.id, "failed to get cached message");
+            return;
+        }
+    };
+
+    if let Some(config) = guild_config(guild_id, &state).await {
+        logs::message_delete(&message, &config, &state).await;
+    }
+}
+
+/// Handle incoming [`MessageDeleteBulk`].
+///
+/// The deleted messages that are cached are logged in the message logs
+/// channel with a transcript.
+pub async fn handle_message_delete_bulk(event: MessageDeleteBulk, state: Arc<ClusterState>) {
+    let guild_id = match event.guild_id {
+        Some(guild_id) => guild_id,
+        None => return,
+    };
+
+    let config = match guild_config(guild_id, &state).await {
+        Some(config) if config.messages.logs.is_some() => config,
+        _ => return,
+    };
+
+    let messages = match state.redis().get_many::<CachedMessage>(&event.ids).await {
+        Ok(messages) => messages,
+        Err(error) => {
+            error!(error = ?error, channel = ?event.channel_id, "failed to get cached messages");
+            Vec::new()
+        }
+    };
+
+    logs::message_delete_bulk(
+        event.channel_id,
+        event.ids.len(),
+        &messages,
+        &config,
+        &state,
+    )
+    .await;
+}
+
+/// Get the configuration of a guild.
+///
+/// Errors are logged and [`None`] is returned.
+async fn guild_config(guild_id: Id<GuildMarker>, state: &ClusterState) -> Option<GuildConfig> {
+    match state.mongodb().get_guild(guild_id).await {
+        Ok(config) => config,
+        Err(error) => {
+            error!(error = ?error, guild = ?guild_id, "failed to get guild configuration");
+            None
+        }
+    }
+}
+
+/// Store a message in the cache.
+///
+/// The message is kept in the cache for the retention duration configured
+/// for the guild, or the default duration if the guild is not configured.
+async fn cache_message(
+    message: &CachedMessage,
+    config: Option<&GuildConfig>,
+    state: &ClusterState,
+) {
+    let result = match config {
+        Some(config) => {
+            let retention = config.messages.retention.min(MessagesConfig::MAX_RETENTION);
+            state.redis().set_ex(message, retention as usize).await
+        }
+        None => state.redis().set(message).await,
+    };
+
+    if let Err(error) = result {
+        error!(error = ?error, message = ?message.id, "failed to cache message");
+    }
+}
+
 async fn warn_old_command(message: Message, state: Arc<ClusterState>) {
     let lang = message
         .author
@@ -0,0 +1,308 @@
+//! Message logs.
+//!
+//! Edited and deleted messages are logged in the message logs channel
+//! configured for the guild. Only messages that are still stored in the cache
+//! can be logged, the cache retention duration is configured with
+//! [`MessagesConfig::retention`].
+//!
+//! [`MessagesConfig::retention`]: raidprotect_model::database::model::MessagesConfig::retention
+
+use std::fmt::Write;
+
+use raidprotect_model::{cache::model::message::CachedMessage, database::model::GuildConfig};
+use tracing::error;
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::{embed::Embed, Attachment},
+    http::attachment::Attachment as FileAttachment,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    interaction::embed::{COLOR_RED, COLOR_TRANSPARENT},
+    translations::Lang,
+    util::{send_log_to, TextProcessExt},
+};
+
+/// Log an edited message.
+pub async fn message_update(
+    before: &CachedMessage,
+    after: &CachedMessage,
+    config: &GuildConfig,
+    state: &ClusterState,
+) {
+    let lang = Lang::from(&*config.lang);
+    let embed = update_embed(before, after, config.id, lang);
+
+    if let Err(error) = send_log(embed, before, config, state).await {
+        error!(error = ?error, message = ?before.id, "failed to log message update");
+    }
+}
+
+/// Log a deleted message.
+pub async fn message_delete(message: &CachedMessage, config: &GuildConfig, state: &ClusterState) {
+    let lang = Lang::from(&*config.lang);
+    let embed = delete_embed(message, lang);
+
+    if let Err(error) = send_log(embed, message, config, state).await {
+        error!(error = ?error, message = ?message.id, "failed to log message deletion");
+    }
+}
+
+/// Log messages deleted in bulk.
+///
+/// A transcript of the deleted messages that are cached is attached to the
+/// log entry, so the content of the messages is kept after a purge.
+pub async fn message_delete_bulk(
+    channel_id: Id<ChannelMarker>,
+    deleted: usize,
+    messages: &[CachedMessage],
+    config: &GuildConfig,
+    state: &ClusterState,
+) {
+    if let Err(error) =
+        message_delete_bulk_inner(channel_id, deleted, messages, config, state).await
+    {
+        error!(error = ?error, channel = ?channel_id, "failed to log bulk message deletion");
+    }
+}
+
+async fn message_delete_bulk_inner(
+    channel_id: Id<ChannelMarker>,
+    deleted: usize,
+    messages: &[CachedMessage],
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let logs_channel = match config.messages.logs {
+        Some(channel) if channel != channel_id => channel,
+        _ => return Ok(()),
+    };
+
+    let lang = Lang::from(&*config.lang);
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.message_logs_bulk_title())
+        .description(lang.message_logs_bulk_description(
+            messages.len(),
+            channel_id.mention(),
+            deleted,
+        ))
+        .build();
+
+    let attachments = if messages.is_empty() {
+        Vec::new()
+    } else {
+        let filename = format!("transcript-{channel_id}.txt");
+        vec![FileAttachment::from_bytes(
+            filename,
+            transcript(messages).into_bytes(),
+            1,
+        )]
+    };
+
+    send_log_to(state, config.id, logs_channel, embed, &attachments).await
+}
+
+/// Build a plain-text transcript of a list of messages.
+///
+/// Messages are sorted in chronological order.
+fn transcript(messages: &[CachedMessage]) -> String {
+    let mut messages = messages.iter().collect::<Vec<_>>();
+    messages.sort_by_key(|message| message.id);
+
+    let mut transcript = String::new();
+
+    for message in messages {
+        // Writing to a String never fails.
+        let _ = writeln!(
+            transcript,
+            "[{}] {} (message {})",
+            message.timestamp.iso_8601(),
+            message.author_id,
+            message.id
+        );
+
+        if !message.content.is_empty() {
+            let _ = writeln!(transcript, "{}", message.content);
+        }
+
+        for attachment in &message.attachments {
+            let _ = writeln!(transcript, "Attachment: {}", attachment.url);
+        }
+
+        transcript.push('\n');
+    }
+
+    transcript
+}
+
+/// Send a log entry in the message logs channel.
+///
+/// Nothing is sent if message logs are disabled or if the message was sent in
+/// the logs channel.
+async fn send_log(
+    embed: Embed,
+    message: &CachedMessage,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let logs_channel = match config.messages.logs {
+        Some(channel) if channel != message.channel_id => channel,
+        _ => return Ok(()),
+    };
+
+    send_log_to(state, config.id, logs_channel, embed, &[]).await
+}
+
+fn update_embed(
+    before: &CachedMessage,
+    after: &CachedMessage,
+    guild_id: Id<GuildMarker>,
+    lang: Lang,
+) -> Embed {
+    let link = format!(
+        "https://discord.com/channels/{guild_id}/{}/{}",
+        after.channel_id, after.id
+    );
+    let description = lang.message_logs_update_description(
+        after.channel_id.mention(),
+        link,
+        after.author_id.mention(),
+    );
+
+    let mut embed = EmbedBuilder::new()
+        .color(COLOR_TRANSPARENT)
+        .title(lang.message_logs_update_title())
+        .description(description)
+        .field(EmbedFieldBuilder::new(
+            lang.message_logs_before(),
+            content_field(&before.content, lang),
+        ))
+        .field(EmbedFieldBuilder::new(
+            lang.message_logs_after(),
+            content_field(&after.content, lang),
+        ));
+
+    // Attachments can be removed when editing a message.
+    let removed: Vec<_> = before
+        .attachments
+        .iter()
+        .filter(|attachment| !after.attachments.iter().any(|a| a.id == attachment.id))
+        .cloned()
+        .collect();
+
+    if !removed.is_empty() {
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.message_logs_attachments(),
+            attachments_field(&removed),
+        ));
+    }
+
+    embed
+        .footer(EmbedFooterBuilder::new(footer(after, lang)))
+        .build()
+}
+
+fn delete_embed(message: &CachedMessage, lang: Lang) -> Embed {
+    let description = lang
+        .message_logs_delete_description(message.channel_id.mention(), message.author_id.mention());
+
+    let mut embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.message_logs_delete_title())
+        .description(description)
+        .field(EmbedFieldBuilder::new(
+            lang.message_logs_content(),
+            content_field(&message.content, lang),
+        ));
+
+    if !message.attachments.is_empty() {
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.message_logs_attachments(),
+            attachments_field(&message.attachments),
+        ));
+    }
+
+    embed
+        .footer(EmbedFooterBuilder::new(footer(message, lang)))
+        .build()
+}
+
+/// Format the message content for an embed field.
+fn content_field(content: &str, lang: Lang) -> String {
+    if content.is_empty() {
+        lang.message_logs_empty().to_string()
+    } else {
+        content.to_string().truncate(1024)
+    }
+}
+
+/// Format a list of attachments for an embed field.
+fn attachments_field(attachments: &[Attachment]) -> String {
+    attachments
+        .iter()
+        .map(|attachment| format!("[{}]({})", attachment.filename, attachment.url))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .truncate(1024)
+}
+
+fn footer(message: &CachedMessage, lang: Lang) -> String {
+    lang.message_logs_footer(message.id, message.author_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::util::Timestamp;
+
+    use super::*;
+    use crate::feature::fixtures;
+
+    fn message(id: u64, content: &str, attachments: Vec<Attachment>) -> CachedMessage {
+        CachedMessage {
+            id: Id::new(id),
+            author_id: Id::new(10),
+            timestamp: Timestamp::from_secs(1_650_000_000 + id as i64).unwrap(),
+            attachments,
+            ..fixtures::message(content)
+        }
+    }
+
+    #[test]
+    fn test_transcript() {
+        let attachment = Attachment {
+            content_type: None,
+            ephemeral: false,
+            filename: "image.png".to_string(),
+            description: None,
+            height: None,
+            id: Id::new(30),
+            proxy_url: "https://media.discordapp.net/image.png".to_string(),
+            size: 1024,
+            url: "https://cdn.discordapp.com/image.png".to_string(),
+            width: None,
+        };
+
+        let messages = vec![
+            message(2, "", vec![attachment]),
+            message(1, "hello world", Vec::new()),
+        ];
+
+        let expected = "\
+[2022-04-15T05:20:01.000000+00:00] 10 (message 1)
+hello world
+
+[2022-04-15T05:20:02.000000+00:00] 10 (message 2)
+Attachment: https://cdn.discordapp.com/image.png
+
+";
+
+        assert_eq!(transcript(&messages), expected);
+    }
+}
@@ -3,11 +3,15 @@
 //! This module contain logic used to handle incoming message, such as spam
 //! detection.
 
+mod automod;
 mod handle;
+mod logs;
 
 pub mod parser;
 
-pub use handle::handle_message;
+pub use handle::{
+    handle_message, handle_message_delete, handle_message_delete_bulk, handle_message_update,
+};
 use twilight_model::channel::message::MessageType;
 
 /// Messages types processed by the bot.
@@ -2,15 +2,37 @@
 //!
 //! This module is used to convert an incoming [`Message`] into a parsed
 //! [`CachedMessage`].
+//!
+//! Links are detected even when they are written in a way commonly used to
+//! bypass filters:
+//!
+//! - invisible characters (such as zero-width spaces) are removed before
+//!   parsing the message,
+//! - links without scheme (`discord.gg/abc`) are detected,
+//! - markdown masked links (`[text](url)`) are detected with both their
+//!   displayed text and real target,
+//! - invites broken up with spaces (`discord . gg / abc`) are detected.
+//!
+//! Links to domains that imitate a protected domain are flagged as phishing
+//! using a [`PhishingDetector`].
+//!
+//! Statistics on the content hidden in the message (invisible characters and
+//! empty spoilers) are computed to detect messages hiding mentions or padded
+//! with invisible characters.
+
+use std::borrow::Cow;
 
 use any_ascii::any_ascii;
 use linkify::{LinkFinder, LinkKind};
-use raidprotect_model::cache::model::message::{CachedMessage, MessageLink};
-use twilight_model::channel::Message;
+use once_cell::sync::Lazy;
+use raidprotect_model::cache::model::message::{CachedMessage, HiddenContent, MessageLink};
+use regex::Regex;
+use twilight_model::{channel::Message, gateway::payload::incoming::MessageUpdate};
 use unicode_segmentation::UnicodeSegmentation;
 use url::Url;
 
 use super::ALLOWED_MESSAGES_TYPES;
+use crate::feature::phishing::PhishingDetector;
 
 /// Domains used for Discord invitations link.
 const INVITE_DOMAINS: [&str; 3] = ["discord.gg", "discord.com", "discordapp.com"];
@@ -20,8 +42,53 @@ const MEDIA_EXT: [&str; 9] = [
     ".png", ".jpg", ".jpeg", ".gif", ".webp", ".webm", ".mp4", ".avi", ".mov",
 ];
 
+/// Invisible characters removed before parsing the message.
+const INVISIBLE_CHARS: [char; 7] = [
+    '\u{00AD}', // soft hyphen
+    '\u{180E}', // mongolian vowel separator
+    '\u{200B}', // zero width space
+    '\u{200C}', // zero width non-joiner
+    '\u{200D}', // zero width joiner
+    '\u{2060}', // word joiner
+    '\u{FEFF}', // zero width no-break space
+];
+
+/// Bidirectional text control characters.
+const BIDI_CHARS: [char; 12] = [
+    '\u{061C}', // arabic letter mark
+    '\u{200E}', // left-to-right mark
+    '\u{200F}', // right-to-left mark
+    '\u{202A}', // left-to-right embedding
+    '\u{202B}', // right-to-left embedding
+    '\u{202C}', // pop directional formatting
+    '\u{202D}', // left-to-right override
+    '\u{202E}', // right-to-left override
+    '\u{2066}', // left-to-right isolate
+    '\u{2067}', // right-to-left isolate
+    '\u{2068}', // first strong isolate
+    '\u{2069}', // pop directional isolate
+];
+
+/// Characters displayed as blank spaces that are not whitespaces.
+const BLANK_CHARS: [char; 5] = [
+    '\u{115F}', // hangul choseong filler
+    '\u{1160}', // hangul jungseong filler
+    '\u{2800}', // braille pattern blank
+    '\u{3164}', // hangul filler
+    '\u{FFA0}', // halfwidth hangul filler
+];
+
+/// Markdown masked link (`[text](url)`).
+static MASKED_LINK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[([^\[\]]*)\]\(\s*<?([^\s()<>]+)>?\s*\)").unwrap());
+
+/// Discord invite in a message without whitespaces.
+static COMPACT_INVITE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:discord\.gg|discord(?:app)?\.com/invite)/([a-z0-9-]{2,32})").unwrap()
+});
+
 /// Parse incoming [`Message`] into a [`CachedMessage`].
-pub fn parse_message(message: &Message) -> CachedMessage {
+pub fn parse_message(message: &Message, phishing: &PhishingDetector) -> CachedMessage {
     // Only these message types are processed.
     // This must be enforced in the gateway crate.
     debug_assert!(
@@ -33,13 +100,7 @@ pub fn parse_message(message: &Message) -> CachedMessage {
         "unsupported message type"
     );
 
-    let words = message.content.unicode_words().map(any_ascii).collect();
     let mention_users = message.mentions.iter().map(|mention| mention.id).collect();
-    let links = LinkFinder::new()
-        .kinds(&[LinkKind::Url])
-        .links(&message.content)
-        .filter_map(|link| parse_link(link.as_str()))
-        .collect();
 
     CachedMessage {
         id: message.id,
@@ -47,22 +108,224 @@ pub fn parse_message(message: &Message) -> CachedMessage {
         channel_id: message.channel_id,
         content: message.content.clone(),
         timestamp: message.timestamp,
-        words,
+        words: parse_words(&message.content),
         attachments: message.attachments.clone(),
-        links,
+        links: parse_links(&message.content, phishing),
+        hidden: parse_hidden(&message.content),
         mention_everyone: message.mention_everyone,
         mention_users,
         mention_roles: message.mention_roles.clone(),
     }
 }
 
-fn parse_link(link: &str) -> Option<MessageLink> {
-    let url = Url::parse(link).ok()?;
+/// Parse incoming [`MessageUpdate`] into an updated [`CachedMessage`].
+///
+/// The updated fields are applied on the previously cached message. If the
+/// message is no longer cached, a new [`CachedMessage`] is created if the
+/// update contains the message author and timestamp.
+///
+/// If the message content has not been updated (e.g. when embeds are loaded),
+/// [`None`] is returned.
+pub fn parse_message_update(
+    update: &MessageUpdate,
+    cached: Option<&CachedMessage>,
+    phishing: &PhishingDetector,
+) -> Option<CachedMessage> {
+    let content = update.content.as_ref()?;
+
+    let mut message = match cached {
+        Some(cached) => cached.clone(),
+        None => CachedMessage {
+            id: update.id,
+            author_id: update.author.as_ref()?.id,
+            channel_id: update.channel_id,
+            content: String::new(),
+            timestamp: update.timestamp?,
+            words: Vec::new(),
+            attachments: Vec::new(),
+            links: Vec::new(),
+            hidden: HiddenContent::default(),
+            mention_everyone: false,
+            mention_users: Vec::new(),
+            mention_roles: Vec::new(),
+        },
+    };
+
+    message.content = content.clone();
+    message.words = parse_words(content);
+    message.links = parse_links(content, phishing);
+    message.hidden = parse_hidden(content);
+
+    if let Some(attachments) = &update.attachments {
+        message.attachments = attachments.clone();
+    }
+    if let Some(mention_everyone) = update.mention_everyone {
+        message.mention_everyone = mention_everyone;
+    }
+    if let Some(mentions) = &update.mentions {
+        message.mention_users = mentions.iter().map(|mention| mention.id).collect();
+    }
+    if let Some(mention_roles) = &update.mention_roles {
+        message.mention_roles = mention_roles.clone();
+    }
 
-    if INVITE_DOMAINS.contains(&url.domain()?) {
+    Some(message)
+}
+
+/// Split the message content into words converted to ASCII.
+fn parse_words(content: &str) -> Vec<String> {
+    remove_invisible(content)
+        .unicode_words()
+        .map(any_ascii)
+        .collect()
+}
+
+/// Find the links included in the message content.
+fn parse_links(content: &str, phishing: &PhishingDetector) -> Vec<MessageLink> {
+    let content = remove_invisible(content);
+
+    // Masked links are removed from the content to avoid detecting their
+    // target twice.
+    let mut links = Vec::new();
+    let content = MASKED_LINK.replace_all(&content, |captures: &regex::Captures| {
+        if let Some(target) = parse_link(&captures[2], phishing) {
+            links.push(MessageLink::Masked {
+                text: captures[1].to_string(),
+                target: Box::new(target),
+            });
+        }
+
+        " "
+    });
+
+    links.extend(
+        LinkFinder::new()
+            .kinds(&[LinkKind::Url])
+            .url_must_have_scheme(false)
+            .links(&content)
+            .filter_map(|link| parse_link(link.as_str(), phishing)),
+    );
+
+    if !links
+        .iter()
+        .any(|link| matches!(link.target(), MessageLink::Invite(_)))
+    {
+        links.extend(parse_compact_invites(&content));
+    }
+
+    links
+}
+
+/// Find invites broken up with whitespaces.
+///
+/// Whitespaces are removed from the content before searching for invites. The
+/// invite code may include the beginning of the following word, but this is
+/// sufficient to detect the invite.
+fn parse_compact_invites(content: &str) -> Vec<MessageLink> {
+    let compact: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+
+    COMPACT_INVITE
+        .captures_iter(&compact)
+        .filter_map(|captures| Url::parse(&format!("https://discord.gg/{}", &captures[1])).ok())
+        .map(MessageLink::Invite)
+        .collect()
+}
+
+/// Whether the URL is a Discord invite.
+///
+/// Links to the invite domains without an invite code are not invites.
+fn is_invite(url: &Url) -> bool {
+    let segments: Vec<_> = match url.path_segments() {
+        Some(segments) => segments.filter(|s| !s.is_empty()).collect(),
+        None => return false,
+    };
+
+    match url.domain() {
+        Some("discord.gg") => segments.len() == 1,
+        Some(domain) if INVITE_DOMAINS.contains(&domain) => {
+            segments.len() == 2 && segments[0] == "invite"
+        }
+        _ => false,
+    }
+}
+
+/// Compute statistics on the hidden content of a message.
+///
+/// A spoiler is empty if it only contains whitespaces and invisible
+/// characters. Spoilers separated only by whitespaces are part of the same
+/// chain.
+fn parse_hidden(content: &str) -> HiddenContent {
+    let mut hidden = HiddenContent::default();
+    let mut chain = 0;
+
+    let parts: Vec<&str> = content.split("||").collect();
+    let last = parts.len() - 1;
+
+    for (index, part) in parts.into_iter().enumerate() {
+        // Odd parts are inside a spoiler, unless the spoiler is not closed.
+        let is_spoiler = index % 2 == 1 && index < last;
+        let is_blank = part.chars().all(|c| c.is_whitespace() || is_invisible(c));
+
+        if is_spoiler && is_blank {
+            hidden.spoilers += part.chars().count() + 4;
+            chain += 1;
+            hidden.spoiler_chain = hidden.spoiler_chain.max(chain);
+
+            continue;
+        }
+
+        if !is_blank {
+            chain = 0;
+        }
+
+        for c in part.chars() {
+            if is_invisible(c) {
+                hidden.invisible += 1;
+            } else if !c.is_whitespace() {
+                hidden.visible += 1;
+            }
+        }
+    }
+
+    hidden
+}
+
+/// Whether a character is not displayed.
+fn is_invisible(c: char) -> bool {
+    INVISIBLE_CHARS.contains(&c)
+        || BIDI_CHARS.contains(&c)
+        || BLANK_CHARS.contains(&c)
+        || (c.is_control() && !c.is_whitespace())
+}
+
+/// Remove invisible characters from the content.
+fn remove_invisible(content: &str) -> Cow<'_, str> {
+    if content.contains(INVISIBLE_CHARS) {
+        Cow::Owned(content.replace(INVISIBLE_CHARS, ""))
+    } else {
+        Cow::Borrowed(content)
+    }
+}
+
+fn parse_link(link: &str, phishing: &PhishingDetector) -> Option<MessageLink> {
+    // Links without scheme are parsed as HTTPS links.
+    let url = if link.contains("://") {
+        Url::parse(link).ok()?
+    } else {
+        Url::parse(&format!("https://{link}")).ok()?
+    };
+
+    if is_invite(&url) {
         return Some(MessageLink::Invite(url));
     }
 
+    if let Some(domain) = url.domain().and_then(|domain| phishing.check(domain)) {
+        return Some(MessageLink::Phishing {
+            domain: domain.to_string(),
+            url,
+        });
+    }
+
     let last_path = url.path_segments()?.last()?;
     for extension in MEDIA_EXT {
         if last_path.ends_with(extension) {
@@ -75,52 +338,278 @@ fn parse_link(link: &str) -> Option<MessageLink> {
 
 #[cfg(test)]
 mod tests {
+    use raidprotect_model::config::DEFAULT_PROTECTED_DOMAINS;
+    use twilight_model::id::Id;
+
     use super::*;
+    use crate::feature::fixtures;
+
+    fn detector() -> PhishingDetector {
+        PhishingDetector::new(&DEFAULT_PROTECTED_DOMAINS)
+    }
 
     #[test]
     fn test_link_invite() {
         assert_eq!(
-            parse_link("https://discord.gg/raidprotect"),
+            parse_link("https://discord.gg/raidprotect", &detector()),
             Some(MessageLink::Invite(
                 Url::parse("https://discord.gg/raidprotect").unwrap()
             ))
         );
 
         assert_eq!(
-            parse_link("https://discord.com/invite/raidprotect"),
+            parse_link("https://discord.com/invite/raidprotect", &detector()),
             Some(MessageLink::Invite(
                 Url::parse("https://discord.com/invite/raidprotect").unwrap()
             ))
         );
 
         assert_eq!(
-            parse_link("https://discordapp.com/invite/raidprotect"),
+            parse_link("https://discordapp.com/invite/raidprotect", &detector()),
             Some(MessageLink::Invite(
                 Url::parse("https://discordapp.com/invite/raidprotect").unwrap()
             ))
         );
     }
 
+    #[test]
+    fn test_link_invite_without_code() {
+        assert_eq!(
+            parse_link("https://discord.com/channels/1/2", &detector()),
+            Some(MessageLink::Other(
+                Url::parse("https://discord.com/channels/1/2").unwrap()
+            ))
+        );
+    }
+
     #[test]
     fn test_link_media() {
         assert_eq!(
-            parse_link("https://cdn.discordapp.com/attachments/618052865725825044/956984958184984586/Capture_decran_2022-03-25_193605.png"),
+            parse_link("https://cdn.discordapp.com/attachments/618052865725825044/956984958184984586/Capture_decran_2022-03-25_193605.png", &detector()),
             Some(MessageLink::Media(Url::parse("https://cdn.discordapp.com/attachments/618052865725825044/956984958184984586/Capture_decran_2022-03-25_193605.png").unwrap()))
         );
 
         assert_eq!(
-            parse_link("https://cdn.discordapp.com/attachments/796185053351772191/872796992357695548/video0-16-2.mp4"),
+            parse_link("https://cdn.discordapp.com/attachments/796185053351772191/872796992357695548/video0-16-2.mp4", &detector()),
             Some(MessageLink::Media(Url::parse("https://cdn.discordapp.com/attachments/796185053351772191/872796992357695548/video0-16-2.mp4").unwrap()))
         );
     }
 
+    fn message_update(content: Option<&str>) -> MessageUpdate {
+        MessageUpdate {
+            attachments: None,
+            author: None,
+            channel_id: Id::new(2),
+            content: content.map(String::from),
+            edited_timestamp: None,
+            embeds: None,
+            guild_id: Some(Id::new(3)),
+            id: Id::new(1),
+            kind: None,
+            mention_everyone: None,
+            mention_roles: None,
+            mentions: None,
+            pinned: None,
+            timestamp: None,
+            tts: None,
+        }
+    }
+
+    fn cached_message(content: &str) -> CachedMessage {
+        CachedMessage {
+            words: parse_words(content),
+            links: parse_links(content, &detector()),
+            hidden: parse_hidden(content),
+            mention_roles: vec![Id::new(5)],
+            ..fixtures::message(content)
+        }
+    }
+
+    #[test]
+    fn test_message_update() {
+        let cached = cached_message("hello world");
+        let update = message_update(Some("join discord.gg https://discord.gg/raidprotect"));
+
+        let expected = CachedMessage {
+            content: "join discord.gg https://discord.gg/raidprotect".to_string(),
+            words: parse_words("join discord.gg https://discord.gg/raidprotect"),
+            links: vec![
+                MessageLink::Other(Url::parse("https://discord.gg").unwrap()),
+                MessageLink::Invite(Url::parse("https://discord.gg/raidprotect").unwrap()),
+            ],
+            hidden: parse_hidden("join discord.gg https://discord.gg/raidprotect"),
+            ..cached.clone()
+        };
+
+        assert_eq!(
+            parse_message_update(&update, Some(&cached), &detector()),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn test_message_update_no_content() {
+        let cached = cached_message("hello world");
+        let update = message_update(None);
+
+        assert_eq!(
+            parse_message_update(&update, Some(&cached), &detector()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_message_update_not_cached() {
+        let update = message_update(Some("hello world"));
+
+        // The author and timestamp are required to create a new message.
+        assert_eq!(parse_message_update(&update, None, &detector()), None);
+    }
+
+    #[test]
+    fn test_links_schemeless() {
+        assert_eq!(
+            parse_links("join discord.gg/raidprotect now", &detector()),
+            vec![MessageLink::Invite(
+                Url::parse("https://discord.gg/raidprotect").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_links_invisible_chars() {
+        assert_eq!(
+            parse_links(
+                "https://disc\u{200B}ord.gg/raid\u{FEFF}protect",
+                &detector()
+            ),
+            vec![MessageLink::Invite(
+                Url::parse("https://discord.gg/raidprotect").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_links_compact_invite() {
+        assert_eq!(
+            parse_links("discord . gg / raidprotect", &detector()),
+            vec![MessageLink::Invite(
+                Url::parse("https://discord.gg/raidprotect").unwrap()
+            )]
+        );
+
+        assert_eq!(
+            parse_links("d i s c o r d . c o m / invite / raidprotect", &detector()),
+            vec![MessageLink::Invite(
+                Url::parse("https://discord.gg/raidprotect").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_links_masked() {
+        let links = parse_links("look at [google.com](https://evil.tld/login)", &detector());
+
+        assert_eq!(
+            links,
+            vec![MessageLink::Masked {
+                text: "google.com".to_string(),
+                target: Box::new(MessageLink::Other(
+                    Url::parse("https://evil.tld/login").unwrap()
+                )),
+            }]
+        );
+        assert_eq!(links[0].url().as_str(), "https://evil.tld/login");
+    }
+
+    #[test]
+    fn test_links_masked_invite() {
+        let links = parse_links(
+            "[click here](<https://discord.gg/raidprotect>)",
+            &detector(),
+        );
+
+        assert_eq!(
+            links[0].target(),
+            &MessageLink::Invite(Url::parse("https://discord.gg/raidprotect").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_link_phishing() {
+        assert_eq!(
+            parse_link("https://dlscord.gift/nitro", &detector()),
+            Some(MessageLink::Phishing {
+                url: Url::parse("https://dlscord.gift/nitro").unwrap(),
+                domain: "discord.com".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn test_link_other() {
         assert_eq!(
-            parse_link("https://raidprotect.org/"),
+            parse_link("https://raidprotect.org/", &detector()),
             Some(MessageLink::Other(
                 Url::parse("https://raidprotect.org/").unwrap()
             ))
         );
     }
+
+    #[test]
+    fn test_hidden_plain() {
+        assert_eq!(
+            parse_hidden("hello ||world||"),
+            HiddenContent {
+                visible: 10,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_hidden_invisible() {
+        assert_eq!(
+            parse_hidden("hi\u{200B}\u{200B}\u{202E}\u{3164}"),
+            HiddenContent {
+                visible: 2,
+                invisible: 4,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_hidden_spoiler_chain() {
+        let content = format!("hi {} <@1>", "||\u{200B}||".repeat(3));
+
+        assert_eq!(
+            parse_hidden(&content),
+            HiddenContent {
+                visible: 6,
+                invisible: 0,
+                spoilers: 15,
+                spoiler_chain: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_hidden_spoiler_chain_broken() {
+        let hidden = parse_hidden("||\u{200B}|| a ||\u{200B}|| ||||");
+
+        assert_eq!(hidden.spoilers, 14);
+        assert_eq!(hidden.spoiler_chain, 2);
+    }
+
+    #[test]
+    fn test_hidden_unclosed_spoiler() {
+        assert_eq!(
+            parse_hidden("||hello"),
+            HiddenContent {
+                visible: 5,
+                ..Default::default()
+            }
+        );
+    }
 }
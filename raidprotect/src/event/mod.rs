@@ -5,8 +5,12 @@
 //!
 //! The user-side event handling is done in the `raidprotect_handler` crate.
 
+mod antinuke;
 mod captcha;
 mod message;
+mod names;
+mod persist;
 mod process;
+mod raid;
 
 pub use process::ProcessEvent;
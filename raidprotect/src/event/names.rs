@@ -0,0 +1,151 @@
+//! Username and nickname rules event processing.
+//!
+//! The names of members are checked against the rules configured for the
+//! guild when the following events are received:
+//!
+//! - `MemberAdd`: the username and nickname of the new member are checked.
+//! - `MemberUpdate`: the username and nickname are checked again, to catch
+//!   members changing their names after joining.
+//!
+//! Compiled rules are cached in memory for each guild, and compiled again when
+//! the guild configuration changes.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use once_cell::sync::Lazy;
+use raidprotect_model::{
+    cache::model::CachedMember,
+    database::model::{GuildConfig, NameAction, NameRule},
+};
+use tracing::error;
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::embed::Embed,
+    gateway::payload::incoming::MemberUpdate,
+    guild::Member,
+    id::{marker::GuildMarker, Id},
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    feature::names::{action_name, apply_action, is_exempt, rule_kind_name, NameMatcher},
+    interaction::embed::COLOR_RED,
+    translations::Lang,
+    util::{send_log, TextProcessExt},
+};
+
+/// Compiled name rules of each guild.
+static CACHE: Lazy<RwLock<HashMap<Id<GuildMarker>, Arc<NameMatcher>>>> =
+    Lazy::new(Default::default);
+
+/// Handle `MemberAdd` event.
+///
+/// Returns `true` if the member has been removed from the guild, in which case
+/// the captcha is skipped.
+pub async fn member_add(member: &Member, state: &ClusterState) -> bool {
+    let name = CachedMember::from(member);
+
+    match check_member(member.guild_id, &name, state).await {
+        Ok(Some(action)) => action != NameAction::Rename,
+        Ok(None) => false,
+        Err(error) => {
+            error!(error = ?error, member = ?member, "error while checking names for `MemberAdd` event");
+
+            false
+        }
+    }
+}
+
+/// Handle `MemberUpdate` event.
+pub async fn member_update(event: &MemberUpdate, state: &ClusterState) {
+    let name = CachedMember::from(event);
+
+    if let Err(error) = check_member(event.guild_id, &name, state).await {
+        error!(error = ?error, event = ?event, "error while checking names for `MemberUpdate` event");
+    }
+}
+
+/// Check the names of a member against the guild rules.
+///
+/// Returns the action performed on the member, if any.
+async fn check_member(
+    guild_id: Id<GuildMarker>,
+    member: &CachedMember,
+    state: &ClusterState,
+) -> Result<Option<NameAction>, anyhow::Error> {
+    let config = match state.mongodb().get_guild(guild_id).await? {
+        Some(config) if !config.names.rules.is_empty() => config,
+        _ => return Ok(None),
+    };
+
+    let matcher = compiled(guild_id, &config.names.rules);
+    let rule = match matcher.find(&member.name, member.nick.as_deref()) {
+        Some(rule) => rule,
+        None => return Ok(None),
+    };
+
+    if is_exempt(member, &config, state).await? {
+        return Ok(None);
+    }
+
+    apply_action(member, &config, state).await?;
+    send_log(state, &config, log_embed(member, rule, &config)).await?;
+
+    Ok(Some(config.names.action))
+}
+
+/// Get the compiled name rules of a guild.
+fn compiled(guild_id: Id<GuildMarker>, rules: &[NameRule]) -> Arc<NameMatcher> {
+    let cached = CACHE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&guild_id)
+        .cloned();
+
+    if let Some(cached) = cached {
+        if cached.is_current(rules) {
+            return cached;
+        }
+    }
+
+    let compiled = Arc::new(NameMatcher::new(rules));
+    CACHE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(guild_id, compiled.clone());
+
+    compiled
+}
+
+/// Build the log entry of a member with a matching name.
+fn log_embed(member: &CachedMember, rule: &NameRule, config: &GuildConfig) -> Embed {
+    let lang = Lang::from(&*config.lang);
+
+    EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.names_log_title())
+        .description(lang.names_log_description(
+            member.user_id,
+            member.name.remove_markdown().truncate(32),
+            member.user_id.mention(),
+        ))
+        .field(
+            EmbedFieldBuilder::new(
+                lang.names_log_rule(),
+                lang.names_rule_entry(rule_kind_name(rule.kind, lang), &rule.pattern),
+            )
+            .inline(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                lang.names_log_action(),
+                action_name(config.names.action, lang),
+            )
+            .inline(),
+        )
+        .build()
+}
@@ -0,0 +1,146 @@
+//! Role persistence event processing.
+//!
+//! The following events are handled:
+//!
+//! - `MemberRemove`: the persisted roles of the leaving member are saved. The
+//!   roles are read from the cache, so this must run before the cache update.
+//! - `MemberAdd`: the saved roles are reapplied to the returning member, before
+//!   any other protection is applied. The saved roles are only deleted once
+//!   reapplied, so they are not lost if the member cannot be updated.
+//!
+//! See the [`feature::persist`](crate::feature::persist) module for more
+//! information.
+
+use raidprotect_model::{cache::model::CachedRole, database::model::PersistedRoles};
+use time::{Duration, OffsetDateTime};
+use tracing::{debug, error};
+use twilight_http::request::AuditLogReason;
+use twilight_model::{gateway::payload::incoming::MemberRemove, guild::Member};
+
+use crate::{
+    cluster::ClusterState,
+    feature::{persist::persisted_roles, quarantine::restored_roles},
+    translations::Lang,
+};
+
+/// Handle `MemberAdd` event.
+///
+/// Returns `true` if the member has been quarantined again, in which case the
+/// other protections are skipped.
+pub async fn member_add(member: &Member, state: &ClusterState) -> bool {
+    match member_add_inner(member, state).await {
+        Ok(quarantined) => quarantined,
+        Err(error) => {
+            error!(error = ?error, member = ?member, "error while reapplying persisted roles");
+
+            false
+        }
+    }
+}
+
+async fn member_add_inner(member: &Member, state: &ClusterState) -> Result<bool, anyhow::Error> {
+    // Ensure the member has joined recently to ignore members sent on bot
+    // startup.
+    let now = OffsetDateTime::now_utc();
+    let joined_at = OffsetDateTime::from_unix_timestamp(member.joined_at.as_secs())?;
+
+    if (now - joined_at) > Duration::seconds(5) || member.user.bot {
+        return Ok(false);
+    }
+
+    let persisted = match state
+        .mongodb()
+        .get_persisted_roles(member.guild_id, member.user.id)
+        .await?
+    {
+        Some(persisted) => persisted,
+        None => return Ok(false),
+    };
+
+    let config = state.mongodb().get_guild_or_create(member.guild_id).await?;
+    let lang = Lang::from(&*config.lang);
+
+    let bot_highest = state
+        .redis()
+        .permissions(member.guild_id)
+        .await?
+        .current_member()
+        .await?
+        .highest_role();
+
+    // Deleted roles are not part of the cache, and roles that cannot be
+    // assigned by the bot are ignored.
+    let saved = state
+        .redis()
+        .get_many::<CachedRole>(&persisted.roles)
+        .await?;
+    let roles = restored_roles(&member.roles, &saved, None, bot_highest);
+
+    let quarantined = if roles.len() != member.roles.len() {
+        debug!(roles = ?roles, "reapplying persisted roles");
+
+        state
+            .cache_http(member.guild_id)
+            .update_guild_member_roles(member.user.id, &roles)
+            .await?
+            .reason(lang.persist_reason())?
+            .exec()
+            .await?;
+
+        matches!(config.quarantine.role, Some(role) if roles.contains(&role))
+    } else {
+        false
+    };
+
+    // The saved roles are only deleted once reapplied, so they are kept if
+    // the member cannot be updated.
+    state
+        .mongodb()
+        .delete_persisted_roles(member.guild_id, member.user.id)
+        .await?;
+
+    Ok(quarantined)
+}
+
+/// Handle `MemberRemove` event.
+pub async fn member_remove(event: &MemberRemove, state: &ClusterState) {
+    if let Err(error) = member_remove_inner(event, state).await {
+        error!(error = ?error, event = ?event, "error while saving persisted roles");
+    }
+}
+
+async fn member_remove_inner(
+    event: &MemberRemove,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    if event.user.bot {
+        return Ok(());
+    }
+
+    let member = match state.redis().member(event.guild_id, event.user.id).await? {
+        Some(member) => member,
+        None => return Ok(()),
+    };
+
+    let config = match state.mongodb().get_guild(event.guild_id).await? {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    let roles = persisted_roles(&member.roles, &config);
+
+    if roles.is_empty() {
+        return Ok(());
+    }
+
+    let persisted = PersistedRoles {
+        guild_id: event.guild_id,
+        user_id: event.user.id,
+        roles,
+        date: OffsetDateTime::now_utc(),
+    };
+
+    state.mongodb().set_persisted_roles(&persisted).await?;
+
+    Ok(())
+}
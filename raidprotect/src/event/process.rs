@@ -1,11 +1,11 @@
 use std::{fmt::Debug, sync::Arc};
 
 use async_trait::async_trait;
-use raidprotect_model::cache::UpdateCache;
+use raidprotect_model::cache::{model::antinuke::DestructiveAction, UpdateCache};
 use tracing::{debug, error, trace};
 use twilight_model::gateway::{event::Event as GatewayEvent, payload::incoming};
 
-use super::message::ALLOWED_MESSAGES_TYPES;
+use super::{message::ALLOWED_MESSAGES_TYPES, raid::JoinOutcome};
 use crate::cluster::ClusterState;
 
 /// Process incoming events.
@@ -63,12 +63,20 @@ impl ProcessEvent for GatewayEvent {
             InteractionCreate,
             ThreadCreate,
             ThreadDelete,
+            ThreadMembersUpdate,
             ThreadUpdate,
             RoleCreate,
             RoleDelete,
             MemberAdd,
             MemberUpdate,
-            MessageCreate
+            MemberRemove,
+            MemberChunk,
+            BanAdd,
+            WebhooksUpdate,
+            MessageCreate,
+            MessageDelete,
+            MessageDeleteBulk,
+            MessageUpdate
         }
     }
 }
@@ -80,14 +88,13 @@ process_cache_events! {
     UnavailableGuild,
     GuildUpdate,
     ChannelCreate,
-    ChannelDelete,
     ChannelUpdate,
     ThreadCreate,
     ThreadDelete,
+    ThreadMembersUpdate,
     ThreadUpdate,
     RoleCreate,
-    RoleDelete,
-    MemberUpdate
+    MemberChunk
 }
 
 #[async_trait]
@@ -106,10 +113,136 @@ impl ProcessEvent for incoming::MessageCreate {
     }
 }
 
+#[async_trait]
+impl ProcessEvent for incoming::MessageDelete {
+    async fn process(self, state: Arc<ClusterState>) {
+        if self.guild_id.is_some() {
+            super::message::handle_message_delete(self, state).await;
+        }
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::MessageDeleteBulk {
+    async fn process(self, state: Arc<ClusterState>) {
+        if self.guild_id.is_some() {
+            super::message::handle_message_delete_bulk(self, state).await;
+        }
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::MessageUpdate {
+    async fn process(self, state: Arc<ClusterState>) {
+        if self.guild_id.is_some() {
+            super::message::handle_message_update(self, state).await;
+        }
+    }
+}
+
 #[async_trait]
 impl ProcessEvent for incoming::MemberAdd {
     async fn process(self, state: Arc<ClusterState>) {
         process_cache_event(self.clone(), &state).await;
-        super::captcha::member_add(&self.0, state).await;
+
+        // Persisted roles are reapplied first. Members quarantined again do
+        // not need to be checked by other protections.
+        if super::persist::member_add(&self.0, &state).await {
+            return;
+        }
+
+        // Members removed by raid mode or name rules do not need to complete
+        // the captcha.
+        let outcome = super::raid::member_add(&self.0, &state).await;
+
+        if outcome == JoinOutcome::Removed || super::names::member_add(&self.0, &state).await {
+            return;
+        }
+
+        let required = outcome == JoinOutcome::RequireCaptcha;
+        super::captcha::member_add(&self.0, required, state).await;
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::MemberUpdate {
+    async fn process(self, state: Arc<ClusterState>) {
+        process_cache_event(self.clone(), &state).await;
+        super::names::member_update(&self, &state).await;
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::MemberRemove {
+    async fn process(self, state: Arc<ClusterState>) {
+        // Roles are read from the cache before it is updated.
+        super::persist::member_remove(&self, &state).await;
+        process_cache_event(self.clone(), &state).await;
+        super::antinuke::destructive_action(
+            self.guild_id,
+            DestructiveAction::Kick,
+            self.user.id.get(),
+            &state,
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::ChannelDelete {
+    async fn process(self, state: Arc<ClusterState>) {
+        process_cache_event(self.clone(), &state).await;
+
+        if let Some(guild_id) = self.guild_id {
+            super::antinuke::destructive_action(
+                guild_id,
+                DestructiveAction::ChannelDelete,
+                self.id.get(),
+                &state,
+            )
+            .await;
+        }
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::RoleDelete {
+    async fn process(self, state: Arc<ClusterState>) {
+        process_cache_event(self.clone(), &state).await;
+        super::antinuke::destructive_action(
+            self.guild_id,
+            DestructiveAction::RoleDelete,
+            self.role_id.get(),
+            &state,
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::BanAdd {
+    async fn process(self, state: Arc<ClusterState>) {
+        super::antinuke::destructive_action(
+            self.guild_id,
+            DestructiveAction::Ban,
+            self.user.id.get(),
+            &state,
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl ProcessEvent for incoming::WebhooksUpdate {
+    async fn process(self, state: Arc<ClusterState>) {
+        // This event is also sent when a webhook is updated or deleted, the
+        // audit log is used to only count webhook creations.
+        super::antinuke::destructive_action(
+            self.guild_id,
+            DestructiveAction::WebhookCreate,
+            self.channel_id.get(),
+            &state,
+        )
+        .await;
     }
 }
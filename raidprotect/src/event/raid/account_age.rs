@@ -0,0 +1,209 @@
+//! Account age gate.
+//!
+//! Members joining with an account younger than the minimum age configured
+//! for the guild are handled with the configured [`AccountAgeAction`]. The
+//! member is notified in direct message with the date at which its account
+//! will be old enough, and the action is logged in the logs channel.
+//!
+//! The `captcha` action requires the captcha to be enabled, and the
+//! `quarantine` action requires a quarantine role. Otherwise, the member is
+//! kicked.
+
+use raidprotect_model::database::model::{
+    AccountAgeAction, AccountAgeConfig, CaptchaConfig, GuildConfig, QuarantineConfig, RaidAction,
+};
+use time::{Duration, OffsetDateTime};
+use twilight_mention::Mention;
+use twilight_model::guild::Member;
+use twilight_util::{
+    builder::embed::{EmbedBuilder, EmbedFieldBuilder},
+    snowflake::Snowflake,
+};
+
+use super::{guild_name, notify, remove, JoinOutcome};
+use crate::{
+    cluster::ClusterState,
+    feature::{
+        quarantine::quarantine,
+        raid::{account_age_action_name, format_age},
+    },
+    interaction::embed::COLOR_RED,
+    translations::Lang,
+    util::{relative_timestamp, send_log},
+};
+
+/// Apply the account age gate to a new member.
+///
+/// Returns [`JoinOutcome::RequireCaptcha`] if the member must complete the
+/// captcha, and [`JoinOutcome::Removed`] if the member has been removed from
+/// the guild or quarantined.
+pub async fn account_age(
+    member: &Member,
+    config: &GuildConfig,
+    now: OffsetDateTime,
+    state: &ClusterState,
+) -> Result<JoinOutcome, anyhow::Error> {
+    let user_id = member.user.id;
+    let created_at = OffsetDateTime::from_unix_timestamp(user_id.timestamp() / 1000)?;
+    let allowed_at = created_at + Duration::seconds(config.account_age.min_age.into());
+
+    if allowed_at <= now {
+        return Ok(JoinOutcome::Continue);
+    }
+
+    let lang = Lang::from(&*config.lang);
+    let action = effective_action(&config.account_age, &config.captcha, &config.quarantine);
+    let age = format_age(config.account_age.min_age, lang);
+    let allowed_at = relative_timestamp(allowed_at);
+
+    let notice = match action {
+        AccountAgeAction::Kick => lang.account_age_dm_kick(age, allowed_at),
+        AccountAgeAction::Ban => lang.account_age_dm_ban(age, allowed_at),
+        AccountAgeAction::Captcha => lang.account_age_dm_captcha(age),
+        AccountAgeAction::Quarantine => lang.account_age_dm_quarantine(age),
+    };
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.account_age_dm_title(guild_name(config.id, state).await?))
+        .description(notice)
+        .build();
+
+    notify(user_id, embed, state).await;
+
+    match action {
+        AccountAgeAction::Kick => {
+            remove(
+                config.id,
+                user_id,
+                RaidAction::Kick,
+                lang.account_age_reason(),
+                state,
+            )
+            .await?
+        }
+        AccountAgeAction::Ban => {
+            remove(
+                config.id,
+                user_id,
+                RaidAction::Ban,
+                lang.account_age_reason(),
+                state,
+            )
+            .await?
+        }
+        AccountAgeAction::Captcha => {}
+        AccountAgeAction::Quarantine => {
+            quarantine(
+                config.id,
+                user_id,
+                &member.roles,
+                None,
+                lang.account_age_reason(),
+                config,
+                state,
+            )
+            .await?;
+        }
+    }
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.account_age_log_title())
+        .description(lang.account_age_log_description(
+            relative_timestamp(created_at),
+            user_id,
+            user_id.mention(),
+        ))
+        .field(EmbedFieldBuilder::new(
+            lang.account_age_log_action(),
+            account_age_action_name(action, lang),
+        ))
+        .build();
+
+    send_log(state, config, embed).await?;
+
+    match action {
+        AccountAgeAction::Captcha => Ok(JoinOutcome::RequireCaptcha),
+        _ => Ok(JoinOutcome::Removed),
+    }
+}
+
+/// Get the action performed on accounts younger than the minimum age.
+///
+/// Actions that cannot be performed with the current configuration fall back
+/// to [`AccountAgeAction::Kick`].
+fn effective_action(
+    config: &AccountAgeConfig,
+    captcha: &CaptchaConfig,
+    quarantine: &QuarantineConfig,
+) -> AccountAgeAction {
+    match config.action {
+        AccountAgeAction::Captcha if !captcha.enabled || captcha.role.is_none() => {
+            AccountAgeAction::Kick
+        }
+        AccountAgeAction::Quarantine if quarantine.role.is_none() => AccountAgeAction::Kick,
+        action => action,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::Id;
+
+    use super::*;
+
+    fn config(action: AccountAgeAction) -> AccountAgeConfig {
+        AccountAgeConfig {
+            enabled: true,
+            action,
+            ..Default::default()
+        }
+    }
+
+    fn captcha(enabled: bool) -> CaptchaConfig {
+        CaptchaConfig {
+            enabled,
+            role: enabled.then(|| Id::new(2)),
+            ..Default::default()
+        }
+    }
+
+    fn quarantine(role: bool) -> QuarantineConfig {
+        QuarantineConfig {
+            role: role.then(|| Id::new(1)),
+        }
+    }
+
+    #[test]
+    fn test_effective_action() {
+        let kick = config(AccountAgeAction::Kick);
+        let ban = config(AccountAgeAction::Ban);
+        let captcha_action = config(AccountAgeAction::Captcha);
+        let quarantine_action = config(AccountAgeAction::Quarantine);
+
+        assert_eq!(
+            effective_action(&kick, &captcha(false), &quarantine(false)),
+            AccountAgeAction::Kick
+        );
+        assert_eq!(
+            effective_action(&ban, &captcha(false), &quarantine(false)),
+            AccountAgeAction::Ban
+        );
+        assert_eq!(
+            effective_action(&captcha_action, &captcha(true), &quarantine(false)),
+            AccountAgeAction::Captcha
+        );
+        assert_eq!(
+            effective_action(&captcha_action, &captcha(false), &quarantine(false)),
+            AccountAgeAction::Kick
+        );
+        assert_eq!(
+            effective_action(&quarantine_action, &captcha(false), &quarantine(true)),
+            AccountAgeAction::Quarantine
+        );
+        assert_eq!(
+            effective_action(&quarantine_action, &captcha(false), &quarantine(false)),
+            AccountAgeAction::Kick
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Handle `MemberAdd` event.
+
+use std::{sync::Arc, time::Duration as StdDuration};
+
+use raidprotect_model::{
+    cache::model::raid::RaidMode,
+    database::model::{GuildConfig, RaidAction},
+};
+use time::{Duration, OffsetDateTime};
+use tracing::{error, info, warn};
+use twilight_model::{
+    guild::Member,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use super::{account_age, guild_name, members_list, notify, remove, risk, JoinOutcome};
+use crate::{
+    cluster::ClusterState, interaction::embed::COLOR_RED, translations::Lang,
+    util::send_log_with_attachments,
+};
+
+/// Handle `MemberAdd` event.
+///
+/// Returns the [`JoinOutcome`] used to process the member with the other
+/// protections, such as the captcha.
+pub async fn member_add(member: &Member, state: &Arc<ClusterState>) -> JoinOutcome {
+    match member_add_inner(member, state).await {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            error!(error = ?error, member = ?member, "error while processing raid protection for `MemberAdd` event");
+
+            JoinOutcome::Continue
+        }
+    }
+}
+
+/// Delay before members removed by raid mode are logged.
+///
+/// Removals are logged by batches to avoid flooding the logs channel during a
+/// raid.
+const RAID_MODE_LOG_DELAY: StdDuration = StdDuration::from_secs(10);
+
+async fn member_add_inner(
+    member: &Member,
+    state: &Arc<ClusterState>,
+) -> Result<JoinOutcome, anyhow::Error> {
+    let now = OffsetDateTime::now_utc();
+    let joined_at = OffsetDateTime::from_unix_timestamp(member.joined_at.as_secs())?;
+    let config = state.mongodb().get_guild_or_create(member.guild_id).await?;
+
+    // Record the join for the mass ban and mass kick commands. This is done
+    // before the recency check so that joins processed late (such as during
+    // a raid) are still recorded.
+    state
+        .redis()
+        .push_join_history(
+            member.guild_id,
+            member.user.id,
+            joined_at,
+            config.raid.history,
+        )
+        .await?;
+
+    if member.user.bot {
+        return Ok(JoinOutcome::Continue);
+    }
+
+    // Raid mode is checked before the recency check, as joins may be
+    // processed late during a raid.
+    if state
+        .redis()
+        .get::<RaidMode>(&member.guild_id)
+        .await?
+        .is_some()
+    {
+        raid_mode(member, &config, state).await?;
+
+        return Ok(JoinOutcome::Removed);
+    }
+
+    // Ensure the member has joined recently to ignore members sent on bot
+    // startup.
+    if (now - joined_at) > Duration::seconds(5) {
+        return Ok(JoinOutcome::Continue);
+    }
+
+    let join_wave = config.raid.detection && detect_wave(member, &config, now, state).await?;
+    let risk = risk::score(member, &config, now, join_wave, state).await?;
+
+    let outcome = if config.account_age.enabled {
+        account_age::account_age(member, &config, now, state).await?
+    } else {
+        JoinOutcome::Continue
+    };
+
+    if outcome == JoinOutcome::Removed || risk::quarantine(member, &config, &risk, state).await? {
+        return Ok(JoinOutcome::Removed);
+    }
+
+    Ok(outcome)
+}
+
+/// Count the join to detect join waves.
+///
+/// Raid mode is enabled if the number of recent joins reaches the configured
+/// threshold. Returns `true` if the member joined during a join wave.
+async fn detect_wave(
+    member: &Member,
+    config: &GuildConfig,
+    now: OffsetDateTime,
+    state: &ClusterState,
+) -> Result<bool, anyhow::Error> {
+    let joins = state
+        .redis()
+        .push_join(member.guild_id, member.user.id, now, config.raid.window)
+        .await?;
+
+    if joins.len() < config.raid.joins as usize {
+        return Ok(false);
+    }
+
+    let raid_mode = RaidMode {
+        guild_id: member.guild_id,
+        enabled_at: now,
+        expires_at: Some(now + Duration::seconds(config.raid.duration.into())),
+        enabled_by: None,
+    };
+
+    // Raid mode may already be enabled, manually or by another join.
+    if !state.redis().enable_raid_mode(&raid_mode).await? {
+        return Ok(true);
+    }
+
+    info!(guild = ?member.guild_id, joins = joins.len(), "raid mode enabled after a join wave");
+
+    alert(config, &joins, state).await?;
+
+    Ok(true)
+}
+
+/// Alert moderators that raid mode has been enabled after a join wave.
+async fn alert(
+    config: &GuildConfig,
+    joins: &[Id<UserMarker>],
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let (members, file) = members_list(joins, format!("raid-{}.txt", config.id), lang);
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.raid_detected_title())
+        .description(lang.raid_detected_description(
+            joins.len(),
+            (config.raid.duration / 60).max(1),
+            config.raid.window,
+        ))
+        .field(EmbedFieldBuilder::new(
+            lang.raid_detected_members(),
+            members,
+        ))
+        .build();
+    let attachments = file.into_iter().collect::<Vec<_>>();
+
+    send_log_with_attachments(state, config, embed, &attachments).await
+}
+
+/// Remove a member that joined while raid mode is enabled.
+///
+/// The member is notified in direct message before being removed. Removed
+/// members are logged by batches in the logs channel.
+async fn raid_mode(
+    member: &Member,
+    config: &GuildConfig,
+    state: &Arc<ClusterState>,
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let user_id = member.user.id;
+    let action = config.raid.action;
+
+    let notice = match action {
+        RaidAction::Kick => lang.raidmode_dm_kick(),
+        RaidAction::Ban => lang.raidmode_dm_ban(),
+    };
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.raidmode_dm_title(guild_name(config.id, state).await?))
+        .description(notice)
+        .build();
+
+    notify(user_id, embed, state).await;
+    remove(config.id, user_id, action, lang.raidmode_reason(), state).await?;
+
+    // The first removal of a batch schedules the log of the whole batch. The
+    // log is scheduled again if it has been lost.
+    if state.redis().push_raid_removal(config.id, user_id).await? {
+        let state = state.clone();
+        let guild_id = config.id;
+
+        tokio::spawn(async move {
+            tokio::time::sleep(RAID_MODE_LOG_DELAY).await;
+
+            if let Err(error) = log_removals(guild_id, &state).await {
+                warn!(error = ?error, guild = ?guild_id, "failed to log raid mode removals");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Log the batch of members removed by raid mode.
+async fn log_removals(
+    guild_id: Id<GuildMarker>,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let removed = state.redis().take_raid_removals(guild_id).await?;
+
+    if removed.is_empty() {
+        return Ok(());
+    }
+
+    let config = state.mongodb().get_guild_or_create(guild_id).await?;
+    let lang = Lang::from(&*config.lang);
+
+    let description = match config.raid.action {
+        RaidAction::Kick => lang.raidmode_log_kick(removed.len()),
+        RaidAction::Ban => lang.raidmode_log_ban(removed.len()),
+    };
+    let (members, file) = members_list(&removed, format!("raidmode-{guild_id}.txt"), lang);
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.raidmode_log_title())
+        .description(description)
+        .field(EmbedFieldBuilder::new(lang.raidmode_log_members(), members))
+        .build();
+
+    let attachments = file.into_iter().collect::<Vec<_>>();
+
+    send_log_with_attachments(state, &config, embed, &attachments).await
+}
@@ -0,0 +1,200 @@
+//! Raid protection event processing.
+//!
+//! This module export functions used to process events related to the raid
+//! protection. The following events are handled:
+//!
+//! - `MemberAdd`: members joining while raid mode is enabled are removed and
+//!   logged by batches. Otherwise, joins are counted to detect join waves, and
+//!   raid mode is enabled automatically when a join wave is detected. The risk score of the
+//!   member is computed, and accounts younger than the minimum age configured
+//!   for the guild are handled by the account age gate.
+
+mod account_age;
+mod member_add;
+mod risk;
+
+pub use member_add::member_add;
+use raidprotect_model::{cache::model::CachedGuild, database::model::RaidAction};
+use tracing::debug;
+use twilight_http::request::AuditLogReason;
+use twilight_mention::Mention;
+use twilight_model::{
+    channel::embed::Embed,
+    http::attachment::Attachment as FileAttachment,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{cluster::ClusterState, translations::Lang, util::send_direct_message};
+
+/// Outcome of the raid protection for a new member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinOutcome {
+    /// The member has been removed from the guild or quarantined, other
+    /// protections are skipped.
+    Removed,
+    /// The member must complete the captcha, regardless of its risk score.
+    RequireCaptcha,
+    /// The member is processed by other protections.
+    Continue,
+}
+
+/// Maximum length of an embed field value.
+const FIELD_MAX_LENGTH: usize = 1024;
+
+/// Length kept in a members field for the line counting missing members.
+const FIELD_MORE_LENGTH: usize = 100;
+
+/// Format a list of members for a log entry.
+///
+/// Returns the value of the embed field listing the members that fit in it.
+/// If some members do not fit in the field, the full list is returned as a
+/// text file to attach to the log entry.
+fn members_list(
+    members: &[Id<UserMarker>],
+    filename: String,
+    lang: Lang,
+) -> (String, Option<FileAttachment>) {
+    let lines = members
+        .iter()
+        .map(|user_id| format!("{} (`{user_id}`)", user_id.mention()))
+        .collect::<Vec<_>>();
+
+    let (mut field, missing) = fit_lines(&lines, FIELD_MAX_LENGTH, FIELD_MORE_LENGTH);
+
+    if missing == 0 {
+        return (field, None);
+    }
+
+    field.push('\n');
+    field.push_str(&lang.raid_members_more(missing));
+
+    let list = members
+        .iter()
+        .map(|user_id| user_id.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let file = FileAttachment::from_bytes(filename, list.into_bytes(), 1);
+
+    (field, Some(file))
+}
+
+/// Join the lines that fit in a text of a maximum length.
+///
+/// If all the lines do not fit, `reserved` characters are kept free to add a
+/// line counting the missing lines. Returns the joined lines and the number
+/// of missing lines.
+fn fit_lines(lines: &[String], max: usize, reserved: usize) -> (String, usize) {
+    let joined = lines.join("\n");
+
+    if joined.chars().count() <= max {
+        return (joined, 0);
+    }
+
+    let mut text = String::new();
+    let mut length = 0;
+    let mut count = 0;
+
+    for line in lines {
+        let line_length = line.chars().count() + usize::from(count > 0);
+
+        if length + line_length > max - reserved {
+            break;
+        }
+
+        if count > 0 {
+            text.push('\n');
+        }
+
+        text.push_str(line);
+        length += line_length;
+        count += 1;
+    }
+
+    (text, lines.len() - count)
+}
+
+/// Get the name of a guild from the cache.
+async fn guild_name(
+    guild_id: Id<GuildMarker>,
+    state: &ClusterState,
+) -> Result<String, anyhow::Error> {
+    let guild = state.redis().get::<CachedGuild>(&guild_id).await?;
+
+    Ok(guild.map(|guild| guild.name).unwrap_or_default())
+}
+
+/// Notify a member in direct message.
+///
+/// The message must be sent before the member is removed, as users can only
+/// receive direct messages from members of a common guild. Errors are ignored
+/// since members can disable direct messages.
+async fn notify(user_id: Id<UserMarker>, embed: Embed, state: &ClusterState) {
+    if let Err(error) = send_direct_message(state, user_id, embed).await {
+        debug!(error = ?error, user = ?user_id, "failed to notify member in direct message");
+    }
+}
+
+/// Remove a member from the guild.
+async fn remove(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    action: RaidAction,
+    reason: &str,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let http = state.cache_http(guild_id);
+
+    match action {
+        RaidAction::Kick => {
+            http.remove_guild_member(user_id)
+                .await?
+                .reason(reason)?
+                .exec()
+                .await?;
+        }
+        RaidAction::Ban => {
+            http.create_ban(user_id)
+                .await?
+                .reason(reason)?
+                .exec()
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_lines() {
+        let lines = vec!["aaaa".to_string(), "bbbb".to_string(), "cccc".to_string()];
+
+        assert_eq!(
+            fit_lines(&lines, 14, 4),
+            ("aaaa\nbbbb\ncccc".to_string(), 0)
+        );
+        assert_eq!(fit_lines(&lines, 13, 3), ("aaaa\nbbbb".to_string(), 1));
+        assert_eq!(fit_lines(&lines, 13, 5), ("aaaa".to_string(), 2));
+        assert_eq!(fit_lines(&lines, 5, 2), (String::new(), 3));
+    }
+
+    #[test]
+    fn test_fit_lines_no_partial_line() {
+        let lines = (0..100)
+            .map(|id| format!("<@{id:0>18}> (`{id:0>18}`)"))
+            .collect::<Vec<_>>();
+
+        let (text, missing) = fit_lines(&lines, FIELD_MAX_LENGTH, FIELD_MORE_LENGTH);
+
+        assert!(missing > 0);
+        assert!(text.chars().count() <= FIELD_MAX_LENGTH - FIELD_MORE_LENGTH);
+        assert!(text.lines().all(|line| lines.contains(&line.to_string())));
+        assert_eq!(text.lines().count() + missing, lines.len());
+    }
+}
@@ -0,0 +1,147 @@
+//! Risk scoring of new members.
+//!
+//! The risk score of each new member is stored in the cache to be used by
+//! other features, and logged in the join logs channel if configured. Members
+//! with a risk score above the configured threshold are quarantined.
+
+use raidprotect_model::{cache::model::risk::MemberRisk, database::model::GuildConfig};
+use time::OffsetDateTime;
+use twilight_mention::{
+    timestamp::{Timestamp, TimestampStyle},
+    Mention,
+};
+use twilight_model::guild::Member;
+use twilight_util::{
+    builder::embed::{EmbedBuilder, EmbedFieldBuilder},
+    snowflake::Snowflake,
+};
+
+use crate::{
+    cluster::ClusterState,
+    feature::{
+        quarantine,
+        risk::{format_signals, member_risk},
+    },
+    interaction::embed::{COLOR_RED, COLOR_TRANSPARENT},
+    translations::Lang,
+    util::send_log,
+};
+
+/// Risk score from which join logs are highlighted.
+const HIGHLIGHT_SCORE: u8 = 50;
+
+/// Compute and store the risk score of a new member.
+///
+/// The join is logged in the join logs channel if configured.
+pub async fn score(
+    member: &Member,
+    config: &GuildConfig,
+    now: OffsetDateTime,
+    join_wave: bool,
+    state: &ClusterState,
+) -> Result<MemberRisk, anyhow::Error> {
+    let risk = member_risk(member.guild_id, &member.user, now, join_wave);
+    state.redis().set(&risk).await?;
+
+    if let Some(logs) = config.risk.logs {
+        let lang = Lang::from(&*config.lang);
+        let user_id = member.user.id;
+        let created_at = (user_id.timestamp() / 1000) as u64;
+        let color = if risk.score >= HIGHLIGHT_SCORE {
+            COLOR_RED
+        } else {
+            COLOR_TRANSPARENT
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(color)
+            .title(lang.risk_log_title())
+            .description(lang.risk_log_description(user_id, user_id.mention()))
+            .field(
+                EmbedFieldBuilder::new(
+                    lang.profile_created_at(),
+                    Timestamp::new(created_at, Some(TimestampStyle::RelativeTime))
+                        .mention()
+                        .to_string(),
+                )
+                .inline(),
+            )
+            .field(
+                EmbedFieldBuilder::new(
+                    lang.risk_score(),
+                    lang.risk_score_value(MemberRisk::MAX_SCORE, risk.score),
+                )
+                .inline(),
+            )
+            .field(EmbedFieldBuilder::new(
+                lang.risk_signals(),
+                format_signals(&risk, lang),
+            ))
+            .build();
+
+        state
+            .cache_http(config.id)
+            .create_message(logs)
+            .await?
+            .embeds(&[embed])?
+            .exec()
+            .await?;
+    }
+
+    Ok(risk)
+}
+
+/// Quarantine a member if its risk score reaches the configured threshold.
+///
+/// Returns `true` if the member has been quarantined.
+pub async fn quarantine(
+    member: &Member,
+    config: &GuildConfig,
+    risk: &MemberRisk,
+    state: &ClusterState,
+) -> Result<bool, anyhow::Error> {
+    let threshold = match (config.risk.quarantine, config.quarantine.role) {
+        (Some(threshold), Some(_)) => threshold,
+        _ => return Ok(false),
+    };
+
+    if risk.score < threshold {
+        return Ok(false);
+    }
+
+    let lang = Lang::from(&*config.lang);
+    let user_id = member.user.id;
+
+    if !quarantine::quarantine(
+        config.id,
+        user_id,
+        &member.roles,
+        None,
+        lang.risk_quarantine_reason(),
+        config,
+        state,
+    )
+    .await?
+    {
+        return Ok(false);
+    }
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.risk_quarantine_title())
+        .description(lang.risk_quarantine_description(
+            user_id,
+            MemberRisk::MAX_SCORE,
+            risk.score,
+            user_id.mention(),
+        ))
+        .field(EmbedFieldBuilder::new(
+            lang.risk_signals(),
+            format_signals(risk, lang),
+        ))
+        .build();
+
+    send_log(state, config, embed).await?;
+
+    Ok(true)
+}
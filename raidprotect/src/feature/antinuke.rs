@@ -0,0 +1,153 @@
+//! Anti-nuke protection.
+//!
+//! Destructive actions are counted for each guild. When the configured
+//! threshold is reached, the audit log is fetched to find the member
+//! responsible for the actions, who is then handled with the configured
+//! action.
+
+use std::collections::HashMap;
+
+use raidprotect_model::{
+    cache::{
+        model::{antinuke::DestructiveAction, CachedRole},
+        permission::RoleOrdering,
+    },
+    database::model::AntinukeAction,
+};
+use time::{Duration, OffsetDateTime};
+use twilight_model::{
+    guild::audit_log::{AuditLogEntry, AuditLogEventType},
+    id::{
+        marker::{RoleMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::snowflake::Snowflake;
+
+use crate::translations::Lang;
+
+/// Audit log event type corresponding to a destructive action.
+pub fn audit_log_type(action: DestructiveAction) -> AuditLogEventType {
+    match action {
+        DestructiveAction::ChannelDelete => AuditLogEventType::ChannelDelete,
+        DestructiveAction::RoleDelete => AuditLogEventType::RoleDelete,
+        DestructiveAction::Ban => AuditLogEventType::MemberBanAdd,
+        DestructiveAction::Kick => AuditLogEventType::MemberKick,
+        DestructiveAction::WebhookCreate => AuditLogEventType::WebhookCreate,
+    }
+}
+
+/// Find the member responsible for destructive actions in the audit log.
+///
+/// Only entries created after `since` are counted. The member with the most
+/// entries is returned if its number of entries reaches the `threshold`.
+pub fn find_actor(
+    entries: &[AuditLogEntry],
+    since: OffsetDateTime,
+    threshold: u32,
+) -> Option<(Id<UserMarker>, u32)> {
+    let since = (since.unix_timestamp_nanos() / 1_000_000) as i64;
+    let mut counts: HashMap<Id<UserMarker>, u32> = HashMap::new();
+
+    for entry in entries {
+        if let Some(user_id) = entry.user_id {
+            if entry.id.timestamp() >= since {
+                *counts.entry(user_id).or_default() += 1;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count >= threshold)
+        .max_by_key(|(_, count)| *count)
+}
+
+/// Get the start of the time window in which actions are counted.
+pub fn window_start(now: OffsetDateTime, window: u32) -> OffsetDateTime {
+    now - Duration::seconds(window.into())
+}
+
+/// Get the roles of a member that cannot be removed by the bot.
+///
+/// Managed roles and roles higher or equal to the bot's highest role are
+/// kept, all other roles are removed.
+pub fn roles_to_keep(roles: &[CachedRole], bot_highest: RoleOrdering) -> Vec<Id<RoleMarker>> {
+    roles
+        .iter()
+        .filter(|role| role.managed || RoleOrdering::from(*role) >= bot_highest)
+        .map(|role| role.id)
+        .collect()
+}
+
+/// Get the localized name of a destructive action.
+pub fn action_kind_name(action: DestructiveAction, lang: Lang) -> &'static str {
+    match action {
+        DestructiveAction::ChannelDelete => lang.antinuke_kind_channel_delete(),
+        DestructiveAction::RoleDelete => lang.antinuke_kind_role_delete(),
+        DestructiveAction::Ban => lang.antinuke_kind_ban(),
+        DestructiveAction::Kick => lang.antinuke_kind_kick(),
+        DestructiveAction::WebhookCreate => lang.antinuke_kind_webhook_create(),
+    }
+}
+
+/// Get the localized name of an anti-nuke action.
+pub fn action_name(action: AntinukeAction, lang: Lang) -> &'static str {
+    match action {
+        AntinukeAction::StripRoles => lang.antinuke_action_strip_roles(),
+        AntinukeAction::Quarantine => lang.antinuke_action_quarantine(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::fixtures::{role, snowflake};
+
+    fn entry(user_id: u64, created_at: OffsetDateTime) -> AuditLogEntry {
+        AuditLogEntry {
+            action_type: AuditLogEventType::ChannelDelete,
+            changes: Vec::new(),
+            id: Id::new(snowflake(created_at)),
+            options: None,
+            reason: None,
+            target_id: None,
+            user_id: Some(Id::new(user_id)),
+        }
+    }
+
+    #[test]
+    fn test_find_actor() {
+        let now = OffsetDateTime::now_utc();
+        let since = window_start(now, 10);
+
+        let entries = vec![
+            entry(1, now - Duration::seconds(1)),
+            entry(1, now - Duration::seconds(2)),
+            entry(1, now - Duration::seconds(3)),
+            entry(2, now - Duration::seconds(1)),
+            entry(1, now - Duration::seconds(60)),
+        ];
+
+        assert_eq!(find_actor(&entries, since, 3), Some((Id::new(1), 3)));
+        assert_eq!(find_actor(&entries, since, 4), None);
+        assert_eq!(find_actor(&[], since, 1), None);
+    }
+
+    #[test]
+    fn test_roles_to_keep() {
+        let bot_highest = RoleOrdering::from(&role(10, 5, true));
+        let roles = vec![
+            role(1, 1, false),
+            role(2, 4, false),
+            role(3, 2, true),
+            role(4, 5, false),
+            role(5, 8, false),
+        ];
+
+        assert_eq!(
+            roles_to_keep(&roles, bot_highest),
+            vec![Id::new(3), Id::new(5)]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Auto-moderation feature.
+
+use raidprotect_model::database::model::AutomodRuleKind;
+use regex::{Regex, RegexBuilder};
+
+use crate::translations::Lang;
+
+/// Maximum size of a compiled regex filter, in bytes.
+///
+/// This prevents patterns with large repetitions from using too much memory
+/// once compiled.
+pub const REGEX_SIZE_LIMIT: usize = 256 * 1024;
+
+/// Get the localized name of an auto-moderation rule.
+pub fn rule_name(kind: AutomodRuleKind, lang: Lang) -> &'static str {
+    match kind {
+        AutomodRuleKind::Invites => lang.automod_rule_invites(),
+        AutomodRuleKind::Phishing => lang.automod_rule_phishing(),
+        AutomodRuleKind::Hidden => lang.automod_rule_hidden(),
+        AutomodRuleKind::Duplicates => lang.automod_rule_duplicates(),
+        AutomodRuleKind::Coordinated => lang.automod_rule_coordinated(),
+    }
+}
+
+/// Get the localized name of a regex filter.
+pub fn regex_filter_name(name: &str, lang: Lang) -> String {
+    lang.automod_rule_regex(name)
+}
+
+/// Compile the pattern of a regex filter.
+///
+/// The `regex` crate guarantees a matching time linear to the size of the
+/// message, and the compiled size of the pattern is limited to
+/// [`REGEX_SIZE_LIMIT`].
+pub fn compile_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .dfa_size_limit(REGEX_SIZE_LIMIT)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_regex() {
+        let regex = compile_regex(r"(?i)free\s+nitro").unwrap();
+
+        assert!(regex.is_match("Get FREE  nitro here"));
+        assert!(!regex.is_match("nitro is not free"));
+    }
+
+    #[test]
+    fn test_compile_regex_size_limit() {
+        assert!(compile_regex(r"\w{1000}").is_err());
+        assert!(compile_regex(r"\w+").is_ok());
+    }
+}
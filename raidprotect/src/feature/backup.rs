@@ -0,0 +1,696 @@
+//! Guild structure backups.
+//!
+//! Snapshots of the roles and channels of a guild are built from the cache and
+//! stored in the database. A snapshot can be compared with the current state
+//! of the guild, and used to restore the guild structure after a nuke: missing
+//! roles and channels are recreated, and permission overwrites are reapplied.
+//!
+//! Snapshots are created with `/backup create`, or automatically at the
+//! interval configured with `/backup schedule`. Scheduled snapshots are skipped
+//! while the guild has lost a large part of the structure of the latest
+//! snapshot, so that snapshots taken after a nuke do not replace the last
+//! snapshot taken before it.
+
+use std::{collections::HashMap, sync::Arc, time::Duration as StdDuration};
+
+use futures::TryStreamExt;
+use raidprotect_model::{
+    cache::model::{CachedChannel, CachedGuild, CachedRole},
+    database::model::{Backup, BackupChannel, BackupOverwrite, BackupRole, GuildConfig},
+};
+use time::{Duration, OffsetDateTime};
+use tracing::{error, info, warn};
+use twilight_http::request::AuditLogReason;
+use twilight_model::{
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        ChannelType,
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{cluster::ClusterState, translations::Lang};
+
+/// Interval between two checks of the scheduled backups (10 minutes).
+const SCHEDULE_CHECK_INTERVAL: StdDuration = StdDuration::from_secs(10 * 60);
+
+/// Percentage of the structure of the latest snapshot from which the guild is
+/// considered nuked, and scheduled snapshots are skipped.
+const MAJOR_LOSS_PERCENT: usize = 25;
+
+/// Build a snapshot of the structure of a guild.
+///
+/// Threads are not included in the snapshot. The version of the snapshot is
+/// set when it is inserted in the database.
+pub fn snapshot(
+    guild_id: Id<GuildMarker>,
+    roles: &[CachedRole],
+    channels: &[CachedChannel],
+    created_by: Option<Id<UserMarker>>,
+    created_at: OffsetDateTime,
+) -> Backup {
+    let roles = roles
+        .iter()
+        .map(|role| BackupRole {
+            id: role.id,
+            name: role.name.clone(),
+            color: role.color,
+            position: role.position,
+            permissions: role.permissions,
+            managed: role.managed,
+        })
+        .collect();
+
+    let channels = channels
+        .iter()
+        .filter(|channel| !channel.is_thread())
+        .map(|channel| BackupChannel {
+            id: channel.id,
+            kind: channel.kind,
+            name: channel.name.clone(),
+            parent_id: channel.parent_id,
+            position: channel.position,
+            rate_limit_per_user: channel.rate_limit_per_user,
+            permission_overwrites: channel
+                .permission_overwrites
+                .iter()
+                .flatten()
+                .map(|overwrite| BackupOverwrite {
+                    id: overwrite.id,
+                    kind: overwrite.kind,
+                    allow: overwrite.allow,
+                    deny: overwrite.deny,
+                })
+                .collect(),
+        })
+        .collect();
+
+    Backup {
+        id: None,
+        guild_id,
+        version: 0,
+        created_at,
+        created_by,
+        roles,
+        channels,
+    }
+}
+
+/// Differences between a snapshot and the current state of a guild.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BackupDiff<'a> {
+    /// Roles of the snapshot missing from the guild.
+    ///
+    /// Managed roles are not included since they cannot be restored.
+    pub missing_roles: Vec<&'a BackupRole>,
+    /// Channels of the snapshot missing from the guild.
+    pub missing_channels: Vec<&'a BackupChannel>,
+    /// Channels whose permission overwrites differ from the snapshot.
+    pub changed_overwrites: Vec<&'a BackupChannel>,
+    /// Roles of the guild created after the snapshot.
+    pub new_roles: Vec<&'a CachedRole>,
+    /// Channels of the guild created after the snapshot.
+    pub new_channels: Vec<&'a CachedChannel>,
+}
+
+impl BackupDiff<'_> {
+    /// Whether the snapshot corresponds to the current state of the guild.
+    pub fn is_empty(&self) -> bool {
+        self.missing_roles.is_empty()
+            && self.missing_channels.is_empty()
+            && self.changed_overwrites.is_empty()
+            && self.new_roles.is_empty()
+            && self.new_channels.is_empty()
+    }
+}
+
+/// Compare a snapshot with the current roles and channels of a guild.
+pub fn diff<'a>(
+    backup: &'a Backup,
+    roles: &'a [CachedRole],
+    channels: &'a [CachedChannel],
+) -> BackupDiff<'a> {
+    let channels = channels
+        .iter()
+        .filter(|channel| !channel.is_thread())
+        .collect::<Vec<_>>();
+
+    let missing_roles = backup
+        .roles
+        .iter()
+        .filter(|role| !role.managed && !roles.iter().any(|current| current.id == role.id))
+        .collect();
+
+    let mut missing_channels = Vec::new();
+    let mut changed_overwrites = Vec::new();
+
+    for channel in &backup.channels {
+        match channels.iter().find(|current| current.id == channel.id) {
+            Some(current) => {
+                let overwrites = current.permission_overwrites.as_deref().unwrap_or_default();
+
+                if !same_overwrites(&channel.permission_overwrites, overwrites) {
+                    changed_overwrites.push(channel);
+                }
+            }
+            None => missing_channels.push(channel),
+        }
+    }
+
+    let new_roles = roles
+        .iter()
+        .filter(|role| !backup.roles.iter().any(|saved| saved.id == role.id))
+        .collect();
+
+    let new_channels = channels
+        .into_iter()
+        .filter(|channel| !backup.channels.iter().any(|saved| saved.id == channel.id))
+        .collect();
+
+    BackupDiff {
+        missing_roles,
+        missing_channels,
+        changed_overwrites,
+        new_roles,
+        new_channels,
+    }
+}
+
+/// Whether a guild has lost a large part of the structure of a snapshot.
+///
+/// Missing roles, missing channels and channels with changed permission
+/// overwrites are counted as lost.
+pub fn is_major_loss(backup: &Backup, diff: &BackupDiff) -> bool {
+    let total = backup.roles.iter().filter(|role| !role.managed).count() + backup.channels.len();
+    let lost =
+        diff.missing_roles.len() + diff.missing_channels.len() + diff.changed_overwrites.len();
+
+    total > 0 && lost * 100 >= total * MAJOR_LOSS_PERCENT
+}
+
+/// Whether saved permission overwrites are identical to the current ones,
+/// regardless of their order.
+fn same_overwrites(saved: &[BackupOverwrite], current: &[PermissionOverwrite]) -> bool {
+    saved.len() == current.len()
+        && saved.iter().all(|saved| {
+            current.iter().any(|current| {
+                current.id == saved.id
+                    && current.kind == saved.kind
+                    && current.allow == saved.allow
+                    && current.deny == saved.deny
+            })
+        })
+}
+
+/// Sort the channels to restore so that categories are created first.
+///
+/// Channels are created in the order of their position, so that recreated
+/// channels keep their relative ordering.
+pub fn restore_order(channels: &mut [&BackupChannel]) {
+    channels.sort_by_key(|channel| (channel.kind != ChannelType::GuildCategory, channel.position));
+}
+
+/// Map saved permission overwrites to the current roles of the guild.
+///
+/// Role overwrites are updated with the id of the restored role. Overwrites
+/// of roles that no longer exist and have not been restored are removed.
+pub fn map_overwrites(
+    overwrites: &[BackupOverwrite],
+    roles: &HashMap<Id<RoleMarker>, Id<RoleMarker>>,
+) -> Vec<PermissionOverwrite> {
+    overwrites
+        .iter()
+        .filter_map(|overwrite| {
+            let id = match overwrite.kind {
+                PermissionOverwriteType::Role => roles.get(&overwrite.id.cast())?.cast(),
+                _ => overwrite.id,
+            };
+
+            Some(PermissionOverwrite {
+                allow: overwrite.allow,
+                deny: overwrite.deny,
+                id,
+                kind: overwrite.kind,
+            })
+        })
+        .collect()
+}
+
+/// Create a snapshot of a guild and store it in the database.
+///
+/// The created snapshot is returned with its version.
+pub async fn create_backup(
+    guild_id: Id<GuildMarker>,
+    created_by: Option<Id<UserMarker>>,
+    state: &ClusterState,
+) -> Result<Backup, anyhow::Error> {
+    let roles = state.redis().guild_roles(guild_id).await?;
+    let channels = state.redis().guild_channels(guild_id).await?;
+
+    let mut backup = snapshot(
+        guild_id,
+        &roles,
+        &channels,
+        created_by,
+        OffsetDateTime::now_utc(),
+    );
+    let id = state.mongodb().create_backup(&mut backup).await?;
+    backup.id = Some(id);
+
+    Ok(backup)
+}
+
+/// Summary of a restored snapshot.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RestoreSummary {
+    /// Number of restored roles.
+    pub roles: usize,
+    /// Number of restored channels.
+    pub channels: usize,
+    /// Number of channels whose permission overwrites have been reapplied.
+    pub overwrites: usize,
+    /// Number of failed operations.
+    pub failed: usize,
+}
+
+/// Restore the structure of a guild from a snapshot.
+///
+/// Missing roles are recreated first, followed by missing categories and
+/// channels. Permission overwrites of existing channels are then reapplied.
+/// Failed operations are counted in the returned [`RestoreSummary`].
+pub async fn restore_backup(
+    backup: &Backup,
+    lang: Lang,
+    state: &ClusterState,
+) -> Result<RestoreSummary, anyhow::Error> {
+    let guild_id = backup.guild_id;
+    let http = state.cache_http(guild_id);
+    let mut summary = RestoreSummary::default();
+
+    let roles = state.redis().guild_roles(guild_id).await?;
+    let channels = state.redis().guild_channels(guild_id).await?;
+    let diff = diff(backup, &roles, &channels);
+
+    // Restore roles
+    let mut role_ids = roles
+        .iter()
+        .map(|role| (role.id, role.id))
+        .collect::<HashMap<_, _>>();
+    let mut positions = Vec::new();
+
+    for role in &diff.missing_roles {
+        let created = async {
+            let response = http
+                .create_role()
+                .await?
+                .name(&role.name)
+                .color(role.color)
+                .permissions(role.permissions)
+                .reason(lang.backup_restore_reason())?
+                .exec()
+                .await?;
+
+            Ok::<_, anyhow::Error>(response.model().await?)
+        };
+
+        match created.await {
+            Ok(created) => {
+                role_ids.insert(role.id, created.id);
+                positions.push((created.id, role.position.max(1) as u64));
+                summary.roles += 1;
+            }
+            Err(error) => {
+                warn!(error = ?error, role = ?role.id, "failed to restore role");
+                summary.failed += 1;
+            }
+        }
+    }
+
+    if !positions.is_empty() {
+        if let Err(error) = state
+            .http()
+            .update_role_positions(guild_id, &positions)
+            .exec()
+            .await
+        {
+            warn!(error = ?error, guild = ?guild_id, "failed to restore role positions");
+        }
+    }
+
+    // Restore channels
+    let mut channel_ids: HashMap<Id<ChannelMarker>, Id<ChannelMarker>> = channels
+        .iter()
+        .map(|channel| (channel.id, channel.id))
+        .collect();
+    let mut missing_channels = diff.missing_channels.clone();
+    restore_order(&mut missing_channels);
+
+    for channel in missing_channels {
+        let overwrites = map_overwrites(&channel.permission_overwrites, &role_ids);
+        let parent_id = channel
+            .parent_id
+            .and_then(|id| channel_ids.get(&id))
+            .copied();
+
+        let created = async {
+            let mut request = http
+                .create_guild_channel(&channel.name)
+                .await?
+                .kind(channel.kind)
+                .permission_overwrites(&overwrites);
+
+            if let Some(parent_id) = parent_id {
+                request = request.parent_id(parent_id);
+            }
+            if let Some(position) = channel.position {
+                request = request.position(position.max(0) as u64);
+            }
+            if let Some(rate_limit) = channel.rate_limit_per_user {
+                request = request.rate_limit_per_user(rate_limit)?;
+            }
+
+            let response = request.reason(lang.backup_restore_reason())?.exec().await?;
+
+            Ok::<_, anyhow::Error>(response.model().await?)
+        };
+
+        match created.await {
+            Ok(created) => {
+                channel_ids.insert(channel.id, created.id);
+                summary.channels += 1;
+            }
+            Err(error) => {
+                warn!(error = ?error, channel = ?channel.id, "failed to restore channel");
+                summary.failed += 1;
+            }
+        }
+    }
+
+    // Reapply permission overwrites
+    for channel in &diff.changed_overwrites {
+        let overwrites = map_overwrites(&channel.permission_overwrites, &role_ids);
+        let updated = async {
+            http.update_channel(channel.id)
+                .await?
+                .permission_overwrites(&overwrites)
+                .reason(lang.backup_restore_reason())?
+                .exec()
+                .await?;
+
+            Ok::<_, anyhow::Error>(())
+        };
+
+        match updated.await {
+            Ok(_) => summary.overwrites += 1,
+            Err(error) => {
+                warn!(error = ?error, channel = ?channel.id, "failed to restore permission overwrites");
+                summary.failed += 1;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Create the scheduled snapshots of guilds.
+///
+/// This function runs indefinitely and checks periodically the guilds with
+/// automatic snapshots enabled.
+pub async fn schedule_backups(state: Arc<ClusterState>) {
+    let mut interval = tokio::time::interval(SCHEDULE_CHECK_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(error) = run_scheduled_backups(&state).await {
+            error!(error = ?error, "failed to run scheduled backups");
+        }
+    }
+}
+
+async fn run_scheduled_backups(state: &ClusterState) -> Result<(), anyhow::Error> {
+    let mut guilds = state.mongodb().find_scheduled_backups().await?;
+    let now = OffsetDateTime::now_utc();
+
+    while let Some(config) = guilds.try_next().await? {
+        if let Err(error) = scheduled_backup(&config, now, state).await {
+            error!(error = ?error, guild = ?config.id, "failed to create scheduled backup");
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a snapshot of a guild if the configured interval has elapsed.
+async fn scheduled_backup(
+    config: &GuildConfig,
+    now: OffsetDateTime,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let interval = match config.backup.interval {
+        Some(interval) => interval,
+        None => return Ok(()),
+    };
+
+    // Skip guilds unavailable or not handled by this cluster.
+    match state.redis().get::<CachedGuild>(&config.id).await? {
+        Some(guild) if !guild.unavailable => {}
+        _ => return Ok(()),
+    }
+
+    if let Some(latest) = state.mongodb().latest_backup(config.id).await? {
+        if !is_backup_due(latest.created_at, interval, now) {
+            return Ok(());
+        }
+
+        let roles = state.redis().guild_roles(config.id).await?;
+        let channels = state.redis().guild_channels(config.id).await?;
+
+        if is_major_loss(&latest, &diff(&latest, &roles, &channels)) {
+            warn!(guild = ?config.id, version = latest.version, "skipped scheduled backup after a major loss of the guild structure");
+
+            return Ok(());
+        }
+    }
+
+    let backup = create_backup(config.id, None, state).await?;
+    info!(guild = ?config.id, version = backup.version, "created scheduled backup");
+
+    Ok(())
+}
+
+/// Whether a scheduled snapshot is due since the last snapshot.
+pub fn is_backup_due(latest: OffsetDateTime, interval: u32, now: OffsetDateTime) -> bool {
+    now - latest >= Duration::seconds(interval.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::guild::Permissions;
+
+    use super::*;
+
+    fn role(id: u64, managed: bool) -> CachedRole {
+        CachedRole {
+            id: Id::new(id),
+            guild_id: Id::new(1),
+            name: format!("role-{id}"),
+            color: 0,
+            icon: None,
+            unicode_emoji: None,
+            position: id as i64,
+            permissions: Permissions::empty(),
+            managed,
+        }
+    }
+
+    fn channel(
+        id: u64,
+        kind: ChannelType,
+        parent_id: Option<u64>,
+        overwrites: Vec<PermissionOverwrite>,
+    ) -> CachedChannel {
+        CachedChannel {
+            id: Id::new(id),
+            guild_id: Id::new(1),
+            kind,
+            name: format!("channel-{id}"),
+            parent_id: parent_id.map(Id::new),
+            permission_overwrites: Some(overwrites),
+            position: Some(id as i32),
+            rate_limit_per_user: None,
+            thread_member: false,
+        }
+    }
+
+    fn overwrite(id: u64, kind: PermissionOverwriteType) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow: Permissions::SEND_MESSAGES,
+            deny: Permissions::empty(),
+            id: Id::new(id),
+            kind,
+        }
+    }
+
+    fn guild() -> (Vec<CachedRole>, Vec<CachedChannel>) {
+        let roles = vec![role(1, false), role(2, false), role(3, true)];
+        let channels = vec![
+            channel(10, ChannelType::GuildCategory, None, Vec::new()),
+            channel(
+                11,
+                ChannelType::GuildText,
+                Some(10),
+                vec![overwrite(2, PermissionOverwriteType::Role)],
+            ),
+            channel(12, ChannelType::GuildPublicThread, Some(11), Vec::new()),
+        ];
+
+        (roles, channels)
+    }
+
+    #[test]
+    fn test_snapshot_skip_threads() {
+        let (roles, channels) = guild();
+        let backup = snapshot(
+            Id::new(1),
+            &roles,
+            &channels,
+            None,
+            OffsetDateTime::now_utc(),
+        );
+
+        assert_eq!(backup.roles.len(), 3);
+        assert_eq!(
+            backup
+                .channels
+                .iter()
+                .map(|channel| channel.id.get())
+                .collect::<Vec<_>>(),
+            vec![10, 11]
+        );
+        assert_eq!(backup.channels[1].permission_overwrites.len(), 1);
+    }
+
+    #[test]
+    fn test_diff() {
+        let (roles, channels) = guild();
+        let backup = snapshot(
+            Id::new(1),
+            &roles,
+            &channels,
+            None,
+            OffsetDateTime::now_utc(),
+        );
+
+        assert!(diff(&backup, &roles, &channels).is_empty());
+
+        // Nuke: delete a role, the managed role and the category, and clear
+        // the overwrites of the text channel.
+        let roles_after = vec![role(1, false), role(4, false)];
+        let channels_after = vec![channel(11, ChannelType::GuildText, Some(10), Vec::new())];
+        let diff = diff(&backup, &roles_after, &channels_after);
+
+        assert_eq!(diff.missing_roles, vec![&backup.roles[1]]);
+        assert_eq!(diff.missing_channels, vec![&backup.channels[0]]);
+        assert_eq!(diff.changed_overwrites, vec![&backup.channels[1]]);
+        assert_eq!(diff.new_roles, vec![&roles_after[1]]);
+        assert!(diff.new_channels.is_empty());
+    }
+
+    #[test]
+    fn test_is_major_loss() {
+        let (roles, channels) = guild();
+        let backup = snapshot(
+            Id::new(1),
+            &roles,
+            &channels,
+            None,
+            OffsetDateTime::now_utc(),
+        );
+
+        assert!(!is_major_loss(&backup, &diff(&backup, &roles, &channels)));
+
+        // A single new role is not a loss.
+        let roles_after = vec![
+            role(1, false),
+            role(2, false),
+            role(3, true),
+            role(4, false),
+        ];
+        assert!(!is_major_loss(
+            &backup,
+            &diff(&backup, &roles_after, &channels)
+        ));
+
+        // Deleting one of the four roles and channels is a major loss.
+        let roles_after = vec![role(1, false), role(3, true)];
+        assert!(is_major_loss(
+            &backup,
+            &diff(&backup, &roles_after, &channels)
+        ));
+
+        // Nuke: all roles and channels are deleted.
+        assert!(is_major_loss(&backup, &diff(&backup, &[], &[])));
+    }
+
+    #[test]
+    fn test_restore_order() {
+        let (roles, channels) = guild();
+        let backup = snapshot(
+            Id::new(1),
+            &roles,
+            &channels,
+            None,
+            OffsetDateTime::now_utc(),
+        );
+
+        let mut order = vec![&backup.channels[1], &backup.channels[0]];
+        restore_order(&mut order);
+
+        assert_eq!(order, vec![&backup.channels[0], &backup.channels[1]]);
+    }
+
+    #[test]
+    fn test_map_overwrites() {
+        let overwrites = vec![
+            BackupOverwrite {
+                id: Id::new(2),
+                kind: PermissionOverwriteType::Role,
+                allow: Permissions::SEND_MESSAGES,
+                deny: Permissions::empty(),
+            },
+            BackupOverwrite {
+                id: Id::new(3),
+                kind: PermissionOverwriteType::Role,
+                allow: Permissions::SEND_MESSAGES,
+                deny: Permissions::empty(),
+            },
+            BackupOverwrite {
+                id: Id::new(5),
+                kind: PermissionOverwriteType::Member,
+                allow: Permissions::SEND_MESSAGES,
+                deny: Permissions::empty(),
+            },
+        ];
+        let roles = HashMap::from([(Id::new(2), Id::new(20))]);
+
+        assert_eq!(
+            map_overwrites(&overwrites, &roles),
+            vec![
+                overwrite(20, PermissionOverwriteType::Role),
+                overwrite(5, PermissionOverwriteType::Member)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_backup_due() {
+        let now = OffsetDateTime::now_utc();
+
+        assert!(is_backup_due(now - Duration::hours(2), 3600, now));
+        assert!(!is_backup_due(now - Duration::minutes(30), 3600, now));
+    }
+}
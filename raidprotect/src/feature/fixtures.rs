@@ -0,0 +1,50 @@
+//! Fixtures shared by tests.
+
+use raidprotect_model::cache::model::{message::CachedMessage, CachedRole};
+use time::OffsetDateTime;
+use twilight_model::{guild::Permissions, id::Id, util::Timestamp};
+
+/// Role of the guild `1` with a given position.
+pub fn role(id: u64, position: i64, managed: bool) -> CachedRole {
+    CachedRole {
+        id: Id::new(id),
+        guild_id: Id::new(1),
+        name: String::new(),
+        color: 0,
+        icon: None,
+        unicode_emoji: None,
+        position,
+        permissions: Permissions::empty(),
+        managed,
+    }
+}
+
+/// Snowflake of an entity created at the given time.
+pub fn snowflake(created_at: OffsetDateTime) -> u64 {
+    const DISCORD_EPOCH: i128 = 1_420_070_400_000;
+
+    let millis = created_at.unix_timestamp_nanos() / 1_000_000 - DISCORD_EPOCH;
+
+    (millis as u64) << 22 | 1
+}
+
+/// Message `1` sent by the user `2` in the channel `3`.
+///
+/// Only the content is set, other fields can be overridden with the struct
+/// update syntax.
+pub fn message(content: &str) -> CachedMessage {
+    CachedMessage {
+        id: Id::new(1),
+        author_id: Id::new(2),
+        channel_id: Id::new(3),
+        content: content.to_string(),
+        timestamp: Timestamp::from_secs(1_650_000_000).unwrap(),
+        words: Vec::new(),
+        attachments: Vec::new(),
+        links: Vec::new(),
+        hidden: Default::default(),
+        mention_everyone: false,
+        mention_users: Vec::new(),
+        mention_roles: Vec::new(),
+    }
+}
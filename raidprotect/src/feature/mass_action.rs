@@ -0,0 +1,425 @@
+//! Mass actions on join waves.
+//!
+//! The joins of each guild are recorded in a join history kept for the
+//! duration configured with `/config raid join-history`. The `/massban` and
+//! `/masskick` commands target the members that joined within a time range,
+//! optionally filtered by account age and roles.
+//!
+//! Members that the moderator cannot sanction, such as the guild owner or
+//! members with a role higher or equal to the moderator's highest role, are
+//! never targeted. The targeted members are shown to the moderator, who must
+//! confirm the action. Each sanctioned member is recorded with a modlog entry.
+
+use std::collections::HashMap;
+
+use raidprotect_model::{
+    cache::{
+        model::{interaction::PendingMassAction, CachedMember, CachedRole},
+        permission::RoleOrdering,
+    },
+    database::model::{GuildConfig, Modlog, ModlogType, ModlogUser},
+};
+use time::{Duration, OffsetDateTime};
+use tracing::warn;
+use twilight_http::request::AuditLogReason;
+use twilight_model::{
+    id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+use twilight_util::snowflake::Snowflake;
+
+use crate::{cluster::ClusterState, translations::Lang};
+
+/// Filters applied to the members targeted by a mass action.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TargetFilter {
+    /// Only target accounts younger than this age.
+    pub max_account_age: Option<Duration>,
+    /// Only target members without roles.
+    pub no_roles: bool,
+}
+
+/// Role hierarchy used to exclude members the moderator cannot sanction.
+#[derive(Debug, Clone)]
+pub struct TargetHierarchy {
+    /// Owner of the guild.
+    owner_id: Id<UserMarker>,
+    /// Highest role of the moderator, [`None`] if the moderator is the owner.
+    moderator: Option<RoleOrdering>,
+    /// The `@everyone` role of the guild.
+    everyone: Option<RoleOrdering>,
+    /// Roles of the guild.
+    roles: HashMap<Id<RoleMarker>, RoleOrdering>,
+}
+
+impl TargetHierarchy {
+    /// Initialize a new [`TargetHierarchy`] from the roles of the guild.
+    pub fn new(
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+        moderator: Option<RoleOrdering>,
+        roles: &[CachedRole],
+    ) -> Self {
+        let roles = roles
+            .iter()
+            .map(|role| (role.id, RoleOrdering::from(role)))
+            .collect::<HashMap<_, _>>();
+
+        Self {
+            owner_id,
+            moderator,
+            everyone: roles.get(&guild_id.cast()).copied(),
+            roles,
+        }
+    }
+
+    /// Whether a member cannot be sanctioned by the moderator.
+    ///
+    /// The guild owner is always protected, as well as members whose highest
+    /// role is higher or equal to the moderator's highest role.
+    fn is_protected(&self, member: &CachedMember) -> bool {
+        if member.user_id == self.owner_id {
+            return true;
+        }
+
+        let moderator = match self.moderator {
+            Some(moderator) => moderator,
+            None => return false,
+        };
+
+        let highest = member
+            .roles
+            .iter()
+            .filter_map(|role| self.roles.get(role))
+            .max()
+            .copied()
+            .or(self.everyone);
+
+        matches!(highest, Some(highest) if highest >= moderator)
+    }
+}
+
+/// Get the time range of a mass action.
+///
+/// The range starts `from` minutes ago and ends `to` minutes ago (now by
+/// default). Returns [`None`] if the range is empty.
+pub fn join_range(
+    now: OffsetDateTime,
+    from: i64,
+    to: Option<i64>,
+) -> Option<(OffsetDateTime, OffsetDateTime)> {
+    let to = to.unwrap_or(0);
+
+    if to >= from {
+        return None;
+    }
+
+    Some((now - Duration::minutes(from), now - Duration::minutes(to)))
+}
+
+/// Select the members targeted by a mass action.
+///
+/// The `joins` are the members that joined within the time range, and
+/// `members` the members currently in the guild. Members that already left
+/// the guild can only be banned, and bots and members protected by the
+/// `hierarchy` are never targeted. The `exempt` users (such as the moderator)
+/// are ignored.
+pub fn select_targets(
+    joins: &[Id<UserMarker>],
+    members: &HashMap<Id<UserMarker>, CachedMember>,
+    kind: ModlogType,
+    filter: TargetFilter,
+    hierarchy: &TargetHierarchy,
+    exempt: &[Id<UserMarker>],
+    now: OffsetDateTime,
+) -> Vec<Id<UserMarker>> {
+    joins
+        .iter()
+        .copied()
+        .filter(|user_id| !exempt.contains(user_id) && *user_id != hierarchy.owner_id)
+        .filter(|user_id| match members.get(user_id) {
+            Some(member) => {
+                !member.bot
+                    && !hierarchy.is_protected(member)
+                    && (!filter.no_roles || member.roles.is_empty())
+            }
+            None => kind == ModlogType::Ban && !filter.no_roles,
+        })
+        .filter(|user_id| match filter.max_account_age {
+            Some(max_age) => now - account_created_at(*user_id) < max_age,
+            None => true,
+        })
+        .collect()
+}
+
+/// Get the creation date of an account from its id.
+fn account_created_at(user_id: Id<UserMarker>) -> OffsetDateTime {
+    OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(user_id.timestamp())
+}
+
+/// Get the localized name of a mass action.
+pub fn action_name(kind: ModlogType, lang: Lang) -> &'static str {
+    match kind {
+        ModlogType::Ban => lang.mass_action_ban(),
+        _ => lang.mass_action_kick(),
+    }
+}
+
+/// Perform a confirmed mass action.
+///
+/// The members are sanctioned with the given `kind`, and a modlog entry is
+/// created for each sanctioned member. Returns the number of members
+/// successfully sanctioned.
+pub async fn execute(
+    action: &PendingMassAction,
+    kind: ModlogType,
+    moderator: &User,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> usize {
+    let lang = Lang::from(&*config.lang);
+    let reason = match &action.reason {
+        Some(reason) => lang.mass_action_reason_custom(reason),
+        None => lang.mass_action_reason().to_string(),
+    };
+
+    let mut done = 0;
+    for user_id in &action.members {
+        if let Err(error) = sanction(*user_id, action, kind, &reason, state).await {
+            warn!(error = ?error, member = ?user_id, "failed to apply mass action");
+            continue;
+        }
+
+        done += 1;
+
+        if let Err(error) = modlog(*user_id, action, kind, moderator, state).await {
+            warn!(error = ?error, member = ?user_id, "failed to create mass action modlog");
+        }
+    }
+
+    done
+}
+
+/// Kick or ban a member.
+async fn sanction(
+    user_id: Id<UserMarker>,
+    action: &PendingMassAction,
+    kind: ModlogType,
+    reason: &str,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let http = state.cache_http(action.guild_id);
+
+    match kind {
+        ModlogType::Ban => {
+            http.create_ban(user_id)
+                .await?
+                .reason(reason)?
+                .exec()
+                .await?;
+        }
+        _ => {
+            http.remove_guild_member(user_id)
+                .await?
+                .reason(reason)?
+                .exec()
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the modlog entry of a sanctioned member.
+async fn modlog(
+    user_id: Id<UserMarker>,
+    action: &PendingMassAction,
+    kind: ModlogType,
+    moderator: &User,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    // The user is fetched to store its name with the modlog. If it cannot be
+    // fetched, the modlog is created with the user id only.
+    let user = match state.http().user(user_id).exec().await {
+        Ok(response) => ModlogUser::from(&response.model().await?),
+        Err(error) => {
+            warn!(error = ?error, user = ?user_id, "failed to fetch user for modlog");
+
+            ModlogUser {
+                id: user_id,
+                name: user_id.to_string(),
+                discriminator: 0,
+                avatar: None,
+            }
+        }
+    };
+
+    let modlog = Modlog {
+        id: None,
+        kind,
+        guild_id: action.guild_id,
+        user,
+        moderator: ModlogUser::from(moderator),
+        date: OffsetDateTime::now_utc(),
+        reason: action.reason.clone(),
+        notes: None,
+    };
+
+    state.mongodb().create_modlog(&modlog).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::util::Timestamp;
+
+    use super::*;
+    use crate::feature::fixtures::{role, snowflake};
+
+    fn member(user_id: Id<UserMarker>, bot: bool, roles: Vec<u64>) -> CachedMember {
+        CachedMember {
+            user_id,
+            name: "member".to_string(),
+            nick: None,
+            bot,
+            roles: roles.into_iter().map(Id::new).collect(),
+            joined_at: Timestamp::from_secs(1_628_594_197).unwrap(),
+            communication_disabled_until: None,
+            pending: false,
+        }
+    }
+
+    #[test]
+    fn test_join_range() {
+        let now = OffsetDateTime::now_utc();
+
+        assert_eq!(
+            join_range(now, 10, None),
+            Some((now - Duration::minutes(10), now))
+        );
+        assert_eq!(
+            join_range(now, 30, Some(20)),
+            Some((now - Duration::minutes(30), now - Duration::minutes(20)))
+        );
+        assert_eq!(join_range(now, 10, Some(10)), None);
+        assert_eq!(join_range(now, 10, Some(20)), None);
+    }
+
+    #[test]
+    fn test_select_targets() {
+        let now = OffsetDateTime::now_utc();
+        let new_account = Id::new(snowflake(now - Duration::hours(1)));
+        let old_account = Id::new(snowflake(now - Duration::days(365)));
+        let with_roles = Id::new(snowflake(now - Duration::hours(2)));
+        let bot = Id::new(snowflake(now - Duration::hours(3)));
+        let left = Id::new(snowflake(now - Duration::hours(4)));
+        let moderator = Id::new(snowflake(now - Duration::hours(5)));
+
+        let joins = vec![new_account, old_account, with_roles, bot, left, moderator];
+        let members = HashMap::from([
+            (new_account, member(new_account, false, vec![])),
+            (old_account, member(old_account, false, vec![])),
+            (with_roles, member(with_roles, false, vec![1])),
+            (bot, member(bot, true, vec![])),
+            (moderator, member(moderator, false, vec![])),
+        ]);
+        let exempt = [moderator];
+        let hierarchy = TargetHierarchy::new(Id::new(1), Id::new(2), None, &[]);
+
+        let all = TargetFilter::default();
+        assert_eq!(
+            select_targets(
+                &joins,
+                &members,
+                ModlogType::Ban,
+                all,
+                &hierarchy,
+                &exempt,
+                now
+            ),
+            vec![new_account, old_account, with_roles, left]
+        );
+        assert_eq!(
+            select_targets(
+                &joins,
+                &members,
+                ModlogType::Kick,
+                all,
+                &hierarchy,
+                &exempt,
+                now
+            ),
+            vec![new_account, old_account, with_roles]
+        );
+
+        let filter = TargetFilter {
+            max_account_age: Some(Duration::days(1)),
+            no_roles: true,
+        };
+        assert_eq!(
+            select_targets(
+                &joins,
+                &members,
+                ModlogType::Ban,
+                filter,
+                &hierarchy,
+                &exempt,
+                now
+            ),
+            vec![new_account]
+        );
+    }
+
+    #[test]
+    fn test_select_targets_hierarchy() {
+        let now = OffsetDateTime::now_utc();
+        let owner = Id::new(snowflake(now - Duration::hours(1)));
+        let above = Id::new(snowflake(now - Duration::hours(2)));
+        let equal = Id::new(snowflake(now - Duration::hours(3)));
+        let below = Id::new(snowflake(now - Duration::hours(4)));
+        let no_roles = Id::new(snowflake(now - Duration::hours(5)));
+
+        let roles = [
+            role(1, 0, false),
+            role(10, 1, false),
+            role(11, 2, false),
+            role(12, 3, false),
+        ];
+        let joins = vec![owner, above, equal, below, no_roles];
+        let members = HashMap::from([
+            (owner, member(owner, false, vec![])),
+            (above, member(above, false, vec![12])),
+            (equal, member(equal, false, vec![10, 11])),
+            (below, member(below, false, vec![10])),
+            (no_roles, member(no_roles, false, vec![])),
+        ]);
+        let all = TargetFilter::default();
+
+        // Moderator with role 11
+        let moderator = Some(RoleOrdering::from(&roles[2]));
+        let hierarchy = TargetHierarchy::new(Id::new(1), owner, moderator, &roles);
+        assert_eq!(
+            select_targets(&joins, &members, ModlogType::Ban, all, &hierarchy, &[], now),
+            vec![below, no_roles]
+        );
+
+        // Guild owner
+        let hierarchy = TargetHierarchy::new(Id::new(1), owner, None, &roles);
+        assert_eq!(
+            select_targets(
+                &joins,
+                &members,
+                ModlogType::Kick,
+                all,
+                &hierarchy,
+                &[],
+                now
+            ),
+            vec![above, equal, below, no_roles]
+        );
+    }
+}
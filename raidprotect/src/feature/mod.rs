@@ -4,4 +4,17 @@
 //! wide to fit in another module, such as features involving both events and
 //! interactions.
 
+pub mod antinuke;
+pub mod automod;
+pub mod backup;
 pub mod captcha;
+pub mod mass_action;
+pub mod names;
+pub mod persist;
+pub mod phishing;
+pub mod quarantine;
+pub mod raid;
+pub mod risk;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
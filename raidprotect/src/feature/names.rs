@@ -0,0 +1,283 @@
+//! Username and nickname rules.
+//!
+//! Names are normalized before being matched against the rules configured for
+//! the guild: characters are converted to their closest ASCII representation
+//! and lowercased, so rules match regardless of case and of common lookalike
+//! characters.
+//!
+//! Rules are checked when a member joins or updates its profile, and can be
+//! applied retroactively to existing members with `/cleanup names`.
+
+use any_ascii::any_ascii;
+use raidprotect_model::{
+    cache::model::CachedMember,
+    database::model::{GuildConfig, NameAction, NameRule, NameRuleKind},
+};
+use regex::Regex;
+use tracing::warn;
+use twilight_http::request::AuditLogReason;
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{cluster::ClusterState, feature::automod::compile_regex, translations::Lang};
+
+/// Compiled name rules of a guild.
+#[derive(Debug)]
+pub struct NameMatcher {
+    /// Source rules, used to detect configuration changes.
+    rules: Vec<NameRule>,
+    /// Compiled rules, in the same order as the configuration.
+    ///
+    /// Rules that failed to compile are [`None`].
+    regexes: Vec<Option<Regex>>,
+}
+
+impl NameMatcher {
+    /// Compile the name rules of a guild.
+    pub fn new(rules: &[NameRule]) -> Self {
+        let regexes = rules
+            .iter()
+            .map(|rule| match compile_rule(rule) {
+                Ok(regex) => Some(regex),
+                Err(error) => {
+                    warn!(error = ?error, pattern = %rule.pattern, "failed to compile name rule");
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            rules: rules.to_vec(),
+            regexes,
+        }
+    }
+
+    /// Whether the compiled rules correspond to the configured rules.
+    pub fn is_current(&self, rules: &[NameRule]) -> bool {
+        self.rules == rules
+    }
+
+    /// Find the first rule matching the username or nickname of a member.
+    pub fn find(&self, name: &str, nick: Option<&str>) -> Option<&NameRule> {
+        let names = [Some(name), nick]
+            .into_iter()
+            .flatten()
+            .map(normalize)
+            .collect::<Vec<_>>();
+
+        self.rules
+            .iter()
+            .zip(&self.regexes)
+            .find(|(_, regex)| match regex {
+                Some(regex) => names.iter().any(|name| regex.is_match(name)),
+                None => false,
+            })
+            .map(|(rule, _)| rule)
+    }
+}
+
+/// Normalize a name before matching it against the rules.
+pub fn normalize(name: &str) -> String {
+    any_ascii(name).to_lowercase()
+}
+
+/// Compile a name rule to a regex matched against normalized names.
+pub fn compile_rule(rule: &NameRule) -> Result<Regex, regex::Error> {
+    match rule.kind {
+        NameRuleKind::Substring => compile_regex(&regex::escape(&normalize(&rule.pattern))),
+        NameRuleKind::Wildcard => compile_regex(&wildcard_pattern(&normalize(&rule.pattern))),
+        NameRuleKind::Regex => compile_regex(&format!("(?i){}", rule.pattern)),
+    }
+}
+
+/// Convert a wildcard pattern to a regex pattern.
+///
+/// `*` matches any sequence of characters, `?` any character and `#` any
+/// digit. The pattern must match the whole name.
+fn wildcard_pattern(pattern: &str) -> String {
+    let mut regex = String::from("^");
+
+    for c in pattern.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '#' => regex.push_str("[0-9]"),
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+
+    regex.push('$');
+    regex
+}
+
+/// Get the localized name of a name rule kind.
+pub fn rule_kind_name(kind: NameRuleKind, lang: Lang) -> &'static str {
+    match kind {
+        NameRuleKind::Substring => lang.names_kind_substring(),
+        NameRuleKind::Wildcard => lang.names_kind_wildcard(),
+        NameRuleKind::Regex => lang.names_kind_regex(),
+    }
+}
+
+/// Get the localized name of a name rule action.
+pub fn action_name(action: NameAction, lang: Lang) -> &'static str {
+    match action {
+        NameAction::Rename => lang.names_action_rename(),
+        NameAction::Kick => lang.raid_action_kick(),
+        NameAction::Ban => lang.raid_action_ban(),
+    }
+}
+
+/// Whether a member is exempted from the name rules.
+///
+/// Bots and members with the `MANAGE_NICKNAMES` permission are exempted.
+/// Members already renamed by the bot are also exempted when using the
+/// [`NameAction::Rename`] action, since their username may still match.
+pub async fn is_exempt(
+    member: &CachedMember,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<bool, anyhow::Error> {
+    if member.bot {
+        return Ok(true);
+    }
+
+    let placeholder = Lang::from(&*config.lang).names_placeholder();
+    if config.names.action == NameAction::Rename && member.nick.as_deref() == Some(placeholder) {
+        return Ok(true);
+    }
+
+    let permissions = state
+        .redis()
+        .permissions(config.id)
+        .await?
+        .member(member.user_id, &member.roles)
+        .await?;
+
+    Ok(permissions.guild().contains(Permissions::MANAGE_NICKNAMES))
+}
+
+/// Apply the configured action to a member with a matching name.
+pub async fn apply_action(
+    member: &CachedMember,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let http = state.cache_http(config.id);
+
+    match config.names.action {
+        NameAction::Rename => {
+            http.update_guild_member_nick(member.user_id, Some(lang.names_placeholder()))
+                .await?
+                .reason(lang.names_reason())?
+                .exec()
+                .await?;
+        }
+        NameAction::Kick => {
+            http.remove_guild_member(member.user_id)
+                .await?
+                .reason(lang.names_reason())?
+                .exec()
+                .await?;
+        }
+        NameAction::Ban => {
+            http.create_ban(member.user_id)
+                .await?
+                .reason(lang.names_reason())?
+                .exec()
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply the configured action to members selected by `/cleanup names`.
+///
+/// Members that left the guild in the meantime are skipped. Returns the
+/// number of members the action has been applied to.
+pub async fn cleanup(
+    members: &[Id<UserMarker>],
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<usize, anyhow::Error> {
+    let mut done = 0;
+
+    for user_id in members {
+        let member = match state.redis().member(config.id, *user_id).await? {
+            Some(member) => member,
+            None => continue,
+        };
+
+        match apply_action(&member, config, state).await {
+            Ok(()) => done += 1,
+            Err(error) => {
+                warn!(error = ?error, member = ?user_id, "failed to apply name rule action")
+            }
+        }
+    }
+
+    Ok(done)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: NameRuleKind, pattern: &str) -> NameRule {
+        NameRule {
+            kind,
+            pattern: pattern.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_substring() {
+        let matcher = NameMatcher::new(&[rule(NameRuleKind::Substring, "discord.gg")]);
+
+        assert!(matcher.find("join DISCORD.GG/abc", None).is_some());
+        assert!(matcher.find("jean", Some("ｄｉｓｃｏｒｄ.ｇｇ")).is_some());
+        assert!(matcher.find("discordXgg", None).is_none());
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let matcher = NameMatcher::new(&[rule(NameRuleKind::Wildcard, "discord-staff-####")]);
+
+        assert!(matcher.find("Discord-Staff-1234", None).is_some());
+        assert!(matcher.find("jean", Some("discord-staff-0000")).is_some());
+        assert!(matcher.find("discord-staff-12345", None).is_none());
+        assert!(matcher.find("discord-staff-abcd", None).is_none());
+
+        let matcher = NameMatcher::new(&[rule(NameRuleKind::Wildcard, "*mod?")]);
+
+        assert!(matcher.find("the real mods", None).is_some());
+        assert!(matcher.find("mod", None).is_none());
+    }
+
+    #[test]
+    fn test_regex() {
+        let matcher = NameMatcher::new(&[
+            rule(NameRuleKind::Regex, "("),
+            rule(NameRuleKind::Regex, r"^free\s*nitro"),
+        ]);
+
+        assert_eq!(
+            matcher.find("Free Nitro", None),
+            Some(&rule(NameRuleKind::Regex, r"^free\s*nitro"))
+        );
+        assert!(matcher.find("get free nitro", None).is_none());
+    }
+
+    #[test]
+    fn test_is_current() {
+        let rules = vec![rule(NameRuleKind::Substring, "spam")];
+        let matcher = NameMatcher::new(&rules);
+
+        assert!(matcher.is_current(&rules));
+        assert!(!matcher.is_current(&[]));
+    }
+}
@@ -0,0 +1,59 @@
+//! Role persistence.
+//!
+//! The roles of members are saved when they leave a guild, and reapplied when
+//! they join again. Sanction-related roles (quarantine and captcha unverified
+//! roles, and the additional roles configured with `/config persist`) are
+//! always persisted, so members cannot remove a sanction by leaving and
+//! joining the guild again. Guilds can also choose to persist all the roles of
+//! returning members.
+
+use raidprotect_model::database::model::GuildConfig;
+use twilight_model::id::{marker::RoleMarker, Id};
+
+/// Get the roles of a leaving member that should be persisted.
+pub fn persisted_roles(
+    member_roles: &[Id<RoleMarker>],
+    config: &GuildConfig,
+) -> Vec<Id<RoleMarker>> {
+    if config.persist.all {
+        return member_roles.to_vec();
+    }
+
+    member_roles
+        .iter()
+        .filter(|role| {
+            Some(**role) == config.quarantine.role
+                || Some(**role) == config.captcha.role
+                || config.persist.roles.contains(role)
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_persisted_roles() {
+        let mut config = GuildConfig::new(Id::new(1));
+        config.quarantine.role = Some(Id::new(2));
+        config.captcha.role = Some(Id::new(3));
+        config.persist.roles = vec![Id::new(4)];
+
+        let member_roles = [Id::new(2), Id::new(3), Id::new(4), Id::new(5)];
+
+        assert_eq!(
+            persisted_roles(&member_roles, &config),
+            vec![Id::new(2), Id::new(3), Id::new(4)]
+        );
+        assert!(persisted_roles(&[Id::new(5)], &config).is_empty());
+
+        config.persist.all = true;
+
+        assert_eq!(
+            persisted_roles(&member_roles, &config),
+            member_roles.to_vec()
+        );
+    }
+}
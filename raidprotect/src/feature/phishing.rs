@@ -0,0 +1,327 @@
+//! Phishing domains detection.
+//!
+//! Phishing links commonly use domains that look like the domain of a popular
+//! service, such as `dlscord.gift`, `discorcl.com` or `disсord.com` (with a
+//! Cyrillic `с`). The domain of each link is compared against a list of
+//! protected domains, configured bot-wide:
+//!
+//! - each label of the domain is converted into a *skeleton*, where
+//!   confusable characters (homoglyphs) are replaced by a common character,
+//! - the skeleton is compared to the skeleton of each protected domain using
+//!   the Damerau-Levenshtein distance to catch typosquatting.
+//!
+//! A label with the same skeleton as a protected domain is only flagged if it
+//! is spelled differently (e.g. with confusable characters or hyphens), so the
+//! brand name itself on another TLD is not considered as phishing. Domains
+//! embedding a full protected domain (e.g. `discord.com.example.ru`) are
+//! always flagged.
+//!
+//! Protected domains, known legitimate domains ([`ALLOWED_DOMAINS`]) and their
+//! subdomains are never flagged. Common multi-part TLDs such as `co.uk` are
+//! handled as a single TLD.
+
+use any_ascii::any_ascii_char;
+
+/// Legitimate domains of protected services that are never flagged.
+pub const ALLOWED_DOMAINS: [&str; 5] = [
+    "discord.dev",
+    "discord.new",
+    "discord.media",
+    "discord.co",
+    "discord.js.org",
+];
+
+/// Second-level labels used by multi-part TLDs (e.g. `co.uk` or `com.br`).
+const SECOND_LEVEL_LABELS: [&str; 7] = ["ac", "co", "com", "edu", "gov", "net", "org"];
+
+/// Detector of domains imitating protected domains.
+#[derive(Debug, Clone)]
+pub struct PhishingDetector {
+    protected: Vec<ProtectedDomain>,
+}
+
+/// Domain protected against phishing.
+#[derive(Debug, Clone)]
+struct ProtectedDomain {
+    /// Full domain name (e.g. `discord.com`).
+    domain: String,
+    /// Domain name without the TLD (e.g. `discord`).
+    name: String,
+    /// Skeleton of the domain name without the TLD.
+    skeleton: String,
+}
+
+impl PhishingDetector {
+    /// Initialize a new [`PhishingDetector`] from a list of protected domains.
+    pub fn new<T: AsRef<str>>(domains: &[T]) -> Self {
+        let protected = domains
+            .iter()
+            .filter_map(|domain| {
+                let domain = domain.as_ref().trim().to_lowercase();
+                let (labels, _tld) = split_tld(&domain)?;
+                let name = labels.rsplit('.').next()?.to_string();
+                let skeleton = skeleton(&name);
+
+                Some(ProtectedDomain {
+                    domain,
+                    name,
+                    skeleton,
+                })
+            })
+            .collect();
+
+        Self { protected }
+    }
+
+    /// Check whether a domain imitates a protected domain.
+    ///
+    /// The imitated protected domain is returned. The domain may be encoded
+    /// with punycode.
+    pub fn check(&self, domain: &str) -> Option<&str> {
+        let (domain, _) = idna::domain_to_unicode(domain);
+        let domain = domain.to_lowercase();
+
+        let is_allowed = self
+            .protected
+            .iter()
+            .map(|protected| &*protected.domain)
+            .chain(ALLOWED_DOMAINS)
+            .any(|allowed| domain == allowed || domain.ends_with(&format!(".{allowed}")));
+
+        if is_allowed {
+            return None;
+        }
+
+        // Domains such as `discord.com.example.ru`
+        let dotted = format!(".{domain}");
+        if let Some(protected) = self
+            .protected
+            .iter()
+            .find(|protected| dotted.contains(&format!(".{}.", protected.domain)))
+        {
+            return Some(&protected.domain);
+        }
+
+        let (labels, _tld) = split_tld(&domain)?;
+
+        labels.split('.').find_map(|label| {
+            let skeleton = skeleton(label);
+
+            self.protected
+                .iter()
+                .find(|protected| is_lookalike(label, &skeleton, protected))
+                .map(|protected| &*protected.domain)
+        })
+    }
+}
+
+/// Split a domain into its labels and its TLD.
+///
+/// Common multi-part TLDs (e.g. `co.uk`) are returned as a single TLD.
+fn split_tld(domain: &str) -> Option<(&str, &str)> {
+    let (labels, tld) = domain.rsplit_once('.')?;
+
+    if tld.chars().count() == 2 {
+        if let Some((rest, second)) = labels.rsplit_once('.') {
+            if SECOND_LEVEL_LABELS.contains(&second) {
+                return Some((rest, &domain[rest.len() + 1..]));
+            }
+        }
+    }
+
+    Some((labels, tld))
+}
+
+/// Whether a domain label looks like a protected domain.
+///
+/// The allowed distance depends on the length of the protected name, to
+/// avoid false positives with short names. A label with the exact same
+/// skeleton is only a lookalike if it is spelled differently.
+fn is_lookalike(label: &str, skeleton: &str, protected: &ProtectedDomain) -> bool {
+    let max_distance = match protected.skeleton.chars().count() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    };
+
+    match distance(skeleton, &protected.skeleton) {
+        0 => label != protected.name,
+        distance => distance <= max_distance,
+    }
+}
+
+/// Convert a domain label into its skeleton.
+///
+/// Confusable characters are replaced with a common ASCII character, and
+/// hyphens are removed.
+fn skeleton(label: &str) -> String {
+    let mapped: String = label
+        .chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .map(|c| match c {
+            // Cyrillic
+            'а' => 'a',
+            'в' => 'b',
+            'с' => 'c',
+            'ԁ' => 'd',
+            'е' | 'ё' => 'e',
+            'һ' | 'н' => 'h',
+            'і' | 'ӏ' => 'l',
+            'ј' => 'j',
+            'к' => 'k',
+            'м' => 'm',
+            'о' => 'o',
+            'р' => 'p',
+            'ѕ' => 's',
+            'т' => 't',
+            'у' => 'y',
+            'х' => 'x',
+            // Greek
+            'α' => 'a',
+            'ι' => 'l',
+            'κ' => 'k',
+            'ν' => 'v',
+            'ο' => 'o',
+            'ρ' => 'p',
+            'τ' => 't',
+            // Latin lookalikes and digits
+            'i' | 'ı' | '1' | '|' | '!' => 'l',
+            '0' => 'o',
+            '3' => 'e',
+            '4' => 'a',
+            '5' => 's',
+            '7' => 't',
+            c if c.is_ascii() => c.to_ascii_lowercase(),
+            c => any_ascii_char(c).chars().next().unwrap_or(c),
+        })
+        .collect();
+
+    mapped
+        .replace("rn", "m")
+        .replace("cl", "d")
+        .replace("vv", "w")
+}
+
+/// Compute the Damerau-Levenshtein distance between two strings.
+///
+/// This is the optimal string alignment variant, where transposition of
+/// adjacent characters counts as a single edit.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            matrix[i][j] = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    matrix[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use raidprotect_model::config::DEFAULT_PROTECTED_DOMAINS;
+
+    use super::*;
+
+    fn detector() -> PhishingDetector {
+        PhishingDetector::new(&DEFAULT_PROTECTED_DOMAINS)
+    }
+
+    #[test]
+    fn test_protected_domains() {
+        let detector = detector();
+
+        assert_eq!(detector.check("discord.com"), None);
+        assert_eq!(detector.check("cdn.discordapp.com"), None);
+        assert_eq!(detector.check("store.steampowered.com"), None);
+        assert_eq!(detector.check("raidprotect.org"), None);
+        assert_eq!(detector.check("record.com"), None);
+    }
+
+    #[test]
+    fn test_homoglyphs() {
+        let detector = detector();
+
+        assert_eq!(detector.check("dlscord.gift"), Some("discord.com"));
+        assert_eq!(detector.check("discorcl.com"), Some("discord.com"));
+        assert_eq!(detector.check("disc0rd.com"), Some("discord.com"));
+        assert_eq!(
+            detector.check("stearncommunity.com"),
+            Some("steamcommunity.com")
+        );
+        // Cyrillic "о"
+        assert_eq!(detector.check("discоrd.com"), Some("discord.com"));
+        // Punycode-encoded Cyrillic "с"
+        assert_eq!(
+            detector.check(&idna::domain_to_ascii("disсord.com").unwrap()),
+            Some("discord.com")
+        );
+    }
+
+    #[test]
+    fn test_typosquatting() {
+        let detector = detector();
+
+        assert_eq!(detector.check("dicsord.com"), Some("discord.com"));
+        assert_eq!(detector.check("discrd.gift"), Some("discord.com"));
+        assert_eq!(
+            detector.check("steamcomunnity.ru"),
+            Some("steamcommunity.com")
+        );
+        assert_eq!(detector.check("discord.com.evil.tld"), Some("discord.com"));
+        assert_eq!(detector.check("discord.com.ru"), Some("discord.com"));
+        assert_eq!(detector.check("dlscord.co.uk"), Some("discord.com"));
+    }
+
+    #[test]
+    fn test_legitimate_domains() {
+        let detector = detector();
+
+        assert_eq!(detector.check("discord.dev"), None);
+        assert_eq!(detector.check("discord.new"), None);
+        assert_eq!(detector.check("discord.media"), None);
+        assert_eq!(detector.check("discord.co"), None);
+        assert_eq!(detector.check("discord.js.org"), None);
+        assert_eq!(detector.check("support.discord.dev"), None);
+        assert_eq!(detector.check("discord.co.uk"), None);
+        assert_eq!(detector.check("discord.example"), None);
+    }
+
+    #[test]
+    fn test_multi_part_tld() {
+        let detector = PhishingDetector::new(&["example.co.uk"]);
+
+        assert_eq!(detector.check("example.co.uk"), None);
+        assert_eq!(detector.check("examp1e.com"), Some("example.co.uk"));
+        assert_eq!(detector.check("exarnple.co.uk"), Some("example.co.uk"));
+        assert_eq!(split_tld("discord.co.uk"), Some(("discord", "co.uk")));
+        assert_eq!(split_tld("www.discord.com"), Some(("www.discord", "com")));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("discord", "discord"), 0);
+        assert_eq!(distance("dicsord", "discord"), 1);
+        assert_eq!(distance("discrd", "discord"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,233 @@
+//! Quarantine of members.
+//!
+//! Quarantined members have their roles removed and are given the quarantine
+//! role configured with `/config quarantine`, which is hidden from all the
+//! channels of the guild. The removed roles are saved in the database and
+//! restored exactly with `/unquarantine`.
+//!
+//! Members can be quarantined by moderators with `/quarantine`, or
+//! automatically by the account age gate, the risk scoring, the anti-nuke
+//! protection and the auto-moderation rules.
+
+use anyhow::Context;
+use raidprotect_model::{
+    cache::{model::CachedRole, permission::RoleOrdering},
+    database::model::{GuildConfig, Quarantine},
+};
+use time::OffsetDateTime;
+use twilight_http::request::AuditLogReason;
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use crate::{cluster::ClusterState, feature::antinuke::roles_to_keep};
+
+/// Get the roles removed from a member when quarantined.
+///
+/// Roles that cannot be removed by the bot (see [`roles_to_keep`]) are kept
+/// and are not saved.
+pub fn removed_roles(
+    member_roles: &[Id<RoleMarker>],
+    keep: &[Id<RoleMarker>],
+) -> Vec<Id<RoleMarker>> {
+    member_roles
+        .iter()
+        .filter(|role| !keep.contains(role))
+        .copied()
+        .collect()
+}
+
+/// Get the roles of a member released from quarantine.
+///
+/// The current roles of the member are kept, except the quarantine role. The
+/// `saved` roles are the cached roles saved with the quarantine: roles deleted
+/// in the meantime are not part of it, and roles that cannot be assigned by
+/// the bot are ignored.
+pub fn restored_roles(
+    member_roles: &[Id<RoleMarker>],
+    saved: &[CachedRole],
+    quarantine_role: Option<Id<RoleMarker>>,
+    bot_highest: RoleOrdering,
+) -> Vec<Id<RoleMarker>> {
+    let mut roles = member_roles
+        .iter()
+        .filter(|role| Some(**role) != quarantine_role)
+        .copied()
+        .collect::<Vec<_>>();
+
+    for role in saved {
+        if !role.managed && RoleOrdering::from(role) < bot_highest && !roles.contains(&role.id) {
+            roles.push(role.id);
+        }
+    }
+
+    roles
+}
+
+/// Quarantine a member.
+///
+/// The removed roles are saved before updating the member. Returns `false` if
+/// the member is already quarantined.
+///
+/// The quarantine role must be configured in the guild configuration.
+pub async fn quarantine(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    member_roles: &[Id<RoleMarker>],
+    moderator_id: Option<Id<UserMarker>>,
+    reason: &str,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<bool, anyhow::Error> {
+    let role = config
+        .quarantine
+        .role
+        .context("quarantine role not configured")?;
+
+    if member_roles.contains(&role) {
+        return Ok(false);
+    }
+
+    let bot_highest = state
+        .redis()
+        .permissions(guild_id)
+        .await?
+        .current_member()
+        .await?
+        .highest_role();
+
+    let roles = state.redis().get_many::<CachedRole>(member_roles).await?;
+    let mut keep = roles_to_keep(&roles, bot_highest);
+    let removed = removed_roles(member_roles, &keep);
+    keep.push(role);
+
+    let record = Quarantine {
+        guild_id,
+        user_id,
+        roles: removed,
+        moderator_id,
+        reason: Some(reason.to_string()),
+        date: OffsetDateTime::now_utc(),
+    };
+
+    state.mongodb().set_quarantine(&record).await?;
+
+    // The saved roles are deleted if the member cannot be updated, so the
+    // member is not considered as quarantined.
+    let update = async {
+        state
+            .cache_http(guild_id)
+            .update_guild_member_roles(user_id, &keep)
+            .await?
+            .reason(reason)?
+            .exec()
+            .await?;
+
+        Ok::<_, anyhow::Error>(())
+    };
+
+    if let Err(error) = update.await {
+        state.mongodb().delete_quarantine(guild_id, user_id).await?;
+
+        return Err(error);
+    }
+
+    Ok(true)
+}
+
+/// Release a member from quarantine.
+///
+/// The saved roles are restored and the quarantine role is removed. Returns
+/// `false` if the member is not quarantined.
+pub async fn unquarantine(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    member_roles: &[Id<RoleMarker>],
+    reason: &str,
+    config: &GuildConfig,
+    state: &ClusterState,
+) -> Result<bool, anyhow::Error> {
+    let record = state.mongodb().get_quarantine(guild_id, user_id).await?;
+    let has_role = matches!(config.quarantine.role, Some(role) if member_roles.contains(&role));
+
+    if record.is_none() && !has_role {
+        return Ok(false);
+    }
+
+    let bot_highest = state
+        .redis()
+        .permissions(guild_id)
+        .await?
+        .current_member()
+        .await?
+        .highest_role();
+
+    let saved = match &record {
+        Some(record) => state.redis().get_many::<CachedRole>(&record.roles).await?,
+        None => Vec::new(),
+    };
+    let roles = restored_roles(member_roles, &saved, config.quarantine.role, bot_highest);
+
+    state
+        .cache_http(guild_id)
+        .update_guild_member_roles(user_id, &roles)
+        .await?
+        .reason(reason)?
+        .exec()
+        .await?;
+
+    state.mongodb().delete_quarantine(guild_id, user_id).await?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::fixtures::role;
+
+    #[test]
+    fn test_removed_roles() {
+        let member_roles = [Id::new(2), Id::new(3), Id::new(4)];
+
+        assert_eq!(
+            removed_roles(&member_roles, &[Id::new(3)]),
+            vec![Id::new(2), Id::new(4)]
+        );
+        assert!(removed_roles(&member_roles, &member_roles).is_empty());
+    }
+
+    #[test]
+    fn test_restored_roles() {
+        let bot_highest = RoleOrdering::from(&role(10, 5, true));
+        let quarantine_role = Some(Id::new(20));
+        let saved = [role(2, 1, false), role(3, 2, false), role(4, 8, false)];
+
+        // The quarantine role is removed, and roles higher than the bot are
+        // not restored.
+        assert_eq!(
+            restored_roles(
+                &[Id::new(20), Id::new(5)],
+                &saved,
+                quarantine_role,
+                bot_highest
+            ),
+            vec![Id::new(5), Id::new(2), Id::new(3)]
+        );
+
+        // Roles are not duplicated.
+        assert_eq!(
+            restored_roles(
+                &[Id::new(20), Id::new(2)],
+                &saved,
+                quarantine_role,
+                bot_highest
+            ),
+            vec![Id::new(2), Id::new(3)]
+        );
+
+        // Managed roles cannot be assigned.
+        assert!(restored_roles(&[], &[role(6, 1, true)], quarantine_role, bot_highest).is_empty());
+    }
+}
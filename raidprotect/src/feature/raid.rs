@@ -0,0 +1,62 @@
+//! Raid protection feature.
+
+use raidprotect_model::database::model::{AccountAgeAction, RaidAction};
+
+use crate::translations::Lang;
+
+/// Get the localized name of a raid mode action.
+pub fn action_name(action: RaidAction, lang: Lang) -> &'static str {
+    match action {
+        RaidAction::Kick => lang.raid_action_kick(),
+        RaidAction::Ban => lang.raid_action_ban(),
+    }
+}
+
+/// Get the localized description of what happens to members joining while
+/// raid mode is enabled.
+pub fn action_participle(action: RaidAction, lang: Lang) -> &'static str {
+    match action {
+        RaidAction::Kick => lang.raidmode_action_kicked(),
+        RaidAction::Ban => lang.raidmode_action_banned(),
+    }
+}
+
+/// Get the localized name of an account age gate action.
+pub fn account_age_action_name(action: AccountAgeAction, lang: Lang) -> &'static str {
+    match action {
+        AccountAgeAction::Kick => lang.raid_action_kick(),
+        AccountAgeAction::Ban => lang.raid_action_ban(),
+        AccountAgeAction::Captcha => lang.account_age_action_captcha(),
+        AccountAgeAction::Quarantine => lang.account_age_action_quarantine(),
+    }
+}
+
+/// Format the minimum account age.
+///
+/// Durations that are a whole number of days are formatted in days, others
+/// are formatted in hours.
+pub fn format_age(seconds: u32, lang: Lang) -> String {
+    const DAY: u32 = 24 * 60 * 60;
+
+    let (days, remainder) = (seconds / DAY, seconds % DAY);
+
+    if days > 0 && remainder == 0 {
+        lang.account_age_days(days)
+    } else {
+        lang.account_age_hours((seconds / 3600).max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age() {
+        let lang = Lang::En;
+
+        assert_eq!(format_age(7 * 24 * 3600, lang), lang.account_age_days(7));
+        assert_eq!(format_age(36 * 3600, lang), lang.account_age_hours(36));
+        assert_eq!(format_age(60, lang), lang.account_age_hours(1));
+    }
+}
@@ -0,0 +1,240 @@
+//! Risk scoring of new members.
+//!
+//! A risk score is computed for each member joining a guild, by combining the
+//! following signals:
+//!
+//! - the age of the account (less than a day or less than a week),
+//! - whether the account uses the default avatar,
+//! - whether the username looks like a random string or contains a lot of
+//!   digits,
+//! - whether the member joined during a join wave.
+//!
+//! Each signal has a weight, and the score is the sum of the weights of the
+//! detected signals, capped to [`MemberRisk::MAX_SCORE`].
+
+use raidprotect_model::cache::model::risk::{MemberRisk, RiskSignal};
+use time::{Duration, OffsetDateTime};
+use twilight_model::{
+    id::{marker::GuildMarker, Id},
+    user::User,
+};
+use twilight_util::snowflake::Snowflake;
+
+use crate::translations::Lang;
+
+/// Minimum length of a username to be checked as a random string.
+const RANDOM_NAME_MIN_LEN: usize = 6;
+
+/// Compute the risk score of a member.
+pub fn member_risk(
+    guild_id: Id<GuildMarker>,
+    user: &User,
+    now: OffsetDateTime,
+    join_wave: bool,
+) -> MemberRisk {
+    let mut signals = Vec::new();
+
+    let created_at = OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(user.id.timestamp());
+    let age = now - created_at;
+
+    if age < Duration::days(1) {
+        signals.push(RiskSignal::NewAccount);
+    } else if age < Duration::weeks(1) {
+        signals.push(RiskSignal::RecentAccount);
+    }
+
+    if user.avatar.is_none() {
+        signals.push(RiskSignal::DefaultAvatar);
+    }
+
+    if is_random_name(&user.name) {
+        signals.push(RiskSignal::RandomName);
+    }
+
+    if is_numeric_name(&user.name) {
+        signals.push(RiskSignal::NumericName);
+    }
+
+    if join_wave {
+        signals.push(RiskSignal::JoinWave);
+    }
+
+    let score = signals
+        .iter()
+        .map(|signal| u32::from(signal_weight(*signal)))
+        .sum::<u32>()
+        .min(MemberRisk::MAX_SCORE.into()) as u8;
+
+    MemberRisk {
+        guild_id,
+        member_id: user.id,
+        score,
+        signals,
+    }
+}
+
+/// Weight of a risk signal in the risk score.
+fn signal_weight(signal: RiskSignal) -> u8 {
+    match signal {
+        RiskSignal::NewAccount => 40,
+        RiskSignal::RecentAccount => 20,
+        RiskSignal::DefaultAvatar => 15,
+        RiskSignal::RandomName => 20,
+        RiskSignal::NumericName => 10,
+        RiskSignal::JoinWave => 30,
+    }
+}
+
+/// Get the localized name of a risk signal.
+pub fn signal_name(signal: RiskSignal, lang: Lang) -> &'static str {
+    match signal {
+        RiskSignal::NewAccount => lang.risk_signal_new_account(),
+        RiskSignal::RecentAccount => lang.risk_signal_recent_account(),
+        RiskSignal::DefaultAvatar => lang.risk_signal_default_avatar(),
+        RiskSignal::RandomName => lang.risk_signal_random_name(),
+        RiskSignal::NumericName => lang.risk_signal_numeric_name(),
+        RiskSignal::JoinWave => lang.risk_signal_join_wave(),
+    }
+}
+
+/// Format the signals of a risk score.
+pub fn format_signals(risk: &MemberRisk, lang: Lang) -> String {
+    if risk.signals.is_empty() {
+        return lang.risk_signal_none().to_string();
+    }
+
+    risk.signals
+        .iter()
+        .map(|signal| signal_name(*signal, lang))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether a username looks like a random string.
+///
+/// Random strings usually contain long runs of consonants, frequent case
+/// changes or alternate between letters and digits.
+fn is_random_name(name: &str) -> bool {
+    let chars: Vec<char> = name.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+
+    if chars.len() < RANDOM_NAME_MIN_LEN {
+        return false;
+    }
+
+    let mut consonants = 0;
+    let mut max_consonants = 0;
+
+    for c in &chars {
+        if c.is_ascii_alphabetic() && !"aeiouy".contains(c.to_ascii_lowercase()) {
+            consonants += 1;
+            max_consonants = max_consonants.max(consonants);
+        } else {
+            consonants = 0;
+        }
+    }
+
+    let case_changes = chars
+        .windows(2)
+        .filter(|pair| {
+            pair[0].is_ascii_alphabetic()
+                && pair[1].is_ascii_alphabetic()
+                && pair[0].is_ascii_uppercase() != pair[1].is_ascii_uppercase()
+        })
+        .count();
+
+    let digit_changes = chars
+        .windows(2)
+        .filter(|pair| pair[0].is_ascii_digit() != pair[1].is_ascii_digit())
+        .count();
+
+    max_consonants >= 5 || case_changes >= 4 || digit_changes >= 3
+}
+
+/// Whether a username contains a lot of digits.
+fn is_numeric_name(name: &str) -> bool {
+    let len = name.chars().count();
+    let digits = name.chars().filter(char::is_ascii_digit).count();
+    let trailing = name.chars().rev().take_while(char::is_ascii_digit).count();
+
+    len > 0 && (digits * 10 >= len * 4 || trailing >= 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::util::ImageHash;
+
+    use super::*;
+    use crate::feature::fixtures::snowflake;
+
+    fn user(id: u64, name: &str, avatar: bool) -> User {
+        User {
+            accent_color: None,
+            avatar: avatar.then(|| ImageHash::new([1; 16], false)),
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: Id::new(id),
+            locale: None,
+            mfa_enabled: None,
+            name: name.to_string(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_member_risk() {
+        let now = OffsetDateTime::now_utc();
+        let guild_id = Id::new(1);
+
+        let old = user(snowflake(now - Duration::days(365)), "Jean", true);
+        let risk = member_risk(guild_id, &old, now, false);
+        assert_eq!(risk.score, 0);
+        assert!(risk.signals.is_empty());
+
+        let new = user(snowflake(now - Duration::hours(2)), "xkcdqwrt", false);
+        let risk = member_risk(guild_id, &new, now, true);
+        assert_eq!(
+            risk.signals,
+            vec![
+                RiskSignal::NewAccount,
+                RiskSignal::DefaultAvatar,
+                RiskSignal::RandomName,
+                RiskSignal::JoinWave
+            ]
+        );
+        assert_eq!(risk.score, MemberRisk::MAX_SCORE);
+
+        let recent = user(snowflake(now - Duration::days(3)), "jean2004", true);
+        let risk = member_risk(guild_id, &recent, now, false);
+        assert_eq!(
+            risk.signals,
+            vec![RiskSignal::RecentAccount, RiskSignal::NumericName]
+        );
+        assert_eq!(risk.score, 30);
+    }
+
+    #[test]
+    fn test_random_name() {
+        assert!(is_random_name("xkcdqwrt"));
+        assert!(is_random_name("aBcDeFgH"));
+        assert!(is_random_name("a8f3k2p9"));
+        assert!(!is_random_name("baptiste"));
+        assert!(!is_random_name("Strength"));
+        assert!(!is_random_name("john2004"));
+        assert!(!is_random_name("bob"));
+    }
+
+    #[test]
+    fn test_numeric_name() {
+        assert!(is_numeric_name("john2004"));
+        assert!(is_numeric_name("12ab34"));
+        assert!(!is_numeric_name("john42"));
+        assert!(!is_numeric_name("baptiste"));
+        assert!(!is_numeric_name(""));
+    }
+}
@@ -0,0 +1,433 @@
+//! Backup command.
+//!
+//! This command manages the snapshots of the guild structure (roles, channels
+//! and permission overwrites). Snapshots can be created manually or at a
+//! regular interval, compared with the current state of the guild, and
+//! restored after a nuke.
+//!
+//! Restoring requires the version of the backup, since the latest backup may
+//! have been created after a nuke. Restoration is performed in background
+//! after the response is sent, and a summary is sent in the logs channel once
+//! it is complete.
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use futures::TryStreamExt;
+use raidprotect_model::database::model::{Backup, BackupConfig};
+use tracing::error;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::{
+    timestamp::{Timestamp, TimestampStyle},
+    Mention,
+};
+use twilight_model::{
+    application::interaction::Interaction,
+    guild::Permissions,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::backup::{create_backup, diff, restore_backup},
+    interaction::{
+        embed::{self, COLOR_GREEN, COLOR_TRANSPARENT},
+        response::InteractionResponse,
+        util::{parse_command_data, InteractionExt},
+    },
+    translations::Lang,
+    util::{guild_logs_channel, TextProcessExt},
+};
+
+/// Backup command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "backup",
+    desc = "Manage the backups of the server structure",
+    desc_localizations = "backup_description",
+    default_permissions = "BackupCommand::default_permissions",
+    dm_permission = false
+)]
+pub enum BackupCommand {
+    #[command(name = "create")]
+    Create(BackupCreateCommand),
+    #[command(name = "list")]
+    List(BackupListCommand),
+    #[command(name = "diff")]
+    Diff(BackupDiffCommand),
+    #[command(name = "restore")]
+    Restore(BackupRestoreCommand),
+    #[command(name = "schedule")]
+    Schedule(BackupScheduleCommand),
+}
+
+desc_localizations!(backup_description);
+
+impl BackupCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::ADMINISTRATOR
+    }
+
+    /// Handle the command.
+    ///
+    /// Unlike other commands, the shared state is required to perform the
+    /// restoration in background.
+    pub async fn handle(
+        mut interaction: Interaction,
+        state: Arc<ClusterState>,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let parsed = parse_command_data::<Self>(&mut interaction)?;
+
+        match parsed {
+            Self::Create(command) => command.exec(interaction, &state).await,
+            Self::List(command) => command.exec(interaction, &state).await,
+            Self::Diff(command) => command.exec(interaction, &state).await,
+            Self::Restore(command) => command.exec(interaction, state).await,
+            Self::Schedule(command) => command.exec(interaction, &state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "create",
+    desc = "Create a backup of the server structure",
+    desc_localizations = "backup_create_description"
+)]
+pub struct BackupCreateCommand;
+
+desc_localizations!(backup_create_description);
+
+impl BackupCreateCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let author_id = interaction.author_id().context("missing author_id")?;
+
+        let backup = create_backup(guild_id, Some(author_id), state).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.backup_create_title())
+            .description(lang.backup_create_confirm(
+                backup.channels.len(),
+                backup.roles.len(),
+                backup.version,
+            ))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List the backups of the server",
+    desc_localizations = "backup_list_description"
+)]
+pub struct BackupListCommand;
+
+desc_localizations!(backup_list_description);
+
+impl BackupListCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let backups: Vec<Backup> = state
+            .mongodb()
+            .find_backups(guild_id)
+            .await?
+            .try_collect()
+            .await?;
+
+        if backups.is_empty() {
+            return Ok(embed::backup::no_backups(lang));
+        }
+
+        let entries = backups
+            .iter()
+            .map(|backup| backup_entry(backup, lang))
+            .collect::<Vec<_>>();
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.backup_list_title())
+            .description(entries.join("\n"))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "diff",
+    desc = "Compare a backup with the current server structure",
+    desc_localizations = "backup_diff_description"
+)]
+pub struct BackupDiffCommand {
+    /// Version of the backup. The latest backup is used by default.
+    #[command(min_value = 1)]
+    version: Option<i64>,
+}
+
+desc_localizations!(backup_diff_description);
+
+impl BackupDiffCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let backup = match find_backup(guild_id, self.version, state).await? {
+            Some(backup) => backup,
+            None => return Ok(embed::backup::not_found(lang)),
+        };
+
+        let roles = state.redis().guild_roles(guild_id).await?;
+        let channels = state.redis().guild_channels(guild_id).await?;
+        let diff = diff(&backup, &roles, &channels);
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.backup_diff_title(backup.version));
+
+        if diff.is_empty() {
+            let embed = embed.description(lang.backup_diff_empty()).build();
+
+            return Ok(InteractionResponse::EphemeralEmbed(embed));
+        }
+
+        let embed = diff_field(
+            embed,
+            lang.backup_diff_missing_roles(),
+            diff.missing_roles.iter().map(|role| &role.name),
+        );
+        let embed = diff_field(
+            embed,
+            lang.backup_diff_missing_channels(),
+            diff.missing_channels.iter().map(|channel| &channel.name),
+        );
+        let embed = diff_field(
+            embed,
+            lang.backup_diff_changed_overwrites(),
+            diff.changed_overwrites.iter().map(|channel| &channel.name),
+        );
+        let embed = diff_field(
+            embed,
+            lang.backup_diff_new_roles(),
+            diff.new_roles.iter().map(|role| &role.name),
+        );
+        let embed = diff_field(
+            embed,
+            lang.backup_diff_new_channels(),
+            diff.new_channels.iter().map(|channel| &channel.name),
+        );
+
+        Ok(InteractionResponse::EphemeralEmbed(embed.build()))
+    }
+}
+
+/// Add a field listing the names of roles or channels to the diff embed.
+///
+/// The field is omitted if the list is empty.
+fn diff_field<'a>(
+    embed: EmbedBuilder,
+    name: &str,
+    values: impl Iterator<Item = &'a String>,
+) -> EmbedBuilder {
+    let list = values
+        .map(|value| format!("`{}`", value.remove_markdown()))
+        .collect::<Vec<_>>();
+
+    if list.is_empty() {
+        return embed;
+    }
+
+    embed.field(EmbedFieldBuilder::new(name, list.join("\n").truncate(1024)))
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "restore",
+    desc = "Restore the missing roles and channels of a backup",
+    desc_localizations = "backup_restore_description"
+)]
+pub struct BackupRestoreCommand {
+    /// Version of the backup, listed with `/backup list`.
+    #[command(min_value = 1)]
+    version: i64,
+}
+
+desc_localizations!(backup_restore_description);
+
+impl BackupRestoreCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: Arc<ClusterState>,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let author_id = interaction.author_id().context("missing author_id")?;
+
+        let backup = match state
+            .mongodb()
+            .get_backup(guild_id, self.version as u32)
+            .await?
+        {
+            Some(backup) => backup,
+            None => return Ok(embed::backup::not_found(lang)),
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.backup_restore_title())
+            .description(lang.backup_restore_started(backup.version))
+            .build();
+
+        tokio::spawn(async move {
+            if let Err(error) = restore(backup, author_id, state).await {
+                error!(error = ?error, guild = ?guild_id, "failed to restore backup");
+            }
+        });
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Restore a backup and send a summary in the logs channel.
+async fn restore(
+    backup: Backup,
+    author_id: Id<UserMarker>,
+    state: Arc<ClusterState>,
+) -> Result<(), anyhow::Error> {
+    let config = state.mongodb().get_guild_or_create(backup.guild_id).await?;
+    let lang = Lang::from(&*config.lang);
+
+    let summary = restore_backup(&backup, lang, &state).await?;
+
+    let logs_channel = guild_logs_channel(&state, config.id, config.logs_chan, lang).await?;
+    let embed = EmbedBuilder::new()
+        .color(COLOR_GREEN)
+        .title(lang.backup_restore_log_title())
+        .description(lang.backup_restore_log_description(author_id.mention(), backup.version))
+        .field(EmbedFieldBuilder::new(lang.backup_roles(), summary.roles.to_string()).inline())
+        .field(
+            EmbedFieldBuilder::new(lang.backup_channels(), summary.channels.to_string()).inline(),
+        )
+        .field(
+            EmbedFieldBuilder::new(lang.backup_overwrites(), summary.overwrites.to_string())
+                .inline(),
+        )
+        .field(EmbedFieldBuilder::new(lang.backup_failed(), summary.failed.to_string()).inline())
+        .build();
+
+    state
+        .cache_http(config.id)
+        .create_message(logs_channel)
+        .await?
+        .embeds(&[embed])?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "schedule",
+    desc = "Configure automatic backups of the server structure",
+    desc_localizations = "backup_schedule_description"
+)]
+pub struct BackupScheduleCommand {
+    /// Interval between automatic backups, in hours. Leave empty to disable.
+    #[command(min_value = 1, max_value = 720)]
+    interval: Option<i64>,
+}
+
+desc_localizations!(backup_schedule_description);
+
+impl BackupScheduleCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.backup.interval = self.interval.map(|hours| {
+            (hours as u32 * 60 * 60).clamp(BackupConfig::MIN_INTERVAL, BackupConfig::MAX_INTERVAL)
+        });
+
+        state.mongodb().update_guild(&config).await?;
+
+        let description = match config.backup.interval {
+            Some(interval) => lang.backup_schedule_enabled(interval / 60 / 60),
+            None => lang.backup_schedule_disabled().to_string(),
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Find a backup with its version, or the latest backup.
+async fn find_backup(
+    guild_id: Id<GuildMarker>,
+    version: Option<i64>,
+    state: &ClusterState,
+) -> Result<Option<Backup>, anyhow::Error> {
+    match version {
+        Some(version) => state.mongodb().get_backup(guild_id, version as u32).await,
+        None => state.mongodb().latest_backup(guild_id).await,
+    }
+}
+
+/// Format a backup in the backups list.
+fn backup_entry(backup: &Backup, lang: Lang) -> String {
+    let date = Timestamp::new(
+        backup.created_at.unix_timestamp() as u64,
+        Some(TimestampStyle::ShortDateTime),
+    );
+
+    let author = match backup.created_by {
+        Some(user_id) => user_id.mention().to_string(),
+        None => lang.backup_automatic().to_string(),
+    };
+
+    lang.backup_list_entry(
+        author,
+        backup.channels.len(),
+        date.mention(),
+        backup.roles.len(),
+        backup.version,
+    )
+}
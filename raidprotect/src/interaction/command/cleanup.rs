@@ -0,0 +1,186 @@
+//! Cleanup command.
+//!
+//! This command applies protection rules retroactively to the existing members
+//! of the server. The `names` subcommand scans the cached members of the
+//! server and applies the action configured with `/config names` to every
+//! member whose username or nickname matches a rule.
+//!
+//! The members are scanned in background after a deferred response, which is
+//! then updated with the number of matching members and confirmation buttons
+//! (see the [`MassActionConfirm`] component). Once confirmed, a summary is
+//! sent in the logs channel when the cleanup is complete.
+//!
+//! [`MassActionConfirm`]: crate::interaction::component::MassActionConfirm
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use raidprotect_model::{
+    cache::model::interaction::{MassActionKind, PendingMassAction},
+    database::model::GuildConfig,
+};
+use tracing::error;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    application::interaction::Interaction,
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::names::{action_name, is_exempt, NameMatcher},
+    interaction::{
+        component::mass_action_buttons,
+        embed::{self, COLOR_RED, COLOR_TRANSPARENT},
+        response::{InteractionResponder, InteractionResponse},
+        util::{parse_command_data, InteractionExt},
+    },
+    translations::Lang,
+};
+
+/// Cleanup command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "cleanup",
+    desc = "Apply protection rules to existing members",
+    desc_localizations = "cleanup_description",
+    default_permissions = "CleanupCommand::default_permissions",
+    dm_permission = false
+)]
+pub enum CleanupCommand {
+    #[command(name = "names")]
+    Names(CleanupNamesCommand),
+}
+
+desc_localizations!(cleanup_description);
+
+impl CleanupCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::MANAGE_GUILD
+    }
+
+    /// Handle the command.
+    ///
+    /// Unlike other commands, the shared state is required to perform the
+    /// cleanup in background.
+    pub async fn handle(
+        mut interaction: Interaction,
+        state: Arc<ClusterState>,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let parsed = parse_command_data::<Self>(&mut interaction)?;
+
+        match parsed {
+            Self::Names(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "names",
+    desc = "Apply the username and nickname rules to existing members",
+    desc_localizations = "cleanup_names_description"
+)]
+pub struct CleanupNamesCommand;
+
+desc_localizations!(cleanup_names_description);
+
+impl CleanupNamesCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: Arc<ClusterState>,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let author_id = interaction.author_id().context("missing author_id")?;
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+
+        if config.names.rules.is_empty() {
+            return Ok(embed::names::no_rules(lang));
+        }
+
+        // Scanning the members may exceed the interaction response deadline,
+        // so the response is deferred and updated once the scan is complete.
+        let responder = InteractionResponder::from_interaction(&interaction);
+
+        tokio::spawn(async move {
+            if let Err(error) = scan(responder, author_id, config, lang, state).await {
+                error!(error = ?error, guild = ?guild_id, "failed to scan member names");
+            }
+        });
+
+        Ok(InteractionResponse::EphemeralDeferredMessage)
+    }
+}
+
+/// Find the members with a matching name and ask for confirmation.
+///
+/// The matching members are stored in a [`PendingMassAction`], and the
+/// deferred response is updated with the confirmation buttons.
+async fn scan(
+    responder: InteractionResponder,
+    author_id: Id<UserMarker>,
+    config: GuildConfig,
+    lang: Lang,
+    state: Arc<ClusterState>,
+) -> Result<(), anyhow::Error> {
+    let matcher = NameMatcher::new(&config.names.rules);
+    let members = state.redis().guild_members(config.id).await?;
+    let scanned = members.len();
+
+    let mut matches = Vec::new();
+    for member in members {
+        if matcher.find(&member.name, member.nick.as_deref()).is_some()
+            && !is_exempt(&member, &config, &state).await?
+        {
+            matches.push(member.user_id);
+        }
+    }
+
+    let mut embed = EmbedBuilder::new()
+        .color(COLOR_TRANSPARENT)
+        .title(lang.cleanup_names_title());
+    let mut components = Vec::new();
+
+    if matches.is_empty() {
+        embed = embed.description(lang.cleanup_names_no_match(scanned));
+    } else {
+        embed = embed
+            .color(COLOR_RED)
+            .description(lang.cleanup_names_confirm(matches.len(), scanned))
+            .field(EmbedFieldBuilder::new(
+                lang.names_log_action(),
+                action_name(config.names.action, lang),
+            ));
+
+        let pending = PendingMassAction {
+            interaction_id: responder.id,
+            guild_id: config.id,
+            moderator_id: author_id,
+            kind: MassActionKind::Names,
+            members: matches,
+            reason: None,
+        };
+
+        state.redis().set(&pending).await?;
+        components.push(mass_action_buttons(responder.id, lang));
+    }
+
+    state
+        .http()
+        .interaction(responder.application_id)
+        .update_response(&responder.token)
+        .embeds(Some(&[embed.build()]))?
+        .components(Some(&components))?
+        .exec()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,252 @@
+//! Auto-moderation configuration commands.
+
+use raidprotect_model::database::model::{AutomodAction, AutomodRule, AutomodRuleKind, RuleMode};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::application::interaction::Interaction;
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::automod::{regex_filter_name, rule_name},
+    interaction::{
+        embed::{self, COLOR_GREEN, COLOR_TRANSPARENT},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+    translations::Lang,
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "automod",
+    desc = "Configure the RaidProtect auto-moderation",
+    desc_localizations = "automod_description"
+)]
+pub enum AutomodConfigCommand {
+    #[command(name = "rule")]
+    Rule(AutomodRuleCommand),
+    #[command(name = "stats")]
+    Stats(AutomodStatsCommand),
+}
+
+desc_localizations!(automod_description);
+
+impl AutomodConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            AutomodConfigCommand::Rule(command) => command.exec(interaction, state).await,
+            AutomodConfigCommand::Stats(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+/// Auto-moderation rule choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum RuleOption {
+    #[option(name = "Invite links", value = "invites")]
+    Invites,
+    #[option(name = "Phishing links", value = "phishing")]
+    Phishing,
+    #[option(name = "Hidden content", value = "hidden")]
+    Hidden,
+    #[option(name = "Cross-channel duplicates", value = "duplicates")]
+    Duplicates,
+    #[option(name = "Coordinated spam", value = "coordinated")]
+    Coordinated,
+}
+
+impl From<RuleOption> for AutomodRuleKind {
+    fn from(option: RuleOption) -> Self {
+        match option {
+            RuleOption::Invites => AutomodRuleKind::Invites,
+            RuleOption::Phishing => AutomodRuleKind::Phishing,
+            RuleOption::Hidden => AutomodRuleKind::Hidden,
+            RuleOption::Duplicates => AutomodRuleKind::Duplicates,
+            RuleOption::Coordinated => AutomodRuleKind::Coordinated,
+        }
+    }
+}
+
+/// Auto-moderation rule mode choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum ModeOption {
+    #[option(name = "Disabled", value = "disabled")]
+    Disabled,
+    #[option(name = "Monitor only", value = "monitor")]
+    Monitor,
+    #[option(name = "Enforce", value = "enforce")]
+    Enforce,
+}
+
+impl From<ModeOption> for RuleMode {
+    fn from(option: ModeOption) -> Self {
+        match option {
+            ModeOption::Disabled => RuleMode::Disabled,
+            ModeOption::Monitor => RuleMode::Monitor,
+            ModeOption::Enforce => RuleMode::Enforce,
+        }
+    }
+}
+
+/// Auto-moderation action choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum ActionOption {
+    #[option(name = "Delete the message", value = "delete")]
+    Delete,
+    #[option(name = "Delete the message and timeout the member", value = "timeout")]
+    Timeout,
+    #[option(
+        name = "Delete the message and quarantine the member",
+        value = "quarantine"
+    )]
+    Quarantine,
+}
+
+impl From<ActionOption> for AutomodAction {
+    fn from(option: ActionOption) -> Self {
+        match option {
+            ActionOption::Delete => AutomodAction::Delete,
+            ActionOption::Timeout => AutomodAction::Timeout,
+            ActionOption::Quarantine => AutomodAction::Quarantine,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "rule",
+    desc = "Configure an auto-moderation rule",
+    desc_localizations = "automod_rule_description"
+)]
+pub struct AutomodRuleCommand {
+    /// Rule to configure.
+    rule: RuleOption,
+    /// Mode of the rule. Use "Monitor only" to test the rule without performing any action.
+    mode: ModeOption,
+    /// Action performed when a message matches the rule.
+    action: Option<ActionOption>,
+    /// Duration of the timeout, in minutes.
+    #[command(min_value = 1, max_value = 40320)]
+    timeout: Option<i64>,
+}
+
+desc_localizations!(automod_rule_description);
+
+impl AutomodRuleCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let kind = AutomodRuleKind::from(self.rule);
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+
+        if matches!(self.action, Some(ActionOption::Quarantine)) && config.quarantine.role.is_none()
+        {
+            return Ok(embed::quarantine::role_missing(lang));
+        }
+
+        let rule = config.automod.rule_mut(kind);
+        let previous_mode = rule.mode;
+
+        rule.mode = self.mode.into();
+        if let Some(action) = self.action {
+            rule.action = action.into();
+        }
+        if let Some(timeout) = self.timeout {
+            let timeout = u32::try_from(timeout * 60).unwrap_or(AutomodRule::MAX_TIMEOUT);
+            rule.timeout = timeout.min(AutomodRule::MAX_TIMEOUT);
+        }
+
+        let mode = rule.mode;
+        state.mongodb().update_guild(&config).await?;
+
+        // Hit counters are reset when the mode changes, so that monitored hits
+        // reflect the current configuration of the rule.
+        if previous_mode != mode {
+            state
+                .redis()
+                .reset_automod_hits(guild_id, kind.name())
+                .await?;
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(
+                lang.automod_rule_confirm_description(mode_name(mode, lang), rule_name(kind, lang)),
+            )
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "stats",
+    desc = "Show how many messages matched the auto-moderation rules",
+    desc_localizations = "automod_stats_description"
+)]
+pub struct AutomodStatsCommand;
+
+desc_localizations!(automod_stats_description);
+
+impl AutomodStatsCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let mut embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.automod_stats_title())
+            .footer(EmbedFooterBuilder::new(lang.automod_stats_footer()));
+
+        for kind in AutomodRuleKind::ALL {
+            let rule = config.automod.rule(kind);
+            let hits = state.redis().automod_hits(guild_id, kind.name()).await?;
+
+            embed = embed.field(EmbedFieldBuilder::new(
+                rule_name(kind, lang),
+                lang.automod_stats_rule(hits.enforced, mode_name(rule.mode, lang), hits.monitored),
+            ));
+        }
+
+        for filter in &config.automod.regex {
+            let hits = state.redis().automod_hits(guild_id, &filter.id()).await?;
+
+            embed = embed.field(EmbedFieldBuilder::new(
+                regex_filter_name(&filter.name, lang),
+                lang.automod_stats_rule(
+                    hits.enforced,
+                    mode_name(filter.rule.mode, lang),
+                    hits.monitored,
+                ),
+            ));
+        }
+
+        Ok(InteractionResponse::EphemeralEmbed(embed.build()))
+    }
+}
+
+/// Get the localized name of a rule mode.
+pub(super) fn mode_name(mode: RuleMode, lang: Lang) -> &'static str {
+    match mode {
+        RuleMode::Disabled => lang.automod_mode_disabled(),
+        RuleMode::Monitor => lang.automod_mode_monitor(),
+        RuleMode::Enforce => lang.automod_mode_enforce(),
+    }
+}
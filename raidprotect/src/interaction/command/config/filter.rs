@@ -0,0 +1,358 @@
+//! Custom filters configuration commands.
+
+use raidprotect_model::{
+    cache::model::message::CachedMessage,
+    database::model::{AutomodRule, RegexFilter},
+};
+use regex::Regex;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::application::interaction::Interaction;
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+
+use super::automod::{mode_name, ActionOption, ModeOption};
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::automod::{compile_regex, regex_filter_name},
+    interaction::{
+        embed::{self, COLOR_GREEN, COLOR_TRANSPARENT},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+    translations::Lang,
+    util::TextProcessExt,
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "filter",
+    desc = "Configure custom auto-moderation filters",
+    desc_localizations = "filter_description"
+)]
+pub enum FilterConfigCommand {
+    #[command(name = "regex-add")]
+    Add(FilterRegexAddCommand),
+    #[command(name = "regex-remove")]
+    Remove(FilterRegexRemoveCommand),
+    #[command(name = "regex-list")]
+    List(FilterRegexListCommand),
+    #[command(name = "regex-test")]
+    Test(FilterRegexTestCommand),
+}
+
+desc_localizations!(filter_description);
+
+impl FilterConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            FilterConfigCommand::Add(command) => command.exec(interaction, state).await,
+            FilterConfigCommand::Remove(command) => command.exec(interaction, state).await,
+            FilterConfigCommand::List(command) => command.exec(interaction, state).await,
+            FilterConfigCommand::Test(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "regex-add",
+    desc = "Add or update a regex filter",
+    desc_localizations = "filter_regex_add_description"
+)]
+pub struct FilterRegexAddCommand {
+    /// Name of the filter. An existing filter with the same name is replaced.
+    name: String,
+    /// Regex pattern matched against the content of messages.
+    pattern: String,
+    /// Mode of the filter. Use "Monitor only" to test the filter without performing any action.
+    mode: ModeOption,
+    /// Action performed when a message matches the filter.
+    action: Option<ActionOption>,
+    /// Duration of the timeout, in minutes.
+    #[command(min_value = 1, max_value = 40320)]
+    timeout: Option<i64>,
+}
+
+desc_localizations!(filter_regex_add_description);
+
+impl FilterRegexAddCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let name = self.name.trim().to_string();
+
+        if name.is_empty() || name.chars().count() > RegexFilter::MAX_NAME_LEN {
+            return Ok(embed::filter::name_too_long(
+                RegexFilter::MAX_NAME_LEN,
+                lang,
+            ));
+        }
+
+        if self.pattern.chars().count() > RegexFilter::MAX_PATTERN_LEN {
+            return Ok(embed::filter::invalid_pattern(
+                lang.filter_regex_pattern_too_long(RegexFilter::MAX_PATTERN_LEN),
+                lang,
+            ));
+        }
+
+        if let Err(error) = compile_regex(&self.pattern) {
+            return Ok(embed::filter::invalid_pattern(error.to_string(), lang));
+        }
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+
+        if matches!(self.action, Some(ActionOption::Quarantine)) && config.quarantine.role.is_none()
+        {
+            return Ok(embed::quarantine::role_missing(lang));
+        }
+
+        let filters = &mut config.automod.regex;
+        let existing = filters.iter().position(|filter| filter.name == name);
+
+        if existing.is_none() && filters.len() >= RegexFilter::MAX_FILTERS {
+            return Ok(embed::filter::too_many_filters(
+                RegexFilter::MAX_FILTERS,
+                lang,
+            ));
+        }
+
+        let mut rule = AutomodRule {
+            mode: self.mode.into(),
+            ..Default::default()
+        };
+        if let Some(action) = self.action {
+            rule.action = action.into();
+        }
+        if let Some(timeout) = self.timeout {
+            let timeout = u32::try_from(timeout * 60).unwrap_or(AutomodRule::MAX_TIMEOUT);
+            rule.timeout = timeout.min(AutomodRule::MAX_TIMEOUT);
+        }
+
+        let filter = RegexFilter {
+            name,
+            pattern: self.pattern,
+            rule,
+        };
+        let mode = filter.rule.mode;
+        let filter_id = filter.id();
+        let filter_name = regex_filter_name(&filter.name, lang);
+
+        match existing {
+            Some(index) => filters[index] = filter,
+            None => filters.push(filter),
+        }
+
+        state.mongodb().update_guild(&config).await?;
+        state
+            .redis()
+            .reset_automod_hits(guild_id, &filter_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.automod_rule_confirm_description(mode_name(mode, lang), filter_name))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "regex-remove",
+    desc = "Remove a regex filter",
+    desc_localizations = "filter_regex_remove_description"
+)]
+pub struct FilterRegexRemoveCommand {
+    /// Name of the filter to remove.
+    name: String,
+}
+
+desc_localizations!(filter_regex_remove_description);
+
+impl FilterRegexRemoveCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let name = self.name.trim();
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let index = match config
+            .automod
+            .regex
+            .iter()
+            .position(|filter| filter.name == name)
+        {
+            Some(index) => index,
+            None => return Ok(embed::filter::not_found(name.to_string(), lang)),
+        };
+
+        let filter = config.automod.regex.remove(index);
+        state.mongodb().update_guild(&config).await?;
+        state
+            .redis()
+            .reset_automod_hits(guild_id, &filter.id())
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.filter_regex_remove_confirm(regex_filter_name(&filter.name, lang)))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "regex-list",
+    desc = "List the regex filters",
+    desc_localizations = "filter_regex_list_description"
+)]
+pub struct FilterRegexListCommand;
+
+desc_localizations!(filter_regex_list_description);
+
+impl FilterRegexListCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let mut embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.filter_regex_list_title());
+
+        if config.automod.regex.is_empty() {
+            embed = embed.description(lang.filter_regex_list_empty());
+        }
+
+        for filter in &config.automod.regex {
+            embed = embed.field(EmbedFieldBuilder::new(
+                filter.name.clone(),
+                lang.filter_regex_list_entry(mode_name(filter.rule.mode, lang), &filter.pattern),
+            ));
+        }
+
+        Ok(InteractionResponse::EphemeralEmbed(embed.build()))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "regex-test",
+    desc = "Test regex filters against the last cached messages",
+    desc_localizations = "filter_regex_test_description"
+)]
+pub struct FilterRegexTestCommand {
+    /// Pattern to test. If not set, the configured filters are tested.
+    pattern: Option<String>,
+}
+
+desc_localizations!(filter_regex_test_description);
+
+impl FilterRegexTestCommand {
+    /// Maximum number of matching messages shown.
+    const MAX_SHOWN: usize = 10;
+
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        // List of filters to test, with the name shown in the results.
+        let filters: Vec<(String, Regex)> = match self.pattern {
+            Some(pattern) => {
+                if pattern.chars().count() > RegexFilter::MAX_PATTERN_LEN {
+                    return Ok(embed::filter::invalid_pattern(
+                        lang.filter_regex_pattern_too_long(RegexFilter::MAX_PATTERN_LEN),
+                        lang,
+                    ));
+                }
+
+                match compile_regex(&pattern) {
+                    Ok(regex) => vec![(lang.filter_regex_test_pattern().to_string(), regex)],
+                    Err(error) => {
+                        return Ok(embed::filter::invalid_pattern(error.to_string(), lang))
+                    }
+                }
+            }
+            None => {
+                let config = state.mongodb().get_guild_or_create(guild_id).await?;
+
+                config
+                    .automod
+                    .regex
+                    .iter()
+                    .filter_map(|filter| {
+                        compile_regex(&filter.pattern)
+                            .ok()
+                            .map(|regex| (regex_filter_name(&filter.name, lang), regex))
+                    })
+                    .collect()
+            }
+        };
+
+        let messages = state.redis().recent_messages(guild_id).await?;
+        let matches: Vec<(&String, &CachedMessage)> = messages
+            .iter()
+            .filter_map(|message| {
+                filters
+                    .iter()
+                    .find(|(_, regex)| regex.is_match(&message.content))
+                    .map(|(name, _)| (name, message))
+            })
+            .collect();
+
+        let mut embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.filter_regex_test_title())
+            .description(lang.filter_regex_test_result(matches.len(), messages.len()));
+
+        for (name, message) in matches.iter().take(Self::MAX_SHOWN) {
+            embed = embed.field(EmbedFieldBuilder::new(
+                (*name).clone(),
+                test_result_entry(message, lang),
+            ));
+        }
+
+        if matches.len() > Self::MAX_SHOWN {
+            embed = embed.footer(EmbedFooterBuilder::new(
+                lang.filter_regex_test_more(matches.len() - Self::MAX_SHOWN),
+            ));
+        }
+
+        Ok(InteractionResponse::EphemeralEmbed(embed.build()))
+    }
+}
+
+/// Format a message matching a filter.
+fn test_result_entry(message: &CachedMessage, lang: Lang) -> String {
+    lang.filter_regex_test_entry(
+        message.channel_id.mention(),
+        message.content.truncate(200),
+        message.author_id.mention(),
+    )
+}
@@ -0,0 +1,155 @@
+//! Message logs configuration commands.
+
+use raidprotect_model::database::model::MessagesConfig;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::Interaction,
+    guild::Permissions,
+    id::{marker::ChannelMarker, Id},
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    interaction::{
+        embed::{COLOR_GREEN, COLOR_RED},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "messages",
+    desc = "Configure the RaidProtect message logs",
+    desc_localizations = "messages_description"
+)]
+pub enum MessagesConfigCommand {
+    #[command(name = "logs")]
+    Logs(MessagesLogsCommand),
+    #[command(name = "retention")]
+    Retention(MessagesRetentionCommand),
+}
+
+desc_localizations!(messages_description);
+
+impl MessagesConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            MessagesConfigCommand::Logs(command) => command.exec(interaction, state).await,
+            MessagesConfigCommand::Retention(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "logs",
+    desc = "Set the channel where edited and deleted messages are logged",
+    desc_localizations = "messages_logs_description"
+)]
+pub struct MessagesLogsCommand {
+    /// Channel to send the logs to. Leave empty to disable message logs.
+    #[command(channel_types = "guild_text")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+desc_localizations!(messages_logs_description);
+
+impl MessagesLogsCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        // Ensure RaidProtect has permissions to send messages in the channel.
+        if let Some(channel) = self.channel {
+            let (permissions, _) = state
+                .redis()
+                .permissions(guild_id)
+                .await?
+                .current_member()
+                .await?
+                .channel(channel)
+                .await?;
+
+            if !permissions.contains(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS) {
+                let embed = EmbedBuilder::new()
+                    .color(COLOR_RED)
+                    .title(lang.messages_missing_logs_permission_title())
+                    .description(lang.bot_missing_permission())
+                    .build();
+
+                return Ok(InteractionResponse::EphemeralEmbed(embed));
+            }
+        }
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.messages.logs = self.channel;
+
+        state.mongodb().update_guild(&config).await?;
+
+        let description = match self.channel {
+            Some(channel) => lang.messages_logs_confirm_description(channel.mention()),
+            None => lang.messages_logs_disabled_description().to_string(),
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "retention",
+    desc = "Set how long messages are kept to log their edition or deletion",
+    desc_localizations = "messages_retention_description"
+)]
+pub struct MessagesRetentionCommand {
+    /// Retention duration, in minutes.
+    #[command(min_value = 1, max_value = 1440)]
+    minutes: i64,
+}
+
+desc_localizations!(messages_retention_description);
+
+impl MessagesRetentionCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let retention = u32::try_from(self.minutes * 60).unwrap_or(MessagesConfig::MAX_RETENTION);
+        let retention = retention.clamp(60, MessagesConfig::MAX_RETENTION);
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.messages.retention = retention;
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.messages_retention_confirm_description(retention / 60))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
@@ -3,9 +3,23 @@
 //! The configuration command allows the user to change the configuration of the
 //! bot.
 
+mod automod;
 mod captcha;
+mod filter;
+mod messages;
+mod names;
+mod persist;
+mod quarantine;
+mod raid;
 
+pub use automod::AutomodConfigCommand;
 pub use captcha::CaptchaConfigCommand;
+pub use filter::FilterConfigCommand;
+pub use messages::MessagesConfigCommand;
+pub use names::NamesConfigCommand;
+pub use persist::PersistConfigCommand;
+pub use quarantine::QuarantineConfigCommand;
+pub use raid::RaidConfigCommand;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{application::interaction::Interaction, guild::Permissions};
 
@@ -27,6 +41,20 @@ use crate::{
 pub enum ConfigCommand {
     #[command(name = "captcha")]
     Captcha(CaptchaConfigCommand),
+    #[command(name = "automod")]
+    Automod(AutomodConfigCommand),
+    #[command(name = "messages")]
+    Messages(MessagesConfigCommand),
+    #[command(name = "filter")]
+    Filter(FilterConfigCommand),
+    #[command(name = "raid")]
+    Raid(RaidConfigCommand),
+    #[command(name = "names")]
+    Names(NamesConfigCommand),
+    #[command(name = "quarantine")]
+    Quarantine(QuarantineConfigCommand),
+    #[command(name = "persist")]
+    Persist(PersistConfigCommand),
 }
 
 impl_command_handle!(ConfigCommand);
@@ -44,6 +72,13 @@ impl ConfigCommand {
     ) -> Result<InteractionResponse, anyhow::Error> {
         match self {
             Self::Captcha(command) => command.exec(interaction, state).await,
+            Self::Automod(command) => command.exec(interaction, state).await,
+            Self::Messages(command) => command.exec(interaction, state).await,
+            Self::Filter(command) => command.exec(interaction, state).await,
+            Self::Raid(command) => command.exec(interaction, state).await,
+            Self::Names(command) => command.exec(interaction, state).await,
+            Self::Quarantine(command) => command.exec(interaction, state).await,
+            Self::Persist(command) => command.exec(interaction, state).await,
         }
     }
 }
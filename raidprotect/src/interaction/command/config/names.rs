@@ -0,0 +1,293 @@
+//! Username and nickname rules configuration commands.
+
+use raidprotect_model::database::model::{NameAction, NameRule, NameRuleKind, NamesConfig};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::application::interaction::Interaction;
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::names::{action_name, compile_rule, rule_kind_name},
+    interaction::{
+        embed::{self, COLOR_GREEN, COLOR_TRANSPARENT},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "names",
+    desc = "Configure the username and nickname rules",
+    desc_localizations = "names_description"
+)]
+pub enum NamesConfigCommand {
+    #[command(name = "add")]
+    Add(NamesAddCommand),
+    #[command(name = "remove")]
+    Remove(NamesRemoveCommand),
+    #[command(name = "list")]
+    List(NamesListCommand),
+    #[command(name = "action")]
+    Action(NamesActionCommand),
+}
+
+desc_localizations!(names_description);
+
+impl NamesConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            NamesConfigCommand::Add(command) => command.exec(interaction, state).await,
+            NamesConfigCommand::Remove(command) => command.exec(interaction, state).await,
+            NamesConfigCommand::List(command) => command.exec(interaction, state).await,
+            NamesConfigCommand::Action(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add a username and nickname rule",
+    desc_localizations = "names_add_description"
+)]
+pub struct NamesAddCommand {
+    /// Kind of the rule.
+    kind: NameRuleKindOption,
+    /// Pattern matched against usernames and nicknames.
+    pattern: String,
+}
+
+desc_localizations!(names_add_description);
+
+impl NamesAddCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let rule = NameRule {
+            kind: self.kind.into(),
+            pattern: self.pattern.trim().to_string(),
+        };
+
+        if rule.pattern.is_empty() || rule.pattern.chars().count() > NameRule::MAX_PATTERN_LEN {
+            return Ok(embed::names::invalid_pattern(
+                lang.names_pattern_too_long(NameRule::MAX_PATTERN_LEN),
+                lang,
+            ));
+        }
+
+        if let Err(error) = compile_rule(&rule) {
+            return Ok(embed::names::invalid_pattern(error.to_string(), lang));
+        }
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let rules = &mut config.names.rules;
+
+        if rules.contains(&rule) {
+            return Ok(embed::names::already_exists(lang));
+        }
+
+        if rules.len() >= NamesConfig::MAX_RULES {
+            return Ok(embed::names::too_many_rules(NamesConfig::MAX_RULES, lang));
+        }
+
+        let entry = lang.names_rule_entry(rule_kind_name(rule.kind, lang), &rule.pattern);
+        rules.push(rule);
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.names_add_confirm(entry))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "remove",
+    desc = "Remove a username and nickname rule",
+    desc_localizations = "names_remove_description"
+)]
+pub struct NamesRemoveCommand {
+    /// Pattern of the rule to remove.
+    pattern: String,
+}
+
+desc_localizations!(names_remove_description);
+
+impl NamesRemoveCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let pattern = self.pattern.trim();
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let index = match config
+            .names
+            .rules
+            .iter()
+            .position(|rule| rule.pattern == pattern)
+        {
+            Some(index) => index,
+            None => return Ok(embed::names::not_found(pattern.to_string(), lang)),
+        };
+
+        let rule = config.names.rules.remove(index);
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.names_remove_confirm(
+                lang.names_rule_entry(rule_kind_name(rule.kind, lang), &rule.pattern),
+            ))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List the username and nickname rules",
+    desc_localizations = "names_list_description"
+)]
+pub struct NamesListCommand;
+
+desc_localizations!(names_list_description);
+
+impl NamesListCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let mut embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.names_list_title())
+            .field(EmbedFieldBuilder::new(
+                lang.names_log_action(),
+                action_name(config.names.action, lang),
+            ));
+
+        let rules = config
+            .names
+            .rules
+            .iter()
+            .map(|rule| lang.names_rule_entry(rule_kind_name(rule.kind, lang), &rule.pattern))
+            .collect::<Vec<_>>();
+
+        if rules.is_empty() {
+            embed = embed.description(lang.names_list_empty());
+        } else {
+            embed = embed.description(rules.join("\n"));
+        }
+
+        Ok(InteractionResponse::EphemeralEmbed(embed.build()))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "action",
+    desc = "Configure the action performed on members with a matching name",
+    desc_localizations = "names_action_description"
+)]
+pub struct NamesActionCommand {
+    /// Action performed on members whose username or nickname matches a rule.
+    action: NameActionOption,
+}
+
+desc_localizations!(names_action_description);
+
+impl NamesActionCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.names.action = self.action.into();
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.names_action_confirm(action_name(config.names.action, lang)))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Name rule kind choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum NameRuleKindOption {
+    #[option(name = "Contains the text", value = "substring")]
+    Substring,
+    #[option(
+        name = "Wildcard (* any text, ? any character, # any digit)",
+        value = "wildcard"
+    )]
+    Wildcard,
+    #[option(name = "Regular expression", value = "regex")]
+    Regex,
+}
+
+impl From<NameRuleKindOption> for NameRuleKind {
+    fn from(option: NameRuleKindOption) -> Self {
+        match option {
+            NameRuleKindOption::Substring => NameRuleKind::Substring,
+            NameRuleKindOption::Wildcard => NameRuleKind::Wildcard,
+            NameRuleKindOption::Regex => NameRuleKind::Regex,
+        }
+    }
+}
+
+/// Name rule action choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum NameActionOption {
+    #[option(name = "Replace the nickname", value = "rename")]
+    Rename,
+    #[option(name = "Kick the member", value = "kick")]
+    Kick,
+    #[option(name = "Ban the member", value = "ban")]
+    Ban,
+}
+
+impl From<NameActionOption> for NameAction {
+    fn from(option: NameActionOption) -> Self {
+        match option {
+            NameActionOption::Rename => NameAction::Rename,
+            NameActionOption::Kick => NameAction::Kick,
+            NameActionOption::Ban => NameAction::Ban,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Role persistence configuration commands.
+
+use raidprotect_model::database::model::PersistConfig;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::Interaction,
+    guild::Role,
+    id::{marker::RoleMarker, Id},
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    interaction::{
+        embed::{self, COLOR_GREEN},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "persist",
+    desc = "Configure the roles restored when members join again",
+    desc_localizations = "persist_description"
+)]
+pub enum PersistConfigCommand {
+    #[command(name = "role-add")]
+    RoleAdd(PersistRoleAddCommand),
+    #[command(name = "role-remove")]
+    RoleRemove(PersistRoleRemoveCommand),
+    #[command(name = "all")]
+    All(PersistAllCommand),
+}
+
+desc_localizations!(persist_description);
+
+impl PersistConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            PersistConfigCommand::RoleAdd(command) => command.exec(interaction, state).await,
+            PersistConfigCommand::RoleRemove(command) => command.exec(interaction, state).await,
+            PersistConfigCommand::All(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "role-add",
+    desc = "Persist a role, such as a mute role, when members leave",
+    desc_localizations = "persist_role_add_description"
+)]
+pub struct PersistRoleAddCommand {
+    /// Role to persist.
+    role: Role,
+}
+
+desc_localizations!(persist_role_add_description);
+
+impl PersistRoleAddCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        if self.role.managed || self.role.id == guild_id.cast() {
+            return Ok(embed::persist::role_invalid(lang));
+        }
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+
+        if config.persist.roles.contains(&self.role.id) {
+            return Ok(embed::persist::role_already_added(lang));
+        }
+
+        if config.persist.roles.len() >= PersistConfig::MAX_ROLES_LEN {
+            return Ok(embed::persist::role_too_many(lang));
+        }
+
+        config.persist.roles.push(self.role.id);
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.persist_role_add_confirm(self.role.mention()))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "role-remove",
+    desc = "Stop persisting a role",
+    desc_localizations = "persist_role_remove_description"
+)]
+pub struct PersistRoleRemoveCommand {
+    /// Role to stop persisting.
+    role: Id<RoleMarker>,
+}
+
+desc_localizations!(persist_role_remove_description);
+
+impl PersistRoleRemoveCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+
+        if !config.persist.roles.contains(&self.role) {
+            return Ok(embed::persist::role_not_configured(lang));
+        }
+
+        config.persist.roles.retain(|role| role != &self.role);
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.persist_role_remove_confirm(self.role.mention()))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "all",
+    desc = "Restore all the roles of returning members",
+    desc_localizations = "persist_all_description"
+)]
+pub struct PersistAllCommand {
+    /// Whether all the roles of returning members are restored.
+    enabled: bool,
+}
+
+desc_localizations!(persist_all_description);
+
+impl PersistAllCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.persist.all = self.enabled;
+        state.mongodb().update_guild(&config).await?;
+
+        let description = if self.enabled {
+            lang.persist_all_enabled()
+        } else {
+            lang.persist_all_disabled()
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
@@ -0,0 +1,138 @@
+//! Quarantine configuration commands.
+
+use raidprotect_model::cache::permission::RoleOrdering;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::{
+        component::{button::ButtonStyle, ActionRow, Button, Component},
+        interaction::Interaction,
+    },
+    channel::message::MessageFlags,
+    guild::{Permissions, Role},
+    http::interaction::InteractionResponseType,
+};
+use twilight_util::builder::{embed::EmbedBuilder, InteractionResponseDataBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    interaction::{
+        embed::{self, COLOR_RED},
+        response::InteractionResponse,
+        util::{CustomId, InteractionExt},
+    },
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "quarantine",
+    desc = "Configure the quarantine of members",
+    desc_localizations = "quarantine_config_description"
+)]
+pub enum QuarantineConfigCommand {
+    #[command(name = "setup")]
+    Setup(QuarantineSetupCommand),
+}
+
+desc_localizations!(quarantine_config_description);
+
+impl QuarantineConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            QuarantineConfigCommand::Setup(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "setup",
+    desc = "Set the quarantine role and hide it from all channels",
+    desc_localizations = "quarantine_setup_description"
+)]
+pub struct QuarantineSetupCommand {
+    /// Role given to quarantined members. A new role is created if not set.
+    role: Option<Role>,
+}
+
+desc_localizations!(quarantine_setup_description);
+
+impl QuarantineSetupCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        // Ensure RaidProtect has permissions to configure the role.
+        let permissions = state
+            .redis()
+            .permissions(guild_id)
+            .await?
+            .current_member()
+            .await?;
+
+        if !permissions
+            .guild()
+            .contains(Permissions::MANAGE_CHANNELS | Permissions::MANAGE_ROLES)
+        {
+            return Ok(embed::quarantine::missing_setup_permission(lang));
+        }
+
+        let (description, custom_id) = match &self.role {
+            Some(role) => {
+                if role.managed || role.id == guild_id.cast() {
+                    return Ok(embed::quarantine::role_invalid(lang));
+                }
+
+                if RoleOrdering::from(role) >= permissions.highest_role() {
+                    return Ok(embed::quarantine::role_hierarchy(lang));
+                }
+
+                (
+                    lang.quarantine_setup_confirm_role(role.mention()),
+                    CustomId::new("quarantine-setup", role.id.to_string()),
+                )
+            }
+            None => (
+                lang.quarantine_setup_confirm_create().to_string(),
+                CustomId::name("quarantine-setup"),
+            ),
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_RED)
+            .title(lang.quarantine_setup_confirm_title())
+            .description(description)
+            .build();
+
+        let components = Component::ActionRow(ActionRow {
+            components: vec![Component::Button(Button {
+                custom_id: Some(custom_id.to_string()),
+                disabled: false,
+                emoji: None,
+                label: Some(lang.quarantine_setup_confirm_button().to_string()),
+                style: ButtonStyle::Success,
+                url: None,
+            })],
+        });
+
+        let response = InteractionResponseDataBuilder::new()
+            .embeds([embed])
+            .components([components])
+            .flags(MessageFlags::EPHEMERAL)
+            .build();
+
+        Ok(InteractionResponse::Raw {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(response),
+        })
+    }
+}
@@ -0,0 +1,569 @@
+//! Raid protection configuration commands.
+
+use raidprotect_model::{
+    cache::model::risk::MemberRisk,
+    database::model::{
+        AccountAgeAction, AccountAgeConfig, AntinukeAction, AntinukeConfig, RaidAction, RaidConfig,
+    },
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::Interaction,
+    guild::Permissions,
+    id::{marker::ChannelMarker, Id},
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::{
+        antinuke,
+        raid::{account_age_action_name, action_participle, format_age},
+    },
+    interaction::{
+        embed::{self, COLOR_GREEN, COLOR_RED},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+};
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "raid",
+    desc = "Configure the RaidProtect raid protection",
+    desc_localizations = "raid_description"
+)]
+pub enum RaidConfigCommand {
+    #[command(name = "detection")]
+    Detection(RaidDetectionCommand),
+    #[command(name = "action")]
+    Action(RaidActionCommand),
+    #[command(name = "account-age")]
+    AccountAge(RaidAccountAgeCommand),
+    #[command(name = "risk")]
+    Risk(RaidRiskCommand),
+    #[command(name = "join-logs")]
+    JoinLogs(RaidJoinLogsCommand),
+    #[command(name = "join-history")]
+    JoinHistory(RaidJoinHistoryCommand),
+    #[command(name = "antinuke")]
+    Antinuke(RaidAntinukeCommand),
+}
+
+desc_localizations!(raid_description);
+
+impl RaidConfigCommand {
+    pub(super) async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            RaidConfigCommand::Detection(command) => command.exec(interaction, state).await,
+            RaidConfigCommand::Action(command) => command.exec(interaction, state).await,
+            RaidConfigCommand::AccountAge(command) => command.exec(interaction, state).await,
+            RaidConfigCommand::Risk(command) => command.exec(interaction, state).await,
+            RaidConfigCommand::JoinLogs(command) => command.exec(interaction, state).await,
+            RaidConfigCommand::JoinHistory(command) => command.exec(interaction, state).await,
+            RaidConfigCommand::Antinuke(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "detection",
+    desc = "Configure the automatic detection of join waves",
+    desc_localizations = "raid_detection_description"
+)]
+pub struct RaidDetectionCommand {
+    /// Whether raid mode is enabled automatically when a join wave is detected.
+    enabled: bool,
+    /// Number of joins that triggers raid mode.
+    #[command(min_value = 2, max_value = 100)]
+    joins: Option<i64>,
+    /// Duration in which the joins are counted, in seconds.
+    #[command(min_value = 1, max_value = 300)]
+    window: Option<i64>,
+    /// Duration of raid mode, in minutes.
+    #[command(min_value = 1, max_value = 1440)]
+    duration: Option<i64>,
+}
+
+desc_localizations!(raid_detection_description);
+
+impl RaidDetectionCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let raid = &mut config.raid;
+
+        raid.detection = self.enabled;
+
+        if let Some(joins) = self.joins {
+            raid.joins = u32::try_from(joins)
+                .unwrap_or(RaidConfig::MAX_JOINS)
+                .clamp(2, RaidConfig::MAX_JOINS);
+        }
+        if let Some(window) = self.window {
+            raid.window = u32::try_from(window)
+                .unwrap_or(RaidConfig::MAX_WINDOW)
+                .clamp(1, RaidConfig::MAX_WINDOW);
+        }
+        if let Some(duration) = self.duration {
+            raid.duration = u32::try_from(duration * 60)
+                .unwrap_or(RaidConfig::MAX_DURATION)
+                .clamp(60, RaidConfig::MAX_DURATION);
+        }
+
+        let description = if raid.detection {
+            lang.raid_detection_enabled(raid.duration / 60, raid.joins, raid.window)
+        } else {
+            lang.raid_detection_disabled().to_string()
+        };
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Raid mode action choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum RaidActionOption {
+    #[option(name = "Kick the member", value = "kick")]
+    Kick,
+    #[option(name = "Ban the member", value = "ban")]
+    Ban,
+}
+
+impl From<RaidActionOption> for RaidAction {
+    fn from(option: RaidActionOption) -> Self {
+        match option {
+            RaidActionOption::Kick => RaidAction::Kick,
+            RaidActionOption::Ban => RaidAction::Ban,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "action",
+    desc = "Configure the action performed on members joining during raid mode",
+    desc_localizations = "raid_action_description"
+)]
+pub struct RaidActionCommand {
+    /// Action performed on members joining while raid mode is enabled.
+    action: RaidActionOption,
+}
+
+desc_localizations!(raid_action_description);
+
+impl RaidActionCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.raid.action = self.action.into();
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.raid_action_confirm(action_participle(config.raid.action, lang)))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Account age gate action choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum AccountAgeActionOption {
+    #[option(name = "Kick the member", value = "kick")]
+    Kick,
+    #[option(name = "Ban the member", value = "ban")]
+    Ban,
+    #[option(name = "Require the captcha", value = "captcha")]
+    Captcha,
+    #[option(name = "Give the quarantine role", value = "quarantine")]
+    Quarantine,
+}
+
+impl From<AccountAgeActionOption> for AccountAgeAction {
+    fn from(option: AccountAgeActionOption) -> Self {
+        match option {
+            AccountAgeActionOption::Kick => AccountAgeAction::Kick,
+            AccountAgeActionOption::Ban => AccountAgeAction::Ban,
+            AccountAgeActionOption::Captcha => AccountAgeAction::Captcha,
+            AccountAgeActionOption::Quarantine => AccountAgeAction::Quarantine,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "account-age",
+    desc = "Configure the minimum account age of new members",
+    desc_localizations = "account_age_description"
+)]
+pub struct RaidAccountAgeCommand {
+    /// Whether accounts younger than the minimum age are filtered.
+    enabled: bool,
+    /// Minimum age of the accounts, in hours.
+    #[command(min_value = 1, max_value = 2160)]
+    age: Option<i64>,
+    /// Action performed on accounts younger than the minimum age.
+    action: Option<AccountAgeActionOption>,
+}
+
+desc_localizations!(account_age_description);
+
+impl RaidAccountAgeCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let account_age = &mut config.account_age;
+
+        account_age.enabled = self.enabled;
+
+        if let Some(age) = self.age {
+            account_age.min_age = u32::try_from(age * 3600)
+                .unwrap_or(AccountAgeConfig::MAX_MIN_AGE)
+                .clamp(3600, AccountAgeConfig::MAX_MIN_AGE);
+        }
+        if let Some(action) = self.action {
+            account_age.action = action.into();
+        }
+        if account_age.enabled {
+            match account_age.action {
+                AccountAgeAction::Captcha if !config.captcha.enabled => {
+                    return Ok(embed::captcha::not_enabled(lang));
+                }
+                AccountAgeAction::Quarantine if config.quarantine.role.is_none() => {
+                    return Ok(embed::quarantine::role_missing(lang));
+                }
+                _ => {}
+            }
+        }
+
+        let description = if account_age.enabled {
+            lang.account_age_enabled(
+                account_age_action_name(account_age.action, lang),
+                format_age(account_age.min_age, lang),
+            )
+        } else {
+            lang.account_age_disabled().to_string()
+        };
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "risk",
+    desc = "Configure the actions based on the risk score of new members",
+    desc_localizations = "risk_description"
+)]
+pub struct RaidRiskCommand {
+    /// Minimum risk score requiring the captcha. Use 0 to require the captcha for all members.
+    #[command(min_value = 0, max_value = 100)]
+    captcha: Option<i64>,
+    /// Minimum risk score for members to be quarantined. Use 0 to disable.
+    #[command(min_value = 0, max_value = 100)]
+    quarantine: Option<i64>,
+}
+
+desc_localizations!(risk_description);
+
+impl RaidRiskCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let risk = &mut config.risk;
+
+        if let Some(captcha) = self.captcha {
+            risk.captcha = u8::try_from(captcha)
+                .unwrap_or(MemberRisk::MAX_SCORE)
+                .min(MemberRisk::MAX_SCORE);
+        }
+        if let Some(quarantine) = self.quarantine {
+            risk.quarantine = match u8::try_from(quarantine) {
+                Ok(0) => None,
+                Ok(score) => Some(score.min(MemberRisk::MAX_SCORE)),
+                Err(_) => Some(MemberRisk::MAX_SCORE),
+            };
+        }
+
+        if risk.quarantine.is_some() && config.quarantine.role.is_none() {
+            return Ok(embed::quarantine::role_missing(lang));
+        }
+
+        let mut description = if risk.captcha > 0 {
+            lang.risk_captcha_threshold(risk.captcha)
+        } else {
+            lang.risk_captcha_all().to_string()
+        };
+
+        description.push('\n');
+        match risk.quarantine {
+            Some(score) => description.push_str(&lang.risk_quarantine_threshold(score)),
+            None => description.push_str(lang.risk_quarantine_disabled()),
+        }
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "join-logs",
+    desc = "Set the channel where joins are logged with their risk score",
+    desc_localizations = "risk_logs_description"
+)]
+pub struct RaidJoinLogsCommand {
+    /// Channel to send the logs to. Leave empty to disable join logs.
+    #[command(channel_types = "guild_text")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+desc_localizations!(risk_logs_description);
+
+impl RaidJoinLogsCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        // Ensure RaidProtect has permissions to send messages in the channel.
+        if let Some(channel) = self.channel {
+            let (permissions, _) = state
+                .redis()
+                .permissions(guild_id)
+                .await?
+                .current_member()
+                .await?
+                .channel(channel)
+                .await?;
+
+            if !permissions.contains(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS) {
+                let embed = EmbedBuilder::new()
+                    .color(COLOR_RED)
+                    .title(lang.messages_missing_logs_permission_title())
+                    .description(lang.bot_missing_permission())
+                    .build();
+
+                return Ok(InteractionResponse::EphemeralEmbed(embed));
+            }
+        }
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.risk.logs = self.channel;
+
+        state.mongodb().update_guild(&config).await?;
+
+        let description = match self.channel {
+            Some(channel) => lang.risk_logs_confirm(channel.mention()),
+            None => lang.risk_logs_disabled().to_string(),
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "join-history",
+    desc = "Configure how long joins are kept for the mass ban and mass kick commands",
+    desc_localizations = "raid_history_description"
+)]
+pub struct RaidJoinHistoryCommand {
+    /// Duration joins are kept, in hours.
+    #[command(min_value = 1, max_value = 168)]
+    hours: i64,
+}
+
+desc_localizations!(raid_history_description);
+
+impl RaidJoinHistoryCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        config.raid.history = u32::try_from(self.hours * 60 * 60)
+            .unwrap_or(RaidConfig::MAX_HISTORY)
+            .clamp(60 * 60, RaidConfig::MAX_HISTORY);
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.raid_history_confirm(config.raid.history / 3600))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "antinuke",
+    desc = "Configure the protection against destructive actions of staff members",
+    desc_localizations = "antinuke_description"
+)]
+pub struct RaidAntinukeCommand {
+    /// Whether the anti-nuke protection is enabled.
+    enabled: bool,
+    /// Number of destructive actions of the same kind that triggers the protection.
+    #[command(min_value = 2, max_value = 50)]
+    threshold: Option<i64>,
+    /// Duration in which the actions are counted, in seconds.
+    #[command(min_value = 1, max_value = 300)]
+    window: Option<i64>,
+    /// Action performed on the member responsible for the actions.
+    action: Option<AntinukeActionOption>,
+}
+
+desc_localizations!(antinuke_description);
+
+impl RaidAntinukeCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let mut config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let antinuke = &mut config.antinuke;
+
+        antinuke.enabled = self.enabled;
+
+        if let Some(threshold) = self.threshold {
+            antinuke.threshold = u32::try_from(threshold)
+                .unwrap_or(AntinukeConfig::MAX_THRESHOLD)
+                .clamp(2, AntinukeConfig::MAX_THRESHOLD);
+        }
+        if let Some(window) = self.window {
+            antinuke.window = u32::try_from(window)
+                .unwrap_or(AntinukeConfig::MAX_WINDOW)
+                .clamp(1, AntinukeConfig::MAX_WINDOW);
+        }
+        if let Some(action) = self.action {
+            antinuke.action = action.into();
+        }
+
+        if antinuke.enabled
+            && antinuke.action == AntinukeAction::Quarantine
+            && config.quarantine.role.is_none()
+        {
+            return Ok(embed::quarantine::role_missing(lang));
+        }
+
+        let description = if antinuke.enabled {
+            lang.antinuke_enabled(
+                antinuke::action_name(antinuke.action, lang),
+                antinuke.threshold,
+                antinuke.window,
+            )
+        } else {
+            lang.antinuke_disabled().to_string()
+        };
+
+        state.mongodb().update_guild(&config).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(description)
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Anti-nuke action choice.
+#[derive(Debug, Clone, Copy, CommandOption, CreateOption)]
+pub enum AntinukeActionOption {
+    #[option(name = "Remove the roles of the member", value = "strip_roles")]
+    StripRoles,
+    #[option(
+        name = "Remove the roles and give the quarantine role",
+        value = "quarantine"
+    )]
+    Quarantine,
+}
+
+impl From<AntinukeActionOption> for AntinukeAction {
+    fn from(option: AntinukeActionOption) -> Self {
+        match option {
+            AntinukeActionOption::StripRoles => AntinukeAction::StripRoles,
+            AntinukeActionOption::Quarantine => AntinukeAction::Quarantine,
+        }
+    }
+}
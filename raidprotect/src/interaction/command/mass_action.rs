@@ -0,0 +1,293 @@
+//! Mass ban and mass kick commands.
+//!
+//! These commands target the members that joined the server within a time
+//! range, such as the accounts of a raid. The range is expressed in minutes
+//! ago: `from` is the start of the range, and `to` the end of the range (now
+//! by default). Targeted members can be filtered by account age and roles.
+//!
+//! The number of targeted members is shown with a confirmation button. Once
+//! confirmed, the action is performed in background (see the
+//! [`MassActionConfirm`] component).
+//!
+//! [`MassActionConfirm`]: crate::interaction::component::MassActionConfirm
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use raidprotect_model::{
+    cache::{
+        error::CacheMiss,
+        model::{
+            interaction::{MassActionKind, PendingMassAction},
+            CachedGuild,
+        },
+    },
+    database::model::{ModlogType, RaidConfig},
+};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::{
+    timestamp::{Timestamp, TimestampStyle},
+    Mention,
+};
+use twilight_model::{
+    application::interaction::Interaction, channel::message::MessageFlags, guild::Permissions,
+    http::interaction::InteractionResponseType,
+};
+use twilight_util::builder::{
+    embed::{EmbedBuilder, EmbedFieldBuilder},
+    InteractionResponseDataBuilder,
+};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::mass_action::{
+        action_name, join_range, select_targets, TargetFilter, TargetHierarchy,
+    },
+    impl_command_handle,
+    interaction::{
+        component::mass_action_buttons,
+        embed::{self, COLOR_RED},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+    util::TextProcessExt,
+};
+
+/// Maximum number of members shown in the preview.
+const PREVIEW_MEMBERS: usize = 20;
+
+/// Maximum range of the commands, in minutes.
+const MAX_RANGE: i64 = RaidConfig::MAX_HISTORY as i64 / 60;
+
+/// Mass ban command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "massban",
+    desc = "Ban the members who joined the server within a time range",
+    desc_localizations = "massban_description",
+    default_permissions = "MassBanCommand::default_permissions",
+    dm_permission = false
+)]
+pub struct MassBanCommand {
+    /// Start of the range, in minutes ago.
+    #[command(min_value = 1, max_value = 10080)]
+    from: i64,
+    /// End of the range, in minutes ago. Defaults to now.
+    #[command(min_value = 0, max_value = 10080)]
+    to: Option<i64>,
+    /// Only target accounts younger than this age, in hours.
+    #[command(rename = "account-age", min_value = 1, max_value = 8760)]
+    account_age: Option<i64>,
+    /// Only target members without roles.
+    #[command(rename = "no-roles")]
+    no_roles: Option<bool>,
+    /// Reason for the ban.
+    reason: Option<String>,
+}
+
+impl_command_handle!(MassBanCommand);
+desc_localizations!(massban_description);
+
+impl MassBanCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::BAN_MEMBERS
+    }
+
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let options = MassActionOptions {
+            from: self.from,
+            to: self.to,
+            account_age: self.account_age,
+            no_roles: self.no_roles,
+            reason: self.reason,
+        };
+
+        preview(ModlogType::Ban, options, interaction, state).await
+    }
+}
+
+/// Mass kick command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "masskick",
+    desc = "Kick the members who joined the server within a time range",
+    desc_localizations = "masskick_description",
+    default_permissions = "MassKickCommand::default_permissions",
+    dm_permission = false
+)]
+pub struct MassKickCommand {
+    /// Start of the range, in minutes ago.
+    #[command(min_value = 1, max_value = 10080)]
+    from: i64,
+    /// End of the range, in minutes ago. Defaults to now.
+    #[command(min_value = 0, max_value = 10080)]
+    to: Option<i64>,
+    /// Only target accounts younger than this age, in hours.
+    #[command(rename = "account-age", min_value = 1, max_value = 8760)]
+    account_age: Option<i64>,
+    /// Only target members without roles.
+    #[command(rename = "no-roles")]
+    no_roles: Option<bool>,
+    /// Reason for the kick.
+    reason: Option<String>,
+}
+
+impl_command_handle!(MassKickCommand);
+desc_localizations!(masskick_description);
+
+impl MassKickCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::KICK_MEMBERS
+    }
+
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let options = MassActionOptions {
+            from: self.from,
+            to: self.to,
+            account_age: self.account_age,
+            no_roles: self.no_roles,
+            reason: self.reason,
+        };
+
+        preview(ModlogType::Kick, options, interaction, state).await
+    }
+}
+
+/// Options shared by the mass action commands.
+struct MassActionOptions {
+    from: i64,
+    to: Option<i64>,
+    account_age: Option<i64>,
+    no_roles: Option<bool>,
+    reason: Option<String>,
+}
+
+/// Find the targeted members and ask the moderator for confirmation.
+async fn preview(
+    kind: ModlogType,
+    options: MassActionOptions,
+    interaction: Interaction,
+    state: &ClusterState,
+) -> Result<InteractionResponse, anyhow::Error> {
+    let lang = interaction.locale()?;
+    let guild = interaction.guild()?;
+    let moderator_id = interaction.author_id().context("missing author_id")?;
+
+    let now = OffsetDateTime::now_utc();
+    let (from, to) = match join_range(now, options.from.min(MAX_RANGE), options.to) {
+        Some(range) => range,
+        None => return Ok(embed::mass_action::invalid_range(lang)),
+    };
+
+    let filter = TargetFilter {
+        max_account_age: options.account_age.map(Duration::hours),
+        no_roles: options.no_roles.unwrap_or(false),
+    };
+
+    let joins = state.redis().joins_between(guild.id, from, to).await?;
+    let members = state
+        .redis()
+        .guild_members(guild.id)
+        .await?
+        .into_iter()
+        .map(|member| (member.user_id, member))
+        .collect::<HashMap<_, _>>();
+    let exempt = [moderator_id, state.current_user().cast()];
+
+    // The moderator's highest role is resolved once to exclude the members
+    // the moderator cannot sanction.
+    let cached_guild = state
+        .redis()
+        .get::<CachedGuild>(&guild.id)
+        .await?
+        .ok_or(CacheMiss::Guild(guild.id))?;
+    let moderator = state
+        .redis()
+        .permissions(guild.id)
+        .await?
+        .member(moderator_id, &guild.member.roles)
+        .await?;
+    let roles = state.redis().guild_roles(guild.id).await?;
+    let hierarchy = TargetHierarchy::new(
+        guild.id,
+        cached_guild.owner_id,
+        (!moderator.is_owner()).then(|| moderator.highest_role()),
+        &roles,
+    );
+
+    let targets = select_targets(&joins, &members, kind, filter, &hierarchy, &exempt, now);
+
+    if targets.is_empty() {
+        return Ok(embed::mass_action::no_members(lang));
+    }
+
+    let reason = options.reason.map(|reason| reason.truncate(100));
+    let pending = PendingMassAction {
+        interaction_id: interaction.id,
+        guild_id: guild.id,
+        moderator_id,
+        kind: MassActionKind::Sanction(kind),
+        members: targets,
+        reason,
+    };
+
+    state.redis().set(&pending).await?;
+
+    // Build the preview embed.
+    let mut preview = pending
+        .members
+        .iter()
+        .take(PREVIEW_MEMBERS)
+        .map(|user_id| user_id.mention().to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if pending.members.len() > PREVIEW_MEMBERS {
+        preview.push_str(&lang.mass_action_preview_more(pending.members.len() - PREVIEW_MEMBERS));
+    }
+
+    let range_start = Timestamp::new(
+        from.unix_timestamp() as u64,
+        Some(TimestampStyle::ShortTime),
+    );
+    let range_end = Timestamp::new(to.unix_timestamp() as u64, Some(TimestampStyle::ShortTime));
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.mass_action_confirm_title(action_name(kind, lang)))
+        .description(lang.mass_action_confirm_description(
+            action_name(kind, lang),
+            pending.members.len(),
+            range_start.mention(),
+            range_end.mention(),
+        ))
+        .field(EmbedFieldBuilder::new(lang.mass_action_members(), preview))
+        .build();
+
+    let components = mass_action_buttons(interaction.id, lang);
+
+    let response = InteractionResponseDataBuilder::new()
+        .embeds([embed])
+        .components([components])
+        .flags(MessageFlags::EPHEMERAL)
+        .build();
+
+    Ok(InteractionResponse::Raw {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(response),
+    })
+}
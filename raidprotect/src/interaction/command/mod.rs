@@ -2,7 +2,12 @@
 //!
 //! This module contains implementations of the bot slash commands.
 
+pub mod backup;
+pub mod cleanup;
 pub mod config;
 pub mod help;
+pub mod mass_action;
 pub mod moderation;
 pub mod profile;
+pub mod quarantine;
+pub mod raidmode;
@@ -27,3 +27,4 @@ pub use kick::KickCommand;
 pub use ban::BanCommand;
 pub use mute::MuteCommand;
 pub use warn::WarnCommand;
+pub use util::{ModerationPermissions, PermissionCheck};
@@ -8,11 +8,8 @@
 //! sent in the guild's logs channel. The muteed user receives a pm with the
 //! reason of the mute.
 
-use super::util::{
-    check_command_permissions, check_user_permissions, get_modal_requirements, get_permissions,
-    get_command_data,
-};
-use raidprotect_model::{cache::model::interaction::PendingSanction, mongodb::modlog::ModlogType};
+use super::util::{get_command_data, get_modal_requirements, ModerationPermissions};
+use raidprotect_model::{cache::model::interaction::PendingSanction, database::model::ModlogType};
 use twilight_interactions::command::{CommandModel, CreateCommand, ResolvedUser};
 use twilight_model::{
     application::{
@@ -64,35 +61,22 @@ impl MuteCommand {
         interaction: Interaction,
         state: &ClusterState,
     ) -> Result<InteractionResponse, anyhow::Error> {
-        let (guild_id, author_id, lang) = get_command_data(&interaction).await?;
+        let (guild_id, lang) = get_command_data(&interaction).await?;
         let user = self.user.resolved;
         let member = match self.user.member {
             Some(member) => member,
             None => return Ok(embed::mute::not_member(user.name, lang)),
         };
-        // Fetch the author and the bot permissions.
-        let (author_permissions, member_permissions, bot_permissions) =
-            get_permissions(state, guild_id, author_id, member, &user).await?;
-
-        // Check if the author and the bot have required permissions.
-        if let Some(value) = check_user_permissions(
-            &member_permissions,
-            lang,
-            &bot_permissions,
-            Permissions::MUTE_MEMBERS,
-        ) {
-            return value;
-        }
+        // Check if the author and the bot can perform the mute, using the
+        // roles of each member.
+        let permissions =
+            ModerationPermissions::fetch(state, &interaction, user.id, &member.roles).await?;
 
-        // Check if the role hierarchy allow the author and the bot to perform
-        // the mute.
-        if let Some(value) = check_command_permissions(
-            member_permissions,
-            author_permissions,
-            lang,
-            bot_permissions,
-        ) {
-            return value;
+        if let Some(response) = permissions
+            .check(Permissions::MUTE_MEMBERS)
+            .response(ModlogType::Mute, lang)
+        {
+            return Ok(response);
         }
 
         // Send reason modal.
@@ -15,78 +15,163 @@ use crate::{
     translations::Lang,
 };
 use anyhow::{Error, Context};
+use raidprotect_model::{
+    cache::permission::{CachePermissions, RoleOrdering},
+    database::model::ModlogType,
+};
 use twilight_model::{
-    application::interaction::Interaction, guild::Permissions, id::Id, user::User,
+    application::interaction::Interaction,
+    guild::Permissions,
+    id::{
+        marker::{RoleMarker, UserMarker},
+        Id,
+    },
 };
 
 /* Get basic data about command */
 pub async fn get_command_data(
     interaction: &Interaction,
-) -> Result<
-    (
-        Id<twilight_model::id::marker::GuildMarker>,
-        Id<twilight_model::id::marker::UserMarker>,
-        Lang,
-    ),
-    anyhow::Error,
-> {
+) -> Result<(Id<twilight_model::id::marker::GuildMarker>, Lang), anyhow::Error> {
     let guild_id = interaction.guild()?.id;
-    let author_id = interaction.author_id().context("missing author_id")?;
     let lang = interaction.locale()?;
-    Ok((guild_id, author_id, lang))
+    Ok((guild_id, lang))
 }
 
 /* Handle Permissions */
-pub async fn get_permissions<'a>(
-    state: &'a ClusterState,
-    guild_id: Id<twilight_model::id::marker::GuildMarker>,
-    author_id: Id<twilight_model::id::marker::UserMarker>,
-    member: twilight_model::application::interaction::application_command::InteractionMember,
-    user: &User,
-) -> Result<
-    (
-        raidprotect_model::cache::permission::CachePermissions<'a>,
-        raidprotect_model::cache::permission::CachePermissions<'a>,
-        raidprotect_model::cache::permission::CachePermissions<'a>,
-    ),
-    anyhow::Error,
-> {
-    let permissions = state.redis().permissions(guild_id).await?;
-    let author_permissions = permissions.member(author_id, &member.roles).await?;
-    let member_permissions = permissions.member(user.id, &member.roles).await?;
-    let bot_permissions = permissions.current_member().await?;
-    Ok((author_permissions, member_permissions, bot_permissions))
+
+/// Permissions of a member used by the moderation checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActorPermissions {
+    /// Whether the member is the guild owner.
+    pub is_owner: bool,
+    /// Highest role of the member.
+    pub highest_role: RoleOrdering,
+    /// Guild-level permissions of the member.
+    pub guild: Permissions,
 }
 
-pub fn check_user_permissions(
-    member_permissions: &raidprotect_model::cache::permission::CachePermissions,
-    lang: Lang,
-    bot_permissions: &raidprotect_model::cache::permission::CachePermissions,
-    command_permissions: Permissions,
-) -> Option<Result<InteractionResponse, anyhow::Error>> {
-    if member_permissions.is_owner() {
-        return Some(Ok(embed::kick::member_owner(lang)));
+impl From<&CachePermissions<'_>> for ActorPermissions {
+    fn from(permissions: &CachePermissions<'_>) -> Self {
+        Self {
+            is_owner: permissions.is_owner(),
+            highest_role: permissions.highest_role(),
+            guild: permissions.guild(),
+        }
     }
-    if !bot_permissions.guild().contains(command_permissions) {
-        return Some(Ok(embed::kick::bot_missing_permission(lang)));
-    }
-    None
 }
 
-pub fn check_command_permissions(
-    member_permissions: raidprotect_model::cache::permission::CachePermissions,
-    author_permissions: raidprotect_model::cache::permission::CachePermissions,
-    lang: Lang,
-    bot_permissions: raidprotect_model::cache::permission::CachePermissions,
-) -> Option<Result<InteractionResponse, anyhow::Error>> {
-    let member_highest_role = member_permissions.highest_role();
-    if member_highest_role >= author_permissions.highest_role() {
-        return Some(Ok(embed::kick::user_hierarchy(lang)));
+/// Permissions of the members involved in a moderation command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModerationPermissions {
+    /// Member that issued the command.
+    pub author: ActorPermissions,
+    /// Member targeted by the command.
+    pub target: ActorPermissions,
+    /// Bot current member.
+    pub bot: ActorPermissions,
+}
+
+impl ModerationPermissions {
+    /// Compute the permissions of the members involved in a command.
+    ///
+    /// The author roles are taken from the interaction member, and the target
+    /// roles from the resolved member of the command data.
+    pub async fn fetch(
+        state: &ClusterState,
+        interaction: &Interaction,
+        target_id: Id<UserMarker>,
+        target_roles: &[Id<RoleMarker>],
+    ) -> Result<Self, anyhow::Error> {
+        let guild = interaction.guild()?;
+        let author_id = interaction.author_id().context("missing author_id")?;
+
+        let permissions = state.redis().permissions(guild.id).await?;
+        let author = permissions.member(author_id, &guild.member.roles).await?;
+        let target = permissions.member(target_id, target_roles).await?;
+        let bot = permissions.current_member().await?;
+
+        Ok(Self {
+            author: ActorPermissions::from(&author),
+            target: ActorPermissions::from(&target),
+            bot: ActorPermissions::from(&bot),
+        })
+    }
+
+    /// Check whether the author and the bot can moderate the target.
+    ///
+    /// The bot must have the `required` permissions. The guild owner cannot be
+    /// moderated, and is not restricted by the role hierarchy.
+    pub fn check(&self, required: Permissions) -> PermissionCheck {
+        if self.target.is_owner {
+            return PermissionCheck::MemberOwner;
+        }
+
+        if !self.bot.guild.contains(required) {
+            return PermissionCheck::BotMissingPermission;
+        }
+
+        if !self.author.is_owner && self.target.highest_role >= self.author.highest_role {
+            return PermissionCheck::UserHierarchy;
+        }
+
+        if self.target.highest_role >= self.bot.highest_role {
+            return PermissionCheck::BotHierarchy;
+        }
+
+        PermissionCheck::Allowed
     }
-    if member_highest_role >= bot_permissions.highest_role() {
-        return Some(Ok(embed::kick::bot_hierarchy(lang)));
+}
+
+/// Outcome of the moderation permission checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionCheck {
+    /// The action is allowed.
+    Allowed,
+    /// The target is the guild owner.
+    MemberOwner,
+    /// The bot is missing the permissions required by the action.
+    BotMissingPermission,
+    /// The target has a role above or equal to the author highest role.
+    UserHierarchy,
+    /// The target has a role above or equal to the bot highest role.
+    BotHierarchy,
+}
+
+impl PermissionCheck {
+    /// Get the response of a failed check for a given command.
+    ///
+    /// Returns [`None`] if the action is allowed.
+    pub fn response(self, kind: ModlogType, lang: Lang) -> Option<InteractionResponse> {
+        let response = match (kind, self) {
+            (_, PermissionCheck::Allowed) => return None,
+            (ModlogType::Kick, PermissionCheck::MemberOwner) => embed::kick::member_owner,
+            (ModlogType::Kick, PermissionCheck::BotMissingPermission) => {
+                embed::kick::bot_missing_permission
+            }
+            (ModlogType::Kick, PermissionCheck::UserHierarchy) => embed::kick::user_hierarchy,
+            (ModlogType::Kick, PermissionCheck::BotHierarchy) => embed::kick::bot_hierarchy,
+            (ModlogType::Ban, PermissionCheck::MemberOwner) => embed::ban::member_owner,
+            (ModlogType::Ban, PermissionCheck::BotMissingPermission) => {
+                embed::ban::bot_missing_permission
+            }
+            (ModlogType::Ban, PermissionCheck::UserHierarchy) => embed::ban::user_hierarchy,
+            (ModlogType::Ban, PermissionCheck::BotHierarchy) => embed::ban::bot_hierarchy,
+            (ModlogType::Mute, PermissionCheck::MemberOwner) => embed::mute::member_owner,
+            (ModlogType::Mute, PermissionCheck::BotMissingPermission) => {
+                embed::mute::bot_missing_permission
+            }
+            (ModlogType::Mute, PermissionCheck::UserHierarchy) => embed::mute::user_hierarchy,
+            (ModlogType::Mute, PermissionCheck::BotHierarchy) => embed::mute::bot_hierarchy,
+            (ModlogType::Warn, PermissionCheck::MemberOwner) => embed::warn::member_owner,
+            (ModlogType::Warn, PermissionCheck::BotMissingPermission) => {
+                embed::warn::bot_missing_permission
+            }
+            (ModlogType::Warn, PermissionCheck::UserHierarchy) => embed::warn::user_hierarchy,
+            (ModlogType::Warn, PermissionCheck::BotHierarchy) => embed::warn::bot_hierarchy,
+        };
+
+        Some(response(lang))
     }
-    None
 }
 
 /* Send Messages */
@@ -112,3 +197,125 @@ pub async fn get_modal_requirements(
     Ok(required)
 }
 pub fn _perform_command() {}
+
+#[cfg(test)]
+mod tests {
+    use raidprotect_model::cache::model::CachedRole;
+
+    use super::*;
+
+    fn actor(is_owner: bool, position: i64, guild: Permissions) -> ActorPermissions {
+        let role = CachedRole {
+            id: Id::new(position as u64 + 1),
+            guild_id: Id::new(1),
+            name: String::new(),
+            color: 0,
+            icon: None,
+            unicode_emoji: None,
+            position,
+            permissions: guild,
+            managed: false,
+        };
+
+        ActorPermissions {
+            is_owner,
+            highest_role: RoleOrdering::from(&role),
+            guild,
+        }
+    }
+
+    fn permissions(
+        author: ActorPermissions,
+        target: ActorPermissions,
+        bot: ActorPermissions,
+    ) -> ModerationPermissions {
+        ModerationPermissions {
+            author,
+            target,
+            bot,
+        }
+    }
+
+    #[test]
+    fn test_check_allowed() {
+        let check = permissions(
+            actor(false, 5, Permissions::KICK_MEMBERS),
+            actor(false, 1, Permissions::empty()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::KICK_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::Allowed);
+    }
+
+    #[test]
+    fn test_check_member_owner() {
+        let check = permissions(
+            actor(false, 5, Permissions::KICK_MEMBERS),
+            actor(true, 1, Permissions::all()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::KICK_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::MemberOwner);
+    }
+
+    #[test]
+    fn test_check_bot_missing_permission() {
+        let check = permissions(
+            actor(false, 5, Permissions::BAN_MEMBERS),
+            actor(false, 1, Permissions::empty()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::BAN_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::BotMissingPermission);
+    }
+
+    #[test]
+    fn test_check_user_hierarchy() {
+        // Target with a role above the author.
+        let check = permissions(
+            actor(false, 5, Permissions::KICK_MEMBERS),
+            actor(false, 7, Permissions::empty()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::KICK_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::UserHierarchy);
+
+        // Target with the same highest role as the author.
+        let check = permissions(
+            actor(false, 5, Permissions::KICK_MEMBERS),
+            actor(false, 5, Permissions::empty()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::KICK_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::UserHierarchy);
+    }
+
+    #[test]
+    fn test_check_owner_ignores_hierarchy() {
+        let check = permissions(
+            actor(true, 1, Permissions::all()),
+            actor(false, 7, Permissions::empty()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::KICK_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::Allowed);
+    }
+
+    #[test]
+    fn test_check_bot_hierarchy() {
+        let check = permissions(
+            actor(true, 1, Permissions::all()),
+            actor(false, 12, Permissions::empty()),
+            actor(false, 10, Permissions::KICK_MEMBERS),
+        )
+        .check(Permissions::KICK_MEMBERS);
+
+        assert_eq!(check, PermissionCheck::BotHierarchy);
+    }
+}
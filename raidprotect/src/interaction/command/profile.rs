@@ -1,18 +1,24 @@
 //! Profile command.
 //!
-//! This command shows basic information about a given user.
+//! This command shows basic information about a given user. The risk score of
+//! the member is also shown to moderators.
 
 use std::time::Duration;
 
 use anyhow::Context;
+use raidprotect_model::cache::model::risk::MemberRisk;
 use twilight_interactions::command::{CommandModel, CreateCommand, ResolvedUser};
 use twilight_mention::{
     timestamp::{Timestamp, TimestampStyle},
     Mention,
 };
-use twilight_model::application::{
-    component::{button::ButtonStyle, ActionRow, Button, Component},
-    interaction::Interaction,
+use twilight_model::{
+    application::{
+        component::{button::ButtonStyle, ActionRow, Button, Component},
+        interaction::Interaction,
+    },
+    guild::Permissions,
+    id::{marker::UserMarker, Id},
 };
 use twilight_util::{
     builder::{
@@ -24,7 +30,9 @@ use twilight_util::{
 
 use crate::{
     cluster::ClusterState,
-    desc_localizations, impl_command_handle,
+    desc_localizations,
+    feature::risk::format_signals,
+    impl_command_handle,
     interaction::{
         component::PostInChat, embed::COLOR_TRANSPARENT, response::InteractionResponse,
         util::InteractionExt,
@@ -89,6 +97,21 @@ impl ProfileCommand {
             ));
         }
 
+        let public_embed = embed.clone().validate()?.build();
+
+        // Risk score, only shown to moderators and never posted in the
+        // channel.
+        if let Some(risk) = member_risk(&interaction, user.id, state).await? {
+            embed = embed.field(EmbedFieldBuilder::new(
+                lang.risk_score(),
+                format!(
+                    "{} ({})",
+                    lang.risk_score_value(MemberRisk::MAX_SCORE, risk.score),
+                    format_signals(&risk, lang)
+                ),
+            ));
+        }
+
         let components = Component::ActionRow(ActionRow {
             components: vec![Component::Button(Button {
                 custom_id: None,
@@ -102,10 +125,40 @@ impl ProfileCommand {
 
         let response = InteractionResponseDataBuilder::new()
             .embeds([embed.validate()?.build()])
+            .components([components.clone()])
+            .build();
+        let public = InteractionResponseDataBuilder::new()
+            .embeds([public_embed])
             .components([components])
             .build();
         let author_id = interaction.author_id().context("missing author id")?;
 
-        PostInChat::create(response, interaction.id, author_id, state, lang).await
+        PostInChat::create(response, public, interaction.id, author_id, state, lang).await
     }
 }
+
+/// Get the risk score of a member.
+///
+/// The risk score is only returned if the command is used in a guild by a
+/// member with the `KICK_MEMBERS` permission.
+async fn member_risk(
+    interaction: &Interaction,
+    user_id: Id<UserMarker>,
+    state: &ClusterState,
+) -> Result<Option<MemberRisk>, anyhow::Error> {
+    let guild = match interaction.guild() {
+        Ok(guild) => guild,
+        Err(_) => return Ok(None),
+    };
+
+    let is_moderator = matches!(
+        guild.member.permissions,
+        Some(permissions) if permissions.contains(Permissions::KICK_MEMBERS)
+    );
+
+    if !is_moderator {
+        return Ok(None);
+    }
+
+    state.redis().get::<MemberRisk>(&(guild.id, user_id)).await
+}
@@ -0,0 +1,248 @@
+//! Quarantine commands.
+//!
+//! The `/quarantine` command removes the roles of a member and gives the
+//! quarantine role, which is hidden from all the channels of the guild. This
+//! allows to contain a suspected compromised account without losing its roles,
+//! which are restored exactly with `/unquarantine`.
+//!
+//! The quarantine role must be configured with `/config quarantine setup`.
+
+use twilight_interactions::command::{CommandModel, CreateCommand, ResolvedUser};
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::embed::Embed,
+    guild::Permissions,
+    id::{
+        marker::{RoleMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::quarantine::{quarantine, unquarantine},
+    impl_command_handle,
+    interaction::{
+        command::moderation::{ModerationPermissions, PermissionCheck},
+        embed::{self, COLOR_GREEN, COLOR_RED},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+    translations::Lang,
+    util::{send_log, TextProcessExt},
+};
+
+/// Quarantine command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "quarantine",
+    desc = "Quarantine a member by removing its roles",
+    desc_localizations = "quarantine_description",
+    default_permissions = "QuarantineCommand::default_permissions",
+    dm_permission = false
+)]
+pub struct QuarantineCommand {
+    /// Member to quarantine.
+    #[command(rename = "member")]
+    pub user: ResolvedUser,
+    /// Reason for the quarantine.
+    pub reason: Option<String>,
+}
+
+impl_command_handle!(QuarantineCommand);
+desc_localizations!(quarantine_description);
+
+impl QuarantineCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::MANAGE_ROLES
+    }
+
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild = interaction.guild()?;
+        let user = self.user.resolved;
+        let member = match self.user.member {
+            Some(member) => member,
+            None => return Ok(embed::quarantine::not_member(user.name, lang)),
+        };
+
+        let config = state.mongodb().get_guild_or_create(guild.id).await?;
+
+        if config.quarantine.role.is_none() {
+            return Ok(embed::quarantine::role_missing(lang));
+        }
+
+        if let Some(response) =
+            check_permissions(&interaction, user.id, &member.roles, state, lang).await?
+        {
+            return Ok(response);
+        }
+
+        let guild_lang = Lang::from(&*config.lang);
+        let reason = audit_reason(self.reason.as_deref(), guild_lang);
+        let moderator_id = guild.member.user.as_ref().map(|user| user.id);
+
+        if !quarantine(
+            guild.id,
+            user.id,
+            &member.roles,
+            moderator_id,
+            &reason,
+            &config,
+            state,
+        )
+        .await?
+        {
+            return Ok(embed::quarantine::already_quarantined(lang));
+        }
+
+        let log = EmbedBuilder::new()
+            .color(COLOR_RED)
+            .title(guild_lang.quarantine_log_title())
+            .description(
+                guild_lang
+                    .quarantine_log_description(moderator_mention(moderator_id), user.id.mention()),
+            );
+        send_log(state, &config, with_reason(log, self.reason, guild_lang)).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .description(lang.quarantine_confirm(user.name.remove_markdown().truncate(30)))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Unquarantine command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "unquarantine",
+    desc = "Release a member from quarantine and restore its roles",
+    desc_localizations = "unquarantine_description",
+    default_permissions = "UnquarantineCommand::default_permissions",
+    dm_permission = false
+)]
+pub struct UnquarantineCommand {
+    /// Member to release from quarantine.
+    #[command(rename = "member")]
+    pub user: ResolvedUser,
+    /// Reason for the release.
+    pub reason: Option<String>,
+}
+
+impl_command_handle!(UnquarantineCommand);
+desc_localizations!(unquarantine_description);
+
+impl UnquarantineCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::MANAGE_ROLES
+    }
+
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild = interaction.guild()?;
+        let user = self.user.resolved;
+        let member = match self.user.member {
+            Some(member) => member,
+            None => return Ok(embed::quarantine::not_member(user.name, lang)),
+        };
+
+        if let Some(response) =
+            check_permissions(&interaction, user.id, &member.roles, state, lang).await?
+        {
+            return Ok(response);
+        }
+
+        let config = state.mongodb().get_guild_or_create(guild.id).await?;
+        let guild_lang = Lang::from(&*config.lang);
+        let reason = audit_reason(self.reason.as_deref(), guild_lang);
+
+        if !unquarantine(guild.id, user.id, &member.roles, &reason, &config, state).await? {
+            return Ok(embed::quarantine::not_quarantined(lang));
+        }
+
+        let moderator_id = guild.member.user.as_ref().map(|user| user.id);
+        let log =
+            EmbedBuilder::new()
+                .color(COLOR_GREEN)
+                .title(guild_lang.unquarantine_log_title())
+                .description(guild_lang.unquarantine_log_description(
+                    moderator_mention(moderator_id),
+                    user.id.mention(),
+                ));
+        send_log(state, &config, with_reason(log, self.reason, guild_lang)).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .description(lang.unquarantine_confirm(user.name.remove_markdown().truncate(30)))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
+
+/// Ensure the author and the bot are allowed to update the roles of a member.
+async fn check_permissions(
+    interaction: &Interaction,
+    user_id: Id<UserMarker>,
+    user_roles: &[Id<RoleMarker>],
+    state: &ClusterState,
+    lang: Lang,
+) -> Result<Option<InteractionResponse>, anyhow::Error> {
+    let permissions = ModerationPermissions::fetch(state, interaction, user_id, user_roles).await?;
+
+    let response = match permissions.check(Permissions::MANAGE_ROLES) {
+        PermissionCheck::Allowed => return Ok(None),
+        PermissionCheck::MemberOwner => embed::quarantine::member_owner(lang),
+        PermissionCheck::BotMissingPermission => embed::quarantine::bot_missing_permission(lang),
+        PermissionCheck::UserHierarchy => embed::quarantine::user_hierarchy(lang),
+        PermissionCheck::BotHierarchy => embed::quarantine::bot_hierarchy(lang),
+    };
+
+    Ok(Some(response))
+}
+
+/// Get the audit log reason of a quarantine command.
+fn audit_reason(reason: Option<&str>, lang: Lang) -> String {
+    match reason {
+        Some(reason) => lang.quarantine_reason_custom(reason),
+        None => lang.quarantine_reason().to_string(),
+    }
+}
+
+/// Mention the moderator that issued a command.
+fn moderator_mention(moderator_id: Option<Id<UserMarker>>) -> String {
+    moderator_id
+        .map(|id| id.mention().to_string())
+        .unwrap_or_default()
+}
+
+/// Add the reason of the command to a log entry.
+fn with_reason(embed: EmbedBuilder, reason: Option<String>, lang: Lang) -> Embed {
+    let mut embed = embed;
+
+    if let Some(reason) = reason {
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.quarantine_log_reason(),
+            reason.truncate(1024),
+        ));
+    }
+
+    embed.build()
+}
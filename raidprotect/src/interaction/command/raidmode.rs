@@ -0,0 +1,267 @@
+//! Raid mode command.
+//!
+//! This command allows moderators to manually enable or disable the raid mode,
+//! and to show its current status. While raid mode is enabled, members joining
+//! the server are removed with the action configured with `/config raid`.
+//!
+//! Raid mode is stored in the cache, so it is shared by all the shards and kept
+//! after a restart. Enabling or disabling raid mode is logged in the logs
+//! channel of the guild.
+
+use anyhow::Context;
+use raidprotect_model::{cache::model::raid::RaidMode, database::model::RaidConfig};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_mention::Mention;
+use twilight_model::{application::interaction::Interaction, guild::Permissions};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    cluster::ClusterState,
+    desc_localizations,
+    feature::raid::{action_name, action_participle},
+    impl_command_handle,
+    interaction::{
+        embed::{COLOR_GREEN, COLOR_RED, COLOR_TRANSPARENT},
+        response::InteractionResponse,
+        util::InteractionExt,
+    },
+    translations::Lang,
+    util::{relative_timestamp, send_log},
+};
+
+/// Raid mode command model.
+///
+/// See the [`module`][self] documentation for more information.
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "raidmode",
+    desc = "Manage the raid mode of the server",
+    desc_localizations = "raidmode_description",
+    default_permissions = "RaidModeCommand::default_permissions",
+    dm_permission = false
+)]
+pub enum RaidModeCommand {
+    #[command(name = "on")]
+    On(RaidModeOnCommand),
+    #[command(name = "off")]
+    Off(RaidModeOffCommand),
+    #[command(name = "status")]
+    Status(RaidModeStatusCommand),
+}
+
+impl_command_handle!(RaidModeCommand);
+desc_localizations!(raidmode_description);
+
+impl RaidModeCommand {
+    fn default_permissions() -> Permissions {
+        Permissions::MANAGE_GUILD
+    }
+
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        match self {
+            Self::On(command) => command.exec(interaction, state).await,
+            Self::Off(command) => command.exec(interaction, state).await,
+            Self::Status(command) => command.exec(interaction, state).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "on",
+    desc = "Enable the raid mode",
+    desc_localizations = "raidmode_on_description"
+)]
+pub struct RaidModeOnCommand {
+    /// Duration of raid mode, in minutes. If not set, raid mode stays enabled until disabled.
+    #[command(min_value = 1, max_value = 1440)]
+    duration: Option<i64>,
+}
+
+desc_localizations!(raidmode_on_description);
+
+impl RaidModeOnCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let author_id = interaction.author_id().context("missing author_id")?;
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let now = OffsetDateTime::now_utc();
+        let expires_at = self.duration.map(|duration| {
+            let seconds = (duration * 60).clamp(60, RaidConfig::MAX_DURATION.into());
+
+            now + Duration::seconds(seconds)
+        });
+
+        let raid_mode = RaidMode {
+            guild_id,
+            enabled_at: now,
+            expires_at,
+            enabled_by: Some(author_id),
+        };
+
+        state.redis().set_raid_mode(&raid_mode).await?;
+
+        let action = action_participle(config.raid.action, lang);
+        let description = match expires_at {
+            Some(expires_at) => lang.raidmode_enabled_until(action, relative_timestamp(expires_at)),
+            None => lang.raidmode_enabled_description(action),
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_RED)
+            .title(lang.raidmode_enabled_title())
+            .description(description)
+            .build();
+
+        // Logs are sent in the guild language.
+        let logs_lang = Lang::from(&*config.lang);
+        let log_embed = EmbedBuilder::new()
+            .color(COLOR_RED)
+            .title(logs_lang.raidmode_log_title())
+            .description(logs_lang.raidmode_log_enabled(author_id.mention()))
+            .field(EmbedFieldBuilder::new(
+                logs_lang.raidmode_status_expires_at(),
+                expiration(expires_at, logs_lang),
+            ))
+            .build();
+
+        send_log(state, &config, log_embed).await?;
+
+        Ok(InteractionResponse::Embed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "off",
+    desc = "Disable the raid mode",
+    desc_localizations = "raidmode_off_description"
+)]
+pub struct RaidModeOffCommand;
+
+desc_localizations!(raidmode_off_description);
+
+impl RaidModeOffCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+        let author_id = interaction.author_id().context("missing author_id")?;
+
+        if !state.redis().disable_raid_mode(guild_id).await? {
+            let embed = EmbedBuilder::new()
+                .color(COLOR_RED)
+                .description(lang.raidmode_not_enabled())
+                .build();
+
+            return Ok(InteractionResponse::EphemeralEmbed(embed));
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.raidmode_disabled_title())
+            .description(lang.raidmode_disabled_description())
+            .build();
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let logs_lang = Lang::from(&*config.lang);
+        let log_embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(logs_lang.raidmode_log_title())
+            .description(logs_lang.raidmode_log_disabled(author_id.mention()))
+            .build();
+
+        send_log(state, &config, log_embed).await?;
+
+        Ok(InteractionResponse::Embed(embed))
+    }
+}
+
+#[derive(Debug, Clone, CommandModel, CreateCommand)]
+#[command(
+    name = "status",
+    desc = "Show the raid mode status",
+    desc_localizations = "raidmode_status_description"
+)]
+pub struct RaidModeStatusCommand;
+
+desc_localizations!(raidmode_status_description);
+
+impl RaidModeStatusCommand {
+    async fn exec(
+        self,
+        interaction: Interaction,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let guild_id = interaction.guild()?.id;
+
+        let config = state.mongodb().get_guild_or_create(guild_id).await?;
+        let raid_mode = state.redis().get::<RaidMode>(&guild_id).await?;
+
+        let mut embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.raidmode_status_title());
+
+        match raid_mode {
+            Some(raid_mode) => {
+                let enabled_by = match raid_mode.enabled_by {
+                    Some(user_id) => user_id.mention().to_string(),
+                    None => lang.raidmode_status_automatic().to_string(),
+                };
+
+                embed = embed
+                    .color(COLOR_RED)
+                    .description(lang.raidmode_status_enabled())
+                    .field(
+                        EmbedFieldBuilder::new(
+                            lang.raidmode_status_enabled_at(),
+                            relative_timestamp(raid_mode.enabled_at),
+                        )
+                        .inline(),
+                    )
+                    .field(
+                        EmbedFieldBuilder::new(
+                            lang.raidmode_status_expires_at(),
+                            expiration(raid_mode.expires_at, lang),
+                        )
+                        .inline(),
+                    )
+                    .field(
+                        EmbedFieldBuilder::new(lang.raidmode_status_enabled_by(), enabled_by)
+                            .inline(),
+                    );
+            }
+            None => embed = embed.description(lang.raidmode_status_disabled()),
+        }
+
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.raidmode_status_action(),
+            action_name(config.raid.action, lang),
+        ));
+
+        Ok(InteractionResponse::EphemeralEmbed(embed.build()))
+    }
+}
+
+/// Format the expiration time of raid mode.
+fn expiration(expires_at: Option<OffsetDateTime>, lang: Lang) -> String {
+    match expires_at {
+        Some(expires_at) => relative_timestamp(expires_at),
+        None => lang.raidmode_status_never().to_string(),
+    }
+}
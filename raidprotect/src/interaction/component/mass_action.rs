@@ -0,0 +1,270 @@
+//! Mass action confirmation buttons.
+//!
+//! These buttons are sent by the `/massban`, `/masskick` and `/cleanup names`
+//! commands. The targeted members are stored in a [`PendingMassAction`] until
+//! the moderator confirms or cancels the action.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use raidprotect_model::{
+    cache::model::interaction::{MassActionKind, PendingMassAction},
+    database::model::{GuildConfig, ModlogType},
+};
+use tracing::error;
+use twilight_mention::Mention;
+use twilight_model::{
+    application::{
+        component::{button::ButtonStyle, ActionRow, Button, Component},
+        interaction::Interaction,
+    },
+    channel::embed::Embed,
+    http::interaction::InteractionResponseType,
+    id::{marker::InteractionMarker, Id},
+    user::User,
+};
+use twilight_util::builder::{
+    embed::{EmbedBuilder, EmbedFieldBuilder},
+    InteractionResponseDataBuilder,
+};
+
+use crate::{
+    cluster::ClusterState,
+    feature::{
+        mass_action::{action_name, execute},
+        names,
+    },
+    interaction::{
+        embed::{self, COLOR_GREEN, COLOR_TRANSPARENT},
+        response::InteractionResponse,
+        util::{CustomId, InteractionExt},
+    },
+    translations::Lang,
+    util::guild_logs_channel,
+};
+
+/// Mass action confirmation button.
+///
+/// The action is performed in background, and a summary is sent in the logs
+/// channel once all the members are processed.
+pub struct MassActionConfirm;
+
+impl MassActionConfirm {
+    pub async fn handle(
+        interaction: Interaction,
+        custom_id: CustomId,
+        state: Arc<ClusterState>,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+        let pending = match get_pending(&interaction, custom_id, &state).await? {
+            Some(pending) => pending,
+            None => return Ok(embed::error::expired_interaction(lang)),
+        };
+
+        let moderator = interaction
+            .guild()?
+            .member
+            .user
+            .clone()
+            .context("missing interaction member user")?;
+        let config = state
+            .mongodb()
+            .get_guild_or_create(pending.guild_id)
+            .await?;
+
+        let name = match pending.kind {
+            MassActionKind::Sanction(kind) => action_name(kind, lang),
+            MassActionKind::Names => names::action_name(config.names.action, lang),
+        };
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.mass_action_started_title(name))
+            .description(lang.mass_action_started_description(pending.members.len()))
+            .build();
+
+        tokio::spawn(async move {
+            let result = match pending.kind {
+                MassActionKind::Sanction(kind) => {
+                    mass_action(pending, kind, moderator, config, state).await
+                }
+                MassActionKind::Names => names_cleanup(pending, config, state).await,
+            };
+
+            if let Err(error) = result {
+                error!(error = ?error, "failed to perform mass action");
+            }
+        });
+
+        Ok(update_message(embed))
+    }
+}
+
+/// Mass action cancel button.
+pub struct MassActionCancel;
+
+impl MassActionCancel {
+    pub async fn handle(
+        interaction: Interaction,
+        custom_id: CustomId,
+        state: &ClusterState,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let lang = interaction.locale()?;
+
+        if get_pending(&interaction, custom_id, state).await?.is_none() {
+            return Ok(embed::error::expired_interaction(lang));
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_TRANSPARENT)
+            .title(lang.mass_action_cancelled())
+            .build();
+
+        Ok(update_message(embed))
+    }
+}
+
+/// Confirmation and cancel buttons of a mass action.
+///
+/// The [`PendingMassAction`] must be stored with the same interaction id.
+pub fn mass_action_buttons(interaction_id: Id<InteractionMarker>, lang: Lang) -> Component {
+    Component::ActionRow(ActionRow {
+        components: vec![
+            Component::Button(Button {
+                custom_id: Some(
+                    CustomId::new("mass-action-confirm", interaction_id.to_string()).to_string(),
+                ),
+                disabled: false,
+                emoji: None,
+                label: Some(lang.mass_action_confirm_button().to_string()),
+                style: ButtonStyle::Danger,
+                url: None,
+            }),
+            Component::Button(Button {
+                custom_id: Some(
+                    CustomId::new("mass-action-cancel", interaction_id.to_string()).to_string(),
+                ),
+                disabled: false,
+                emoji: None,
+                label: Some(lang.mass_action_cancel_button().to_string()),
+                style: ButtonStyle::Secondary,
+                url: None,
+            }),
+        ],
+    })
+}
+
+/// Get and remove the pending mass action of a button.
+///
+/// Returns [`None`] if the action has expired, has already been handled or
+/// if the button was not clicked by the moderator that issued the command.
+async fn get_pending(
+    interaction: &Interaction,
+    custom_id: CustomId,
+    state: &ClusterState,
+) -> Result<Option<PendingMassAction>, anyhow::Error> {
+    let id = custom_id
+        .id
+        .ok_or_else(|| anyhow!("missing component id in custom_id"))?;
+    let pending = match state.redis().get::<PendingMassAction>(&id).await? {
+        Some(pending) => pending,
+        None => return Ok(None),
+    };
+
+    if interaction.author_id() != Some(pending.moderator_id)
+        || !state.redis().delete::<PendingMassAction>(&id).await?
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(pending))
+}
+
+/// Replace the confirmation message with an embed.
+fn update_message(embed: Embed) -> InteractionResponse {
+    let response = InteractionResponseDataBuilder::new()
+        .embeds([embed])
+        .components([])
+        .build();
+
+    InteractionResponse::Raw {
+        kind: InteractionResponseType::UpdateMessage,
+        data: Some(response),
+    }
+}
+
+/// Perform the mass action and send a summary in the logs channel.
+async fn mass_action(
+    pending: PendingMassAction,
+    kind: ModlogType,
+    moderator: User,
+    config: GuildConfig,
+    state: Arc<ClusterState>,
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let done = execute(&pending, kind, &moderator, &config, &state).await;
+
+    let mut embed = EmbedBuilder::new()
+        .color(COLOR_GREEN)
+        .title(lang.mass_action_log_title(action_name(kind, lang)))
+        .description(lang.mass_action_log_description(
+            done,
+            moderator.id.mention(),
+            pending.members.len(),
+        ));
+
+    if let Some(reason) = &pending.reason {
+        embed = embed.field(EmbedFieldBuilder::new(
+            lang.mass_action_log_reason(),
+            reason,
+        ));
+    }
+
+    let logs_channel = guild_logs_channel(&state, config.id, config.logs_chan, lang).await?;
+
+    state
+        .cache_http(config.id)
+        .create_message(logs_channel)
+        .await?
+        .embeds(&[embed.build()])?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+/// Apply the name rules action and send a summary in the logs channel.
+async fn names_cleanup(
+    pending: PendingMassAction,
+    config: GuildConfig,
+    state: Arc<ClusterState>,
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let total = pending.members.len();
+    let done = names::cleanup(&pending.members, &config, &state).await?;
+
+    let embed = EmbedBuilder::new()
+        .color(COLOR_GREEN)
+        .title(lang.cleanup_names_log_title())
+        .description(lang.cleanup_names_log_description(
+            pending.moderator_id.mention(),
+            done,
+            total,
+        ))
+        .field(EmbedFieldBuilder::new(
+            lang.names_log_action(),
+            names::action_name(config.names.action, lang),
+        ))
+        .build();
+
+    let logs_channel = guild_logs_channel(&state, config.id, config.logs_chan, lang).await?;
+
+    state
+        .cache_http(config.id)
+        .create_message(logs_channel)
+        .await?
+        .embeds(&[embed])?
+        .exec()
+        .await?;
+
+    Ok(())
+}
@@ -1,6 +1,10 @@
 //! Component interactions handling.
 
 pub mod captcha;
+mod mass_action;
 mod post_in_chat;
+mod quarantine;
 
+pub use mass_action::{mass_action_buttons, MassActionCancel, MassActionConfirm};
 pub use post_in_chat::PostInChat;
+pub use quarantine::QuarantineSetup;
@@ -33,8 +33,13 @@ pub struct PostInChat;
 
 impl PostInChat {
     /// Create a new [`PostInChat`] component.
+    ///
+    /// The `public` response is posted in the channel instead of the
+    /// ephemeral `response`, to avoid publishing information only intended
+    /// for the author.
     pub async fn create(
         mut response: InteractionResponseData,
+        public: InteractionResponseData,
         interaction_id: Id<InteractionMarker>,
         author_id: Id<UserMarker>,
         state: &ClusterState,
@@ -42,7 +47,7 @@ impl PostInChat {
     ) -> Result<InteractionResponse, anyhow::Error> {
         // Store button state in redis
         let component = PostInChatButton {
-            response: response.clone(),
+            response: public,
             interaction_id,
             author_id,
         };
@@ -0,0 +1,103 @@
+//! Quarantine setup button.
+
+use std::{str::FromStr, sync::Arc};
+
+use tracing::error;
+use twilight_http::request::AuditLogReason;
+use twilight_mention::Mention;
+use twilight_model::{
+    application::interaction::Interaction,
+    guild::Permissions,
+    id::{marker::RoleMarker, Id},
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    cluster::ClusterState,
+    interaction::{
+        embed::{self, COLOR_GREEN},
+        response::InteractionResponse,
+        util::{CustomId, InteractionExt},
+    },
+    translations::Lang,
+    util::hide_channels,
+};
+
+/// Quarantine setup button.
+///
+/// This type handle the button used to configure the quarantine role (sent by
+/// the `/config quarantine setup` command). The component id contains the
+/// selected role, or is empty if a new role must be created.
+///
+/// The role is saved in the configuration, and each channel in the guild is
+/// configured to be hidden to the role (see [`hide_channels`]).
+pub struct QuarantineSetup;
+
+impl QuarantineSetup {
+    pub async fn handle(
+        interaction: Interaction,
+        custom_id: CustomId,
+        state: Arc<ClusterState>,
+    ) -> Result<InteractionResponse, anyhow::Error> {
+        let guild = interaction.guild()?;
+        let mut config = state.mongodb().get_guild_or_create(guild.id).await?;
+
+        let lang = interaction.locale()?;
+        let guild_lang = Lang::from(&*config.lang);
+
+        // Ensure the bot still has the required permissions.
+        let permissions = state
+            .redis()
+            .permissions(guild.id)
+            .await?
+            .current_member()
+            .await?;
+
+        if !permissions
+            .guild()
+            .contains(Permissions::MANAGE_CHANNELS | Permissions::MANAGE_ROLES)
+        {
+            return Ok(embed::quarantine::missing_setup_permission(lang));
+        }
+
+        let role = match custom_id.id {
+            Some(id) => Id::<RoleMarker>::from_str(&id)?,
+            None => match state
+                .cache_http(guild.id)
+                .create_role()
+                .await?
+                .name(guild_lang.quarantine_role_name())
+                .color(0x99AAB5) // Default grey color
+                .permissions(Permissions::empty())
+                .reason(guild_lang.quarantine_setup_reason())?
+                .exec()
+                .await
+            {
+                Ok(response) => response.model().await?.id,
+                Err(err) => {
+                    error!(error = ?err, "failed to create the quarantine role");
+
+                    return Ok(embed::quarantine::role_error(lang));
+                }
+            },
+        };
+
+        config.quarantine.role = Some(role);
+        state.mongodb().update_guild(&config).await?;
+
+        // Start the configuration of channels permissions.
+        tokio::spawn(async move {
+            if let Err(error) = hide_channels(&state, guild.id, role, None).await {
+                error!(error = ?error, guild = ?guild.id, "failed to configure quarantine channels permissions");
+            }
+        });
+
+        let embed = EmbedBuilder::new()
+            .color(COLOR_GREEN)
+            .title(lang.config_updated_title())
+            .description(lang.quarantine_setup_done(role.mention()))
+            .build();
+
+        Ok(InteractionResponse::EphemeralEmbed(embed))
+    }
+}
@@ -0,0 +1,28 @@
+//! Embeds for the backup command.
+
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::COLOR_RED;
+use crate::{interaction::response::InteractionResponse, translations::Lang};
+
+/// No backup has been created for the guild.
+pub fn no_backups(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.backup_error_title())
+        .description(lang.backup_no_backups())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// The requested backup does not exist.
+pub fn not_found(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.backup_error_title())
+        .description(lang.backup_not_found())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
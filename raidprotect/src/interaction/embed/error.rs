@@ -1,5 +1,8 @@
 //! Error embeds.
 
+use raidprotect_model::cache::error::CacheHttpError;
+use twilight_mention::Mention;
+use twilight_model::guild::Permissions;
 use twilight_util::builder::embed::{EmbedBuilder, EmbedFooterBuilder};
 
 use super::COLOR_RED;
@@ -40,6 +43,109 @@ pub fn expired_interaction(lang: Lang) -> InteractionResponse {
     InteractionResponse::EphemeralEmbed(embed)
 }
 
+/// Error returned by a [`CacheHttp`] permission check.
+///
+/// Returns [`None`] if the error is not caused by the bot permissions or the
+/// cache, in which case an internal error should be shown.
+///
+/// [`CacheHttp`]: raidprotect_model::cache::http::CacheHttp
+pub fn cache_http(error: &CacheHttpError, lang: Lang) -> Option<InteractionResponse> {
+    let embed = match error {
+        CacheHttpError::MissingPermissions {
+            missing, channel, ..
+        } => {
+            let permissions = permission_list(*missing, lang);
+            let description = match channel {
+                Some(channel) => {
+                    lang.cache_http_missing_permissions_channel(channel.mention(), permissions)
+                }
+                None => lang.cache_http_missing_permissions_guild(permissions),
+            };
+
+            EmbedBuilder::new()
+                .title(lang.cache_http_missing_permissions_title())
+                .color(COLOR_RED)
+                .description(description)
+        }
+        CacheHttpError::RoleHierarchy { role } => EmbedBuilder::new()
+            .title(lang.cache_http_role_hierarchy_title())
+            .color(COLOR_RED)
+            .description(lang.cache_http_role_hierarchy_description(role.mention())),
+        CacheHttpError::MemberHierarchy { user } => EmbedBuilder::new()
+            .title(lang.cache_http_member_hierarchy_title())
+            .color(COLOR_RED)
+            .description(lang.cache_http_member_hierarchy_description(user.mention())),
+        CacheHttpError::CacheMiss(_) => EmbedBuilder::new()
+            .title(lang.cache_http_cache_miss_title())
+            .color(COLOR_RED)
+            .description(lang.cache_http_cache_miss_description()),
+        CacheHttpError::Other(_) => return None,
+    };
+
+    Some(InteractionResponse::EphemeralEmbed(embed.build()))
+}
+
+/// Format a list of permissions with their localized names.
+fn permission_list(permissions: Permissions, lang: Lang) -> String {
+    let names = [
+        (Permissions::VIEW_CHANNEL, lang.permission_view_channel()),
+        (Permissions::SEND_MESSAGES, lang.permission_send_messages()),
+        (
+            Permissions::SEND_MESSAGES_IN_THREADS,
+            lang.permission_send_messages_in_threads(),
+        ),
+        (Permissions::EMBED_LINKS, lang.permission_embed_links()),
+        (
+            Permissions::USE_EXTERNAL_EMOJIS,
+            lang.permission_use_external_emojis(),
+        ),
+        (
+            Permissions::MANAGE_MESSAGES,
+            lang.permission_manage_messages(),
+        ),
+        (
+            Permissions::MANAGE_CHANNELS,
+            lang.permission_manage_channels(),
+        ),
+        (Permissions::MANAGE_ROLES, lang.permission_manage_roles()),
+        (
+            Permissions::MANAGE_NICKNAMES,
+            lang.permission_manage_nicknames(),
+        ),
+        (
+            Permissions::MANAGE_WEBHOOKS,
+            lang.permission_manage_webhooks(),
+        ),
+        (
+            Permissions::MODERATE_MEMBERS,
+            lang.permission_moderate_members(),
+        ),
+        (
+            Permissions::VIEW_AUDIT_LOG,
+            lang.permission_view_audit_log(),
+        ),
+        (Permissions::KICK_MEMBERS, lang.permission_kick_members()),
+        (Permissions::BAN_MEMBERS, lang.permission_ban_members()),
+    ];
+
+    let mut remaining = permissions;
+    let mut list = Vec::new();
+
+    for (permission, name) in names {
+        if permissions.contains(permission) {
+            list.push(format!("• **{name}**"));
+            remaining.remove(permission);
+        }
+    }
+
+    // Permissions without translation are displayed with their raw name.
+    if !remaining.is_empty() {
+        list.push(format!("• **{remaining:?}**"));
+    }
+
+    list.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +164,25 @@ mod tests {
     fn test_expired_component() {
         expired_interaction(Lang::DEFAULT);
     }
+
+    #[test]
+    fn test_cache_http() {
+        let error = CacheHttpError::missing_permissions(
+            Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS,
+            Permissions::SEND_MESSAGES,
+            None,
+        );
+
+        assert!(cache_http(&error, Lang::DEFAULT).is_some());
+    }
+
+    #[test]
+    fn test_permission_list() {
+        let list = permission_list(
+            Permissions::KICK_MEMBERS | Permissions::BAN_MEMBERS,
+            Lang::En,
+        );
+
+        assert_eq!(list, "• **Kick Members**\n• **Ban Members**");
+    }
 }
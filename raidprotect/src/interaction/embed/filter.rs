@@ -0,0 +1,50 @@
+//! Embeds for the filter configuration commands.
+
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::COLOR_RED;
+use crate::{interaction::response::InteractionResponse, translations::Lang, util::TextProcessExt};
+
+/// Maximum number of regex filters reached.
+pub fn too_many_filters(max: usize, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.filter_error_title())
+        .description(lang.filter_regex_too_many(max))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Filter name is too long.
+pub fn name_too_long(max: usize, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.filter_error_title())
+        .description(lang.filter_regex_name_too_long(max))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Regex pattern is invalid or too large.
+pub fn invalid_pattern(error: String, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.filter_error_title())
+        .description(lang.filter_regex_invalid(error.truncate(1000)))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// No filter found with the given name.
+pub fn not_found(name: String, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.filter_error_title())
+        .description(lang.filter_regex_not_found(name.remove_markdown().truncate(32)))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
@@ -0,0 +1,28 @@
+//! Embeds for the mass action commands.
+
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::COLOR_RED;
+use crate::{interaction::response::InteractionResponse, translations::Lang};
+
+/// The time range of the command is empty.
+pub fn invalid_range(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.mass_action_error_title())
+        .description(lang.mass_action_invalid_range())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// No member matches the command filters.
+pub fn no_members(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.mass_action_error_title())
+        .description(lang.mass_action_no_members())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
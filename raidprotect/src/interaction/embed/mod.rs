@@ -2,11 +2,17 @@
 //!
 //! This crate contains types used to generate embeds used as bot responses.
 
+pub mod backup;
 pub mod ban;
 pub mod captcha;
 pub mod error;
+pub mod filter;
 pub mod kick;
+pub mod mass_action;
 pub mod mute;
+pub mod names;
+pub mod persist;
+pub mod quarantine;
 pub mod warn;
 
 /// RaidProtect's red color.
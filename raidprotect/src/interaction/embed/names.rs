@@ -0,0 +1,61 @@
+//! Embeds for the name rules commands.
+
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::COLOR_RED;
+use crate::{interaction::response::InteractionResponse, translations::Lang, util::TextProcessExt};
+
+/// Maximum number of name rules reached.
+pub fn too_many_rules(max: usize, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.names_error_title())
+        .description(lang.names_too_many(max))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Rule pattern is invalid or too long.
+pub fn invalid_pattern(error: String, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.names_error_title())
+        .description(lang.names_invalid(error.truncate(1000)))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// A rule with the same pattern already exists.
+pub fn already_exists(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.names_error_title())
+        .description(lang.names_already_exists())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// No rule found with the given pattern.
+pub fn not_found(pattern: String, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.names_error_title())
+        .description(lang.names_not_found(pattern.remove_markdown().truncate(100)))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// No name rule is configured.
+pub fn no_rules(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.names_error_title())
+        .description(lang.cleanup_names_no_rules())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
@@ -0,0 +1,46 @@
+//! Embeds for the role persistence configuration commands.
+
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::COLOR_RED;
+use crate::{interaction::response::InteractionResponse, translations::Lang};
+
+/// Role already persisted.
+pub fn role_already_added(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .description(lang.persist_role_already_added())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Too many persisted roles.
+pub fn role_too_many(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .description(lang.persist_role_too_many())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Role not configured as a persisted role.
+pub fn role_not_configured(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .description(lang.persist_role_not_configured())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// The role cannot be persisted.
+pub fn role_invalid(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .description(lang.persist_role_invalid())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
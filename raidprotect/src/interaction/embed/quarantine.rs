@@ -0,0 +1,137 @@
+//! Embeds for the quarantine commands.
+
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::COLOR_RED;
+use crate::{interaction::response::InteractionResponse, translations::Lang, util::TextProcessExt};
+
+/// Quarantine used without quarantine role.
+pub fn role_missing(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_error_title())
+        .description(lang.quarantine_role_missing())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Missing permissions to configure the quarantine role.
+pub fn missing_setup_permission(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_missing_setup_permission_title())
+        .description(lang.bot_missing_permission())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// The quarantine role cannot be given due to the role hierarchy.
+pub fn role_hierarchy(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_missing_setup_permission_title())
+        .description(lang.hierarchy_bot_role())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// The role cannot be used as quarantine role.
+pub fn role_invalid(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_error_title())
+        .description(lang.quarantine_role_invalid())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Error while creating the quarantine role.
+pub fn role_error(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_error_title())
+        .description(lang.quarantine_role_error())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// User is not a server member.
+pub fn not_member(user: String, lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .description(lang.quarantine_not_member(user.remove_markdown().truncate(30)))
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Member is already quarantined.
+pub fn already_quarantined(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_error_title())
+        .description(lang.quarantine_already_quarantined())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Member is not quarantined.
+pub fn not_quarantined(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_error_title())
+        .description(lang.quarantine_not_quarantined())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Bot is missing the `MANAGE_ROLES` permission.
+pub fn bot_missing_permission(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_bot_missing_permission_title())
+        .description(lang.bot_missing_permission())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// User cannot quarantine due to the role hierarchy.
+pub fn user_hierarchy(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_missing_permission_title())
+        .description(lang.hierarchy_user())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Bot cannot quarantine due to the role hierarchy.
+pub fn bot_hierarchy(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_bot_missing_permission_title())
+        .description(lang.hierarchy_bot())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
+
+/// Member is the guild owner, and thus cannot be quarantined.
+pub fn member_owner(lang: Lang) -> InteractionResponse {
+    let embed = EmbedBuilder::new()
+        .color(COLOR_RED)
+        .title(lang.quarantine_missing_permission_title())
+        .description(lang.hierarchy_owner())
+        .build();
+
+    InteractionResponse::EphemeralEmbed(embed)
+}
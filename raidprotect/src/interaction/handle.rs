@@ -1,6 +1,7 @@
 use std::{str::FromStr, sync::Arc};
 
 use anyhow::bail;
+use raidprotect_model::cache::error::CacheHttpError;
 use tracing::{debug, error, warn};
 use twilight_interactions::command::CreateCommand;
 use twilight_model::{
@@ -13,11 +14,11 @@ use twilight_model::{
 
 use super::{
     command::{
-        config::ConfigCommand, help::HelpCommand, moderation::KickCommand, moderation::BanCommand, moderation::MuteCommand, moderation::WarnCommand, profile::ProfileCommand,
+        config::ConfigCommand, help::HelpCommand, moderation::KickCommand, moderation::BanCommand, moderation::MuteCommand, moderation::WarnCommand, profile::ProfileCommand, raidmode::RaidModeCommand, cleanup::CleanupCommand, backup::BackupCommand, mass_action::MassBanCommand, mass_action::MassKickCommand, quarantine::QuarantineCommand, quarantine::UnquarantineCommand,
     },
     component::{
         captcha::{CaptchaDisable, CaptchaEnable, CaptchaValidateButton, CaptchaVerifyButton},
-        PostInChat,
+        MassActionCancel, MassActionConfirm, PostInChat, QuarantineSetup,
     },
     embed,
     response::{InteractionResponder, InteractionResponse},
@@ -46,11 +47,22 @@ pub async fn handle_interaction(interaction: Interaction, state: Arc<ClusterStat
     match response {
         Ok(response) => responder.respond(&state, response).await,
         Err(error) => {
-            error!(error = ?error, "error while processing interaction");
-
-            responder
-                .respond(&state, embed::error::internal_error(lang))
-                .await;
+            // Permission errors are shown to the user with what to fix.
+            let response = error
+                .downcast_ref::<CacheHttpError>()
+                .and_then(|error| embed::error::cache_http(error, lang));
+
+            if let Some(response) = response {
+                debug!(error = ?error, "missing permissions to process interaction");
+
+                responder.respond(&state, response).await;
+            } else {
+                error!(error = ?error, "error while processing interaction");
+
+                responder
+                    .respond(&state, embed::error::internal_error(lang))
+                    .await;
+            }
         }
     }
 }
@@ -66,13 +78,20 @@ async fn handle_command(
     };
 
     match name {
-        "config" => ConfigCommand::handle(interaction, state).await,
-        "help" => HelpCommand::handle(interaction, state).await,
-        "kick" => KickCommand::handle(interaction, state).await,
-        "ban" => BanCommand::handle(interaction, state).await,
-        "mute" => MuteCommand::handle(interaction, state).await,
-        "warn" => WarnCommand::handle(interaction, state).await,
-        "profile" => ProfileCommand::handle(interaction, state).await,
+        "config" => ConfigCommand::handle(interaction, &state).await,
+        "help" => HelpCommand::handle(interaction, &state).await,
+        "kick" => KickCommand::handle(interaction, &state).await,
+        "ban" => BanCommand::handle(interaction, &state).await,
+        "mute" => MuteCommand::handle(interaction, &state).await,
+        "warn" => WarnCommand::handle(interaction, &state).await,
+        "profile" => ProfileCommand::handle(interaction, &state).await,
+        "raidmode" => RaidModeCommand::handle(interaction, &state).await,
+        "cleanup" => CleanupCommand::handle(interaction, state).await,
+        "backup" => BackupCommand::handle(interaction, state).await,
+        "massban" => MassBanCommand::handle(interaction, &state).await,
+        "masskick" => MassKickCommand::handle(interaction, &state).await,
+        "quarantine" => QuarantineCommand::handle(interaction, &state).await,
+        "unquarantine" => UnquarantineCommand::handle(interaction, &state).await,
         name => {
             warn!(name = name, "received unknown command");
 
@@ -96,7 +115,10 @@ async fn handle_component(
         "captcha-enable" => CaptchaEnable::handle(interaction, state).await,
         "captcha-validate" => CaptchaValidateButton::handle(interaction, state).await,
         "captcha-verify" => CaptchaVerifyButton::handle(interaction, state).await,
+        "mass-action-cancel" => MassActionCancel::handle(interaction, custom_id, &state).await,
+        "mass-action-confirm" => MassActionConfirm::handle(interaction, custom_id, state).await,
         "post-in-chat" => PostInChat::handle(interaction, custom_id, &state).await,
+        "quarantine-setup" => QuarantineSetup::handle(interaction, custom_id, state).await,
         name => {
             warn!(name = name, "received unknown component");
 
@@ -135,6 +157,13 @@ pub async fn register_commands(state: &ClusterState, application_id: Id<Applicat
         MuteCommand::create_command().into(),
         WarnCommand::create_command().into(),
         ProfileCommand::create_command().into(),
+        RaidModeCommand::create_command().into(),
+        CleanupCommand::create_command().into(),
+        BackupCommand::create_command().into(),
+        MassBanCommand::create_command().into(),
+        MassKickCommand::create_command().into(),
+        QuarantineCommand::create_command().into(),
+        UnquarantineCommand::create_command().into(),
     ];
 
     let client = state.http().interaction(application_id);
@@ -60,7 +60,6 @@ impl InteractionResponder {
 #[derive(Debug, Clone, PartialEq)]
 pub enum InteractionResponse {
     /// Respond with an embed.
-    #[allow(unused)]
     Embed(Embed),
     /// Respond with an embed sent as ephemeral message.
     EphemeralEmbed(Embed),
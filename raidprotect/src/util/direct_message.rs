@@ -0,0 +1,35 @@
+//! Send direct messages to users.
+
+use twilight_model::{
+    channel::embed::Embed,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::cluster::ClusterState;
+
+/// Send an embed in direct message to a user.
+///
+/// Users can disable direct messages from server members, so errors returned
+/// by this function should usually be ignored.
+pub async fn send_direct_message(
+    state: &ClusterState,
+    user_id: Id<UserMarker>,
+    embed: Embed,
+) -> Result<(), anyhow::Error> {
+    let channel = state
+        .http()
+        .create_private_channel(user_id)
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    state
+        .http()
+        .create_message(channel.id)
+        .embeds(&[embed])?
+        .exec()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! Roles hidden from the guild channels.
+//!
+//! Some features rely on a role that cannot see any channel of the guild, such
+//! as the `@Unverified` role of the captcha or the quarantine role. This
+//! module configures the channel permissions for such roles.
+
+use std::time::Duration;
+
+use raidprotect_model::cache::model::CachedChannel;
+use tracing::{debug, error, trace};
+use twilight_model::{
+    channel::ChannelType,
+    guild::Permissions,
+    http::permission_overwrite::{
+        PermissionOverwrite as HttpPermissionOverwrite,
+        PermissionOverwriteType as HttpPermissionOverwriteType,
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker},
+        Id,
+    },
+};
+
+use crate::cluster::ClusterState;
+
+/// Hide all the channels of a guild to a role.
+///
+/// This function should be used as a background task. It will iterate over all
+/// guilds channels and compute permissions for the role. If the role can see
+/// the channel, the permissions will be updated accordingly (see
+/// [`update_channel_permissions`]).
+///
+/// The category channels are iterated first, since a lot of channels can inherit
+/// from their permissions. The `skip` channel (such as the captcha
+/// verification channel) is not updated.
+pub async fn hide_channels(
+    state: &ClusterState,
+    guild: Id<GuildMarker>,
+    role: Id<RoleMarker>,
+    skip: Option<Id<ChannelMarker>>,
+) -> Result<(), anyhow::Error> {
+    let guild_channels = state.redis().guild_channels(guild).await?;
+
+    let mut categories = Vec::new();
+    let mut channels = Vec::new();
+
+    for channel in guild_channels {
+        // Permissions are not updated for the skipped channel.
+        if Some(channel.id) == skip {
+            continue;
+        }
+
+        // Threads inherit permissions from their parent channel.
+        if channel.is_thread() {
+            continue;
+        }
+
+        if channel.kind == ChannelType::GuildCategory {
+            categories.push(channel);
+        } else {
+            channels.push(channel.id);
+        }
+    }
+
+    // Update permissions for the category channels first.
+    // This will reduce the number of requests to the API since a lot of channels
+    // can inherit from their category.
+    for channel in categories {
+        update_channel_permissions(state, &channel, guild, role).await?;
+    }
+
+    // Small delay to ensure the cache is updated with the new permissions.
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    // Update permissions for the remaining channels.
+    // Since the permissions for these channels could have been updated by the
+    // category channels, the channel is retrieved from the cache again.
+    for channel in channels {
+        let channel = match state.redis().get::<CachedChannel>(&channel).await? {
+            Some(channel) => channel,
+            None => {
+                // Since some delay could have been elapsed since the previous
+                // cache request, the channel could have been deleted.
+                debug!(channel = ?channel, guild = ?guild, "channel no longer in cache during hidden role configuration");
+
+                continue;
+            }
+        };
+
+        update_channel_permissions(state, &channel, guild, role).await?;
+    }
+
+    Ok(())
+}
+
+/// Updates a channel permissions for the hidden role.
+async fn update_channel_permissions(
+    state: &ClusterState,
+    channel: &CachedChannel,
+    guild: Id<GuildMarker>,
+    role: Id<RoleMarker>,
+) -> Result<(), anyhow::Error> {
+    trace!(channel = ?channel.id, role = ?role, guild = ?guild, "updating channel permissions for hidden role");
+
+    // Get permissions for the hidden role. The permissions for everyone are
+    // also retrieved to avoid updating permissions unnecessarily for private
+    // channels.
+    let permissions = channel.permission_overwrites.clone().unwrap_or_default();
+
+    let role_permissions = permissions.iter().find(|p| p.id == role.cast());
+    let everyone_permissions = permissions.iter().find(|p| p.id == guild.cast());
+
+    // Skip updating permissions if the channel is private.
+    if everyone_permissions
+        .map(|p| p.deny)
+        .unwrap_or(Permissions::empty())
+        .contains(Permissions::VIEW_CHANNEL)
+    {
+        return Ok(());
+    }
+
+    // Skip updating permissions if the role is already denied to view the channel.
+    // This will be the case if the channel is a text channel that inherits from
+    // a category channel (that should have been updated first).
+    if role_permissions
+        .map(|p| p.deny)
+        .unwrap_or(Permissions::empty())
+        .contains(Permissions::VIEW_CHANNEL)
+    {
+        return Ok(());
+    }
+
+    // Update the permissions for the hidden role.
+    let permission_overwrite = HttpPermissionOverwrite {
+        id: role.cast(),
+        kind: HttpPermissionOverwriteType::Role,
+        allow: None,
+        deny: Some(Permissions::VIEW_CHANNEL),
+    };
+
+    let update = async {
+        state
+            .cache_http(guild)
+            .update_channel_permission(channel.id, &permission_overwrite)
+            .await?
+            .exec()
+            .await?;
+
+        Ok::<_, anyhow::Error>(())
+    };
+
+    if let Err(error) = update.await {
+        error!(error = ?error, "failed to update channel permissions for hidden role");
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! Get the logs channel of a guild.
 //!
-//! This module exports functions to get the logs channel of a specific guild.
+//! This module exports functions to get the logs channel of a specific guild
+//! and send log entries in it.
 //!
 //! In case the channel is not configured for the current guild, a new one will
 //! be automatically created. If a channel named `raidprotect-logs` is already
@@ -13,18 +14,22 @@ use std::collections::HashMap;
 
 use anyhow::{anyhow, Context};
 use once_cell::sync::Lazy;
-use raidprotect_model::cache::model::CachedChannel;
+use raidprotect_model::{cache::model::CachedChannel, database::model::GuildConfig};
 use tokio::sync::{broadcast, RwLock};
 use tracing::{error, trace, warn};
 use twilight_model::{
     channel::{
+        embed::Embed,
         permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
         ChannelType,
     },
     guild::Permissions,
-    http::permission_overwrite::{
-        PermissionOverwrite as HttpPermissionOverwrite,
-        PermissionOverwriteType as HttpPermissionOverwriteType,
+    http::{
+        attachment::Attachment as FileAttachment,
+        permission_overwrite::{
+            PermissionOverwrite as HttpPermissionOverwrite,
+            PermissionOverwriteType as HttpPermissionOverwriteType,
+        },
     },
     id::{
         marker::{ChannelMarker, GuildMarker},
@@ -93,6 +98,53 @@ pub async fn guild_logs_channel(
     configure_logs_channel(state, guild, lang).await
 }
 
+/// Send a log entry in the logs channel of a guild.
+///
+/// The logs channel is created if it is not configured, see
+/// [`guild_logs_channel`].
+pub async fn send_log(
+    state: &ClusterState,
+    config: &GuildConfig,
+    embed: Embed,
+) -> Result<(), anyhow::Error> {
+    send_log_with_attachments(state, config, embed, &[]).await
+}
+
+/// Send a log entry with file attachments in the logs channel of a guild.
+///
+/// See [`send_log`] for more information.
+pub async fn send_log_with_attachments(
+    state: &ClusterState,
+    config: &GuildConfig,
+    embed: Embed,
+    attachments: &[FileAttachment],
+) -> Result<(), anyhow::Error> {
+    let lang = Lang::from(&*config.lang);
+    let logs_channel = guild_logs_channel(state, config.id, config.logs_chan, lang).await?;
+
+    send_log_to(state, config.id, logs_channel, embed, attachments).await
+}
+
+/// Send a log entry in a specific channel of a guild.
+pub async fn send_log_to(
+    state: &ClusterState,
+    guild: Id<GuildMarker>,
+    channel: Id<ChannelMarker>,
+    embed: Embed,
+    attachments: &[FileAttachment],
+) -> Result<(), anyhow::Error> {
+    state
+        .cache_http(guild)
+        .create_message(channel)
+        .await?
+        .embeds(&[embed])?
+        .attachments(attachments)?
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
 /// Try to find an existing logs channel, or create a new one.
 async fn configure_logs_channel(
     state: &ClusterState,
@@ -220,13 +272,5 @@ async fn send_logs_message(
         .description(lang.logs_creation_description())
         .build();
 
-    state
-        .cache_http(guild)
-        .create_message(channel)
-        .await?
-        .embeds(&[embed])?
-        .exec()
-        .await?;
-
-    Ok(())
+    send_log_to(state, guild, channel, embed, &[]).await
 }
@@ -2,10 +2,14 @@
 //!
 //! This module provides various utilities that doesn't fit in other modules.
 
+mod direct_message;
+mod hidden_role;
 mod logs_channel;
 pub mod resource;
 pub mod shutdown;
 mod text;
 
-pub use logs_channel::guild_logs_channel;
-pub use text::TextProcessExt;
+pub use direct_message::send_direct_message;
+pub use hidden_role::hide_channels;
+pub use logs_channel::{guild_logs_channel, send_log, send_log_to, send_log_with_attachments};
+pub use text::{relative_timestamp, TextProcessExt};
@@ -1,5 +1,21 @@
 //! Text processing utilities.
 
+use time::OffsetDateTime;
+use twilight_mention::{
+    timestamp::{Timestamp, TimestampStyle},
+    Mention,
+};
+
+/// Format a time as a relative Discord timestamp.
+pub fn relative_timestamp(time: OffsetDateTime) -> String {
+    Timestamp::new(
+        time.unix_timestamp() as u64,
+        Some(TimestampStyle::RelativeTime),
+    )
+    .mention()
+    .to_string()
+}
+
 /// Extension trait for [`String`] with text processing utilities.
 pub trait TextProcessExt {
     /// Remove Discord markdown from the String.
@@ -8,7 +24,7 @@ pub trait TextProcessExt {
     /// markdown. It does not check if it is valid markdown.
     fn remove_markdown(&self) -> String;
 
-    /// Truncate text if it exceed a maximum size.
+    /// Truncate text if it exceed a maximum number of characters.
     ///
     /// Truncated characters will be replaced with `...` (without exceeding the
     /// maximum size). Maximum size must be larger at least 3 characters.
@@ -39,12 +55,18 @@ impl TextProcessExt for String {
     fn truncate(&self, max: usize) -> String {
         debug_assert!(max >= 3, "cannot truncate to less than 3 characters");
 
-        if self.len() < max {
+        if self.chars().count() <= max {
             return self.to_string();
         }
 
-        let (start, _) = self.split_at(max - 3);
-        start.to_string() + "..."
+        // Cut on a character boundary, as the text may contain multibyte
+        // characters.
+        let end = self
+            .char_indices()
+            .nth(max - 3)
+            .map_or(self.len(), |(index, _)| index);
+
+        self[..end].to_string() + "..."
     }
 }
 
@@ -72,4 +94,19 @@ mod tests {
         );
         assert_eq!("hello world".to_string().truncate(3), "...".to_string());
     }
+
+    #[test]
+    fn test_truncate_multibyte() {
+        assert_eq!("é".repeat(600).truncate(1024), "é".repeat(600));
+        assert_eq!(
+            "é".repeat(600).truncate(10),
+            format!("{}...", "é".repeat(7))
+        );
+        assert_eq!(
+            "日本語のテキスト".to_string().truncate(6),
+            "日本語...".to_string()
+        );
+        assert_eq!("🦀🦀🦀🦀".to_string().truncate(4), "🦀🦀🦀🦀".to_string());
+        assert_eq!("🦀🦀🦀🦀🦀".to_string().truncate(4), "🦀...".to_string());
+    }
 }
@@ -0,0 +1,28 @@
+
+#![feature(backtrace)]
+#![allow(dead_code)]
+
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[derive(Debug)]
+struct E;
+
+impl Display for E {
+    fn fmt(&self, _formatter: &mut fmt::Formatter) -> fmt::Result {
+        unimplemented!()
+    }
+}
+
+impl Error for E {
+    fn backtrace(&self) -> Option<&Backtrace> {
+        let backtrace = Backtrace::capture();
+        match backtrace.status() {
+            BacktraceStatus::Captured | BacktraceStatus::Disabled | _ => {}
+        }
+        unimplemented!()
+    }
+}
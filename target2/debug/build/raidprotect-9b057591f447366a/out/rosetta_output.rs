@@ -0,0 +1,3404 @@
+#[doc = r" Language type generated by the [rosetta](https://github.com/baptiste0928/rosetta) i18n library."]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Lang {
+    En,
+    Fr,
+}
+impl Lang {
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_kind_webhook_create(&self) -> &'static str {
+        match self {
+            Lang::En => "webhook creations",
+            _ => "créations de webhooks",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_enabled_description(
+        &self,
+        action: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Members joining the server will be **{action}** until raid mode is disabled." , action = action) , _ => format ! ("Les membres rejoignant le serveur seront **{action}** jusqu'à la désactivation du mode raid." , action = action) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn massban_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Ban the members who joined the server within a time range",
+            _ => "Bannir les membres ayant rejoint le serveur dans un intervalle de temps",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_log_enabled(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Raid mode has been enabled by {user}.", user = user),
+            _ => format!("Le mode raid a été activé par {user}.", user = user),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn config_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure RaidProtect on your server",
+            _ => "Configurer RaidProtect sur votre serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_restore_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Restoring backup",
+            _ => "Restauration en cours",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_cache_miss_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Server data not available",
+            _ => "Données du serveur indisponibles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_dm_title(&self, guild: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Raid mode enabled on {guild}", guild = guild),
+            _ => format!("Mode raid activé sur {guild}", guild = guild),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_cancelled(&self) -> &'static str {
+        match self {
+            Lang::En => "Mass action cancelled",
+            _ => "Action de masse annulée",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_confirm_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure",
+            _ => "Configurer",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_dm_kick(&self) -> &'static str {
+        match self { Lang :: En => "The server is currently protected against a raid and new members cannot join it. Please try again later." , _ => "Le serveur est actuellement protégé contre un raid et les nouveaux membres ne peuvent pas le rejoindre. Veuillez réessayer plus tard." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disable_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Disable the RaidProtect captcha",
+            _ => "Désactiver le captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn help_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Need help to use RaidProtect?",
+            _ => "Besoin d'aide pour utiliser RaidProtect ?",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_retention_confirm_description(
+        &self,
+        minutes: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Messages will now be kept for {minutes} minutes to log their edition or deletion." , minutes = minutes) , _ => format ! ("Les messages seront désormais conservés pendant {minutes} minutes pour enregistrer leur modification ou suppression." , minutes = minutes) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_coordinated_quarantine(
+        &self,
+        members: impl ::std::fmt::Display,
+        messages: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{messages} messages with the same content sent by {members} members have been deleted, and the members have been quarantined." , members = members , messages = messages) , _ => format ! ("{messages} messages avec le même contenu envoyés par {members} membres ont été supprimés, et les membres ont été mis en quarantaine." , members = members , messages = messages) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_action_confirm(&self, action: impl ::std::fmt::Display) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Members with a matching name will now be handled with the **{action}** action." , action = action) , _ => format ! ("L'action **{action}** sera désormais effectuée sur les membres dont le nom correspond à une règle." , action = action) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_use_external_emojis(&self) -> &'static str {
+        match self {
+            Lang::En => "Use External Emoji",
+            _ => "Utiliser des émojis externes",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_quarantine_threshold(
+        &self,
+        score: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Members with a risk score of **{score}** or more will be quarantined." , score = score) , _ => format ! ("Les membres ayant un score de risque de **{score}** ou plus seront mis en quarantaine." , score = score) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_kind_ban(&self) -> &'static str {
+        match self {
+            Lang::En => "bans",
+            _ => "bannissements",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_config_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the quarantine of members",
+            _ => "Configurer la quarantaine des membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_coordinated_monitor(
+        &self,
+        members: impl ::std::fmt::Display,
+        messages: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**Monitor mode**: {messages} messages with the same content have been sent by {members} members." , members = members , messages = messages) , _ => format ! ("**Mode surveillance** : {messages} messages avec le même contenu ont été envoyés par {members} membres." , members = members , messages = messages) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn hierarchy_user(&self) -> &'static str {
+        match self { Lang :: En => "This member has a role above or equivalent to yours in the role list. You cannot perform any moderation actions concerning it." , _ => "Ce membre a un rôle au dessus ou équivalent au votre dans la liste des rôles. Vous ne pouvez pas effectuer d'actions de modération le concernant." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_log_description(
+        &self,
+        moderator: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{user} has been quarantined by {moderator}.",
+                moderator = moderator,
+                user = user
+            ),
+            _ => format!(
+                "{user} a été mis en quarantaine par {moderator}.",
+                moderator = moderator,
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_title(&self, version: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Differences with backup #{version}", version = version),
+            _ => format!(
+                "Différences avec la sauvegarde n°{version}",
+                version = version
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_quarantine_description(
+        &self,
+        id: impl ::std::fmt::Display,
+        max: impl ::std::fmt::Display,
+        score: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{user} (`{id}`) has been quarantined with a risk score of **{score}/{max}**." , score = score , id = id , max = max , user = user) , _ => format ! ("{user} (`{id}`) a été mis en quarantaine avec un score de risque de **{score}/{max}**." , score = score , id = id , max = max , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn config_updated_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Configuration updated",
+            _ => "Configuration mise à jour",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disabled_title(&self) -> &'static str {
+        match self {
+            Lang::En => "The captcha has been successfully disabled.",
+            _ => "Le captcha a été désactivé avec succès",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_missing_roles(&self) -> &'static str {
+        match self {
+            Lang::En => "Deleted roles",
+            _ => "Rôles supprimés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_update_description(
+        &self,
+        channel: impl ::std::fmt::Display,
+        link: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{user} edited a [message]({link}) in {channel}.",
+                user = user,
+                channel = channel,
+                link = link
+            ),
+            _ => format!(
+                "{user} a modifié un [message]({link}) dans {channel}.",
+                user = user,
+                channel = channel,
+                link = link
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_missing_channels(&self) -> &'static str {
+        match self {
+            Lang::En => "Deleted channels",
+            _ => "Salons supprimés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "The minimum account age is now disabled.",
+            _ => "L'âge minimum du compte est maintenant désactivé.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Quarantine role configuration",
+            _ => "Configuration du rôle de quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_view_channel(&self) -> &'static str {
+        match self {
+            Lang::En => "View Channels",
+            _ => "Voir les salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn warning_deprecated_command_description(
+        &self,
+        new_command: impl ::std::fmt::Display,
+        old_command: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Use the new `{new_command}` command instead of `{old_command}`.\n\nIf you never used slash commands before, [read the FAQ](https://support.discord.com/hc/en-us/articles/1500000368501-Slash-Commands-FAQ)." , old_command = old_command , new_command = new_command) , _ => format ! ("Utilisez la nouvelle commande `{new_command}` à la place de `{old_command}`.\n\nSi vous n'avez jamais utilisé les commandes slash, [lisez la FAQ](https://support.discord.com/hc/en-us/articles/1500000368501-Slash-Commands-FAQ)." , old_command = old_command , new_command = new_command) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_invalid_range(&self) -> &'static str {
+        match self {
+            Lang::En => "The start of the range must be before its end.",
+            _ => "Le début de l'intervalle doit être avant sa fin.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_role_not_configured(&self) -> &'static str {
+        match self { Lang :: En => "This role is not defined to be given to new members. You can add it with `/config captcha autorole-add`." , _ => "Ce rôle n'est pas configuré pour être donné aux nouveaux membres. Vous pouvez l'ajouter avec `/config captcha autorole-add`." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Quarantine a member by removing its roles",
+            _ => "Mettre un membre en quarantaine en retirant ses rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_started_description(
+        &self,
+        count: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**{count} members** are being processed. A summary will be sent in the logs channel once finished." , count = count) , _ => format ! ("**{count} membres** sont en cours de traitement. Un résumé sera envoyé dans le salon de logs une fois terminé." , count = count) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the minimum account age of new members",
+            _ => "Configurer l'âge minimum du compte des nouveaux membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_kick(&self) -> &'static str {
+        match self {
+            Lang::En => "kick",
+            _ => "expulser",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_content(&self) -> &'static str {
+        match self {
+            Lang::En => "Content",
+            _ => "Contenu",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_detected_description(
+        &self,
+        joins: impl ::std::fmt::Display,
+        minutes: impl ::std::fmt::Display,
+        window: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**{joins} members** joined the server within {window} seconds. Raid mode has been enabled for {minutes} minutes." , joins = joins , window = window , minutes = minutes) , _ => format ! ("**{joins} membres** ont rejoint le serveur en moins de {window} secondes. Le mode raid a été activé pendant {minutes} minutes." , joins = joins , window = window , minutes = minutes) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_overwrites(&self) -> &'static str {
+        match self {
+            Lang::En => "Permissions",
+            _ => "Permissions",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_started_title(
+        &self,
+        action: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Mass action in progress: {action}", action = action),
+            _ => format!("Action de masse en cours : {action}", action = action),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the RaidProtect captcha",
+            _ => "Configurer le captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_list_description(&self) -> &'static str {
+        match self {
+            Lang::En => "List the regex filters",
+            _ => "Lister les filtres regex",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_none(&self) -> &'static str {
+        match self {
+            Lang::En => "No signal",
+            _ => "Aucun signal",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn profile_title(
+        &self,
+        discriminator: impl ::std::fmt::Display,
+        username: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Profile of {username}#{discriminator}",
+                username = username,
+                discriminator = discriminator
+            ),
+            _ => format!(
+                "Profil de {username}#{discriminator}",
+                username = username,
+                discriminator = discriminator
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_too_many(&self, max: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "This server cannot have more than {max} name rules.",
+                max = max
+            ),
+            _ => format!(
+                "Ce serveur ne peut pas avoir plus de {max} règles de noms.",
+                max = max
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_not_enabled_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Captcha not enabled",
+            _ => "Captcha non activé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Member quarantined",
+            _ => "Membre mis en quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_expires_at(&self) -> &'static str {
+        match self {
+            Lang::En => "Expiration",
+            _ => "Expiration",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_add_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Add or update a regex filter",
+            _ => "Ajouter ou modifier un filtre regex",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_action_kicked(&self) -> &'static str {
+        match self {
+            Lang::En => "kicked",
+            _ => "expulsés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_list_description(&self) -> &'static str {
+        match self {
+            Lang::En => "List the roles of the RaidProtect captcha autorole",
+            _ => "Lister les rôles de l'autorole du captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_channel_name(&self) -> &'static str {
+        match self {
+            Lang::En => "verification",
+            _ => "vérification",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_image_button(&self) -> &'static str {
+        match self {
+            _ => "Continuer (entrer le code)",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_action(&self) -> &'static str {
+        match self {
+            Lang::En => "Action on new members",
+            _ => "Action sur les nouveaux membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_confirm_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the quarantine role",
+            _ => "Configurer le rôle de quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_manage_nicknames(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage Nicknames",
+            _ => "Gérer les pseudos",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_duplicates(&self) -> &'static str {
+        match self {
+            Lang::En => "Cross-channel duplicates",
+            _ => "Doublons entre salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_already_added(&self) -> &'static str {
+        match self {
+            Lang::En => "This role is already persisted.",
+            _ => "Ce rôle est déjà conservé.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_restore_log_description(
+        &self,
+        author: impl ::std::fmt::Display,
+        version: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The restoration of backup **#{version}** started by {author} is complete." , author = author , version = version) , _ => format ! ("La restauration de la sauvegarde **n°{version}** lancée par {author} est terminée." , author = author , version = version) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_alert_title(&self, guild: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Anti-nuke protection triggered on {guild}", guild = guild),
+            _ => format!("Protection anti-nuke déclenchée sur {guild}", guild = guild),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_too_many(&self, max: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "This server cannot have more than {max} regex filters.",
+                max = max
+            ),
+            _ => format!(
+                "Ce serveur ne peut pas avoir plus de {max} filtres regex.",
+                max = max
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_remove_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Remove a username and nickname rule",
+            _ => "Supprimer une règle de pseudos et de surnoms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the roles restored when members join again",
+            _ => "Configurer les rôles restaurés lorsque les membres rejoignent à nouveau",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_log_ban(&self, count: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "**{count} members** have been banned because raid mode is enabled.",
+                count = count
+            ),
+            _ => format!(
+                "**{count} membres** ont été bannis car le mode raid est activé.",
+                count = count
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_dm_captcha(&self, age: impl ::std::fmt::Display) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Your account is less than **{age}** old. You must complete the captcha to access the server." , age = age) , _ => format ! ("Votre compte a moins de **{age}**. Vous devez compléter le captcha pour accéder au serveur." , age = age) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn logs_creation_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Channel created automatically by RaidProtect",
+            _ => "Salon créé automatiquement par RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_list_title(&self) -> &'static str {
+        match self {
+            Lang::En => "List of configured roles",
+            _ => "Liste des rôles configurés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_dm_quarantine(
+        &self,
+        age: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Your account is less than **{age}** old. Your access to the server is limited until a moderator reviews your account." , age = age) , _ => format ! ("Votre compte a moins de **{age}**. Votre accès au serveur est limité jusqu'à ce qu'un modérateur vérifie votre compte." , age = age) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_kind_kick(&self) -> &'static str {
+        match self {
+            Lang::En => "kicks",
+            _ => "expulsions",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_reason_custom(
+        &self,
+        reason: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Quarantine: {reason}", reason = reason),
+            _ => format!("Quarantaine : {reason}", reason = reason),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_kind_regex(&self) -> &'static str {
+        match self {
+            Lang::En => "Regex",
+            _ => "Regex",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_missing_logs_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect cannot send logs to this channel",
+            _ => "RaidProtect ne peut pas envoyer les logs dans ce salon",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_test_more(&self, count: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{count} other messages match.", count = count),
+            _ => format!("{count} autres messages correspondent.", count = count),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn help_embed_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Need help ?",
+            _ => "Besoin d'aide ?",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_stats_footer(&self) -> &'static str {
+        match self {
+            Lang::En => "Counters are reset when the mode of a rule is changed.",
+            _ => "Les compteurs sont réinitialisés lorsque le mode d'une règle est modifié.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disable_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Disabling the captcha",
+            _ => "Désactivation du captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_invites(&self) -> &'static str {
+        match self {
+            Lang::En => "Invite links",
+            _ => "Liens d'invitation",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Account too recent",
+            _ => "Compte trop récent",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_detection_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "The automatic detection of join waves is now disabled.",
+            _ => "La détection automatique des vagues d'arrivées est maintenant désactivée.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn profile_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Show information about a user profile",
+            _ => "Afficher des informations à propos d'un utilisateur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the actions based on the risk score of new members",
+            _ => "Configurer les actions basées sur le score de risque des nouveaux membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_list_entry(
+        &self,
+        author: impl ::std::fmt::Display,
+        channels: impl ::std::fmt::Display,
+        date: impl ::std::fmt::Display,
+        roles: impl ::std::fmt::Display,
+        version: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "**#{version}** • {date} • {roles} roles, {channels} channels ({author})",
+                date = date,
+                version = version,
+                roles = roles,
+                author = author,
+                channels = channels
+            ),
+            _ => format!(
+                "**n°{version}** • {date} • {roles} rôles, {channels} salons ({author})",
+                date = date,
+                version = version,
+                roles = roles,
+                author = author,
+                channels = channels
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn expired_interaction_description(&self) -> &'static str {
+        match self { Lang :: En => "The action you are trying to do expired, because you waited too long or already did it. You can retry by resending the command" , _ => "L'action que vous essayez de faire a expiré, car vous avez attendu trop longtemps ou l'avez déjà actionnée. Vous pouvez recommencer en renvoyant la commande." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_delete_description(
+        &self,
+        channel: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "A message from {user} has been deleted in {channel}.",
+                channel = channel,
+                user = user
+            ),
+            _ => format!(
+                "Un message de {user} a été supprimé dans {channel}.",
+                channel = channel,
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_history_description(&self) -> &'static str {
+        match self { Lang :: En => "Configure how long joins are kept for the mass ban and mass kick commands" , _ => "Configurer la durée de conservation des arrivées pour les commandes de bannissement et d'expulsion de masse" }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_empty(&self) -> &'static str {
+        match self {
+            Lang::En => "The server structure is identical to the backup.",
+            _ => "La structure du serveur est identique à la sauvegarde.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_add_confirm(&self, rule: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("The rule {rule} has been added.", rule = rule),
+            _ => format!("La règle {rule} a été ajoutée.", rule = rule),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_send_messages(&self) -> &'static str {
+        match self {
+            Lang::En => "Send Messages",
+            _ => "Envoyer des messages",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_enabled_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode enabled",
+            _ => "Mode raid activé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_add_confirm_description(
+        &self,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The {role} role will now be given after a member has completed the captcha.",
+                role = role
+            ),
+            _ => format!(
+                "Le rôle {role} sera désormais donné après qu'un membre ait complété le captcha.",
+                role = role
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_history_confirm(&self, hours: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Joins will now be kept for **{hours} hours**.",
+                hours = hours
+            ),
+            _ => format!(
+                "Les arrivées seront désormais conservées pendant **{hours} heures**.",
+                hours = hours
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the RaidProtect message logs",
+            _ => "Configurer les logs de messages RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_action_ban(&self) -> &'static str {
+        match self {
+            Lang::En => "Ban",
+            _ => "Bannissement",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn logs_creation_description(&self) -> &'static str {
+        match self { Lang :: En => "RaidProtect will inform you of the moderation actions performed on your server by sending a message in this channel. **This channel is currently only visible to server administrators**, you can change its permissions to allow your moderators to access it. \n\nYou can change the channel used for logging in the RaidProtect settings." , _ => "RaidProtect vous informera des actions de modération effectuées sur votre serveur en envoyant un message dans ce salon. **Ce salon est actuellement visible uniquement par les administrateurs du serveur**, vous pouvez modifier ses permissions pour permettre à vos modérateurs d'y accéder. \n\nVous pouvez modifier le salon utilisé pour les logs dans les paramètres de RaidProtect." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_action_banned(&self) -> &'static str {
+        match self {
+            Lang::En => "banned",
+            _ => "bannis",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_role_name(&self) -> &'static str {
+        match self {
+            Lang::En => "Unverified",
+            _ => "Non vérifié",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_add_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Add a role to the RaidProtect captcha autorole",
+            _ => "Ajouter un rôle à l'autorole du captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_embed_links(&self) -> &'static str {
+        match self {
+            Lang::En => "Embed Links",
+            _ => "Intégrer des liens",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_action_quarantine(&self) -> &'static str {
+        match self {
+            Lang::En => "Quarantine",
+            _ => "Quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage the raid mode of the server",
+            _ => "Gérer le mode raid du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_quarantine_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "High risk score",
+            _ => "Score de risque élevé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_numeric_name(&self) -> &'static str {
+        match self {
+            Lang::En => "Username with many digits",
+            _ => "Pseudo avec beaucoup de chiffres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_restore_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Backup restoration",
+            _ => "Restauration d'une sauvegarde",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_stats_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Show how many messages matched the auto-moderation rules",
+            _ => "Afficher le nombre de messages détectés par les règles d'auto-modération",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_disabled_description(&self) -> &'static str {
+        match self {
+            Lang::En => "New members can join the server again.",
+            _ => "Les nouveaux membres peuvent à nouveau rejoindre le serveur.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_enabled_at(&self) -> &'static str {
+        match self {
+            Lang::En => "Enabled",
+            _ => "Activé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_phishing(&self) -> &'static str {
+        match self {
+            Lang::En => "Phishing links",
+            _ => "Liens de phishing",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn unknown_command_title(&self) -> &'static str {
+        match self {
+            Lang::En => "This command is not yet available",
+            _ => "Cette commande n'est pas encore disponible",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Apply protection rules to existing members",
+            _ => "Appliquer les règles de protection aux membres existants",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_kind_wildcard(&self) -> &'static str {
+        match self {
+            Lang::En => "Wildcard",
+            _ => "Joker",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_no_rules(&self) -> &'static str {
+        match self { Lang :: En => "No name rule is configured. Use `/config names add` to add a rule." , _ => "Aucune règle de noms n'est configurée. Utilisez `/config names add` pour ajouter une règle." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_enabled(
+        &self,
+        action: impl ::std::fmt::Display,
+        age: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Accounts younger than **{age}** will be handled with the **{action}** action.",
+                action = action,
+                age = age
+            ),
+            _ => format!(
+                "Les comptes ayant moins de **{age}** seront traités avec l'action **{action}**.",
+                action = action,
+                age = age
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_logs_confirm_description(
+        &self,
+        channel: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The captcha logs will now be sent to the {channel} channel.",
+                channel = channel
+            ),
+            _ => format!(
+                "Les logs du captcha seront désormais envoyés dans le salon {channel}.",
+                channel = channel
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_enabled(
+        &self,
+        action: impl ::std::fmt::Display,
+        threshold: impl ::std::fmt::Display,
+        window: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Anti-nuke protection enabled: **{action}** when a member performs {threshold} destructive actions of the same kind within {window} seconds. The bot needs the *View Audit Log* permission." , threshold = threshold , action = action , window = window) , _ => format ! ("Protection anti-nuke activée : **{action}** lorsqu'un membre effectue {threshold} actions destructrices du même type en {window} secondes. Le bot a besoin de la permission *Voir les logs du serveur*." , threshold = threshold , action = action , window = window) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_manage_channels(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage Channels",
+            _ => "Gérer les salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn learn_more(&self) -> &'static str {
+        match self {
+            Lang::En => "Learn more",
+            _ => "En savoir plus",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_missing_permissions_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect is missing permissions",
+            _ => "RaidProtect n'a pas les permissions nécessaires",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_on_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Enable the raid mode",
+            _ => "Activer le mode raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_roles(&self) -> &'static str {
+        match self {
+            Lang::En => "Roles",
+            _ => "Rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_test_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Test regex filters against the last cached messages",
+            _ => "Tester les filtres regex sur les derniers messages en cache",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_schedule_enabled(
+        &self,
+        hours: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "A backup will be created automatically every **{hours}** hours.",
+                hours = hours
+            ),
+            _ => format!(
+                "Une sauvegarde sera créée automatiquement toutes les **{hours}** heures.",
+                hours = hours
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_missing_setup_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Unable to configure the quarantine",
+            _ => "Impossible de configurer la quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "You cannot update the roles of this member",
+            _ => "Vous ne pouvez pas modifier les rôles de ce membre",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn post_in_chat_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Send in the channel",
+            _ => "Envoyer dans le salon",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Roles restored after joining the server again",
+            _ => "Rôles restaurés après avoir rejoint à nouveau le serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_never(&self) -> &'static str {
+        match self {
+            Lang::En => "Until disabled",
+            _ => "Jusqu'à désactivation",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_list_empty(&self) -> &'static str {
+        match self {
+            Lang::En => "No regex filter is configured on this server.",
+            _ => "Aucun filtre regex n'est configuré sur ce serveur.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_create_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Create a backup of the server structure",
+            _ => "Créer une sauvegarde de la structure du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_error_title(&self) -> &'static str {
+        match self {
+            _ => "Impossible d'afficher le captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_moderate_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Timeout Members",
+            _ => "Exclure temporairement des membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_delete_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Message deleted",
+            _ => "Message supprimé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_role_too_many(&self) -> &'static str {
+        match self { Lang :: En => "You can only configure 5 roles to be assigned automatically. Use `/config captcha autorole-list` to display which roles are already configured." , _ => "Vous ne pouvez configurer que 5 rôles à assigner automatiquement. Utilisez `/config captcha autorole-list` pour afficher les rôles déjà configurés." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_regex(&self, name: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Regex filter \"{name}\"", name = name),
+            _ => format!("Filtre regex « {name} »", name = name),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn ban_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Bans a user from the server",
+            _ => "Bans a user from the server",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode is enabled",
+            _ => "Le mode raid est activé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_attachments(&self) -> &'static str {
+        match self {
+            Lang::En => "Attachments",
+            _ => "Pièces jointes",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_schedule_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Automatic backups are disabled.",
+            _ => "Les sauvegardes automatiques sont désactivées.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_action_rename(&self) -> &'static str {
+        match self {
+            Lang::En => "Replace the nickname",
+            _ => "Remplacer le surnom",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_log(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "**The captcha has been enabled** on the server by {user}.",
+                user = user
+            ),
+            _ => format!(
+                "**Le captcha a été activé** sur le serveur par {user}.",
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_empty(&self) -> &'static str {
+        match self {
+            Lang::En => "*No content*",
+            _ => "*Aucun contenu*",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_bulk_description(
+        &self,
+        cached: impl ::std::fmt::Display,
+        channel: impl ::std::fmt::Display,
+        deleted: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{deleted} messages have been deleted in {channel}. The transcript contains the {cached} messages that were still cached." , cached = cached , deleted = deleted , channel = channel) , _ => format ! ("{deleted} messages ont été supprimés dans {channel}. La transcription contient les {cached} messages qui étaient encore en cache." , cached = cached , deleted = deleted , channel = channel) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Apply the username and nickname rules to existing members",
+            _ => "Appliquer les règles de pseudos et de surnoms aux membres existants",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_member_hierarchy_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect cannot act on this member",
+            _ => "RaidProtect ne peut pas agir sur ce membre",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_confirm_description(
+        &self,
+        action: impl ::std::fmt::Display,
+        count: impl ::std::fmt::Display,
+        from: impl ::std::fmt::Display,
+        to: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Are you sure you want to {action} **{count} members** who joined between {from} and {to}? This action cannot be undone." , to = to , from = from , count = count , action = action) , _ => format ! ("Voulez-vous vraiment {action} **{count} membres** ayant rejoint entre {from} et {to} ? Cette action est irréversible." , to = to , from = from , count = count , action = action) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_changed_overwrites(&self) -> &'static str {
+        match self {
+            Lang::En => "Modified permissions",
+            _ => "Permissions modifiées",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn unquarantine_confirm(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{user} has been released from quarantine.", user = user),
+            _ => format!("{user} a été sorti de quarantaine.", user = user),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn help_support(&self) -> &'static str {
+        match self {
+            Lang::En => "Our Discord server",
+            _ => "Notre serveur Discord",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_pattern_too_long(&self, max: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The pattern must be between 1 and {max} characters long.",
+                max = max
+            ),
+            _ => format!(
+                "Le motif doit contenir entre 1 et {max} caractères.",
+                max = max
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn kick_not_member(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Impossible to kick **{user}**, it is not a member of this server.",
+                user = user
+            ),
+            _ => format!(
+                "Impossible d'expulser **{user}**, ce n'est pas un membre de ce serveur.",
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_logs_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Set the channel where edited and deleted messages are logged",
+            _ => "Définir le salon où sont enregistrés les messages modifiés et supprimés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_input_label(&self) -> &'static str {
+        match self {
+            _ => "Entrez le code ci-dessous",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_kind_channel_delete(&self) -> &'static str {
+        match self {
+            Lang::En => "channel deletions",
+            _ => "suppressions de salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_error_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Unable to quarantine",
+            _ => "Impossible de mettre en quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_quarantine_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Members are not quarantined based on their risk score.",
+            _ => "Les membres ne sont pas mis en quarantaine selon leur score de risque.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_log_action(&self) -> &'static str {
+        match self {
+            Lang::En => "Action",
+            _ => "Action",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_after(&self) -> &'static str {
+        match self {
+            Lang::En => "After",
+            _ => "Après",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_remove_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Remove a role from the RaidProtect captcha autorole",
+            _ => "Supprimer un rôle de l'autorole du captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn warning_deprecated_command_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect now uses slash commands",
+            _ => "RaidProtect supporte désormais les commandes slash",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_bot_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect cannot update the roles of this member",
+            _ => "RaidProtect ne peut pas modifier les rôles de ce membre",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_confirm(
+        &self,
+        count: impl ::std::fmt::Display,
+        scanned: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**{count}** of the {scanned} scanned members have a name matching a rule. Do you want to apply the action to them?" , count = count , scanned = scanned) , _ => format ! ("**{count}** des {scanned} membres analysés ont un nom correspondant à une règle. Voulez-vous leur appliquer l'action ?" , count = count , scanned = scanned) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_role_reason(&self) -> &'static str {
+        match self {
+            _ => "Affichage du captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_hours(&self, hours: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{hours} hours", hours = hours),
+            _ => format!("{hours} heures", hours = hours),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn expired_interaction_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Interaction expired",
+            _ => "L'interaction a expirée",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_log_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Removed members",
+            _ => "Membres retirés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn hierarchy_bot_role(&self) -> &'static str {
+        match self { Lang :: En => "This role is above the RaidProtect role in the role list, which prevents it from being added to new members. You can fix this by moving RaidProtect higher in the role list." , _ => "Ce rôle est au dessus de celui de RaidProtect dans la liste des rôles, ce qui empêche de le donner aux nouveaux membres. Vous pouvez corriger cela en plaçant RaidProtect plus haut dans la liste des rôles." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn hierarchy_owner(&self) -> &'static str {
+        match self { Lang :: En => "This member is the owner of this server. You cannot perform any moderation actions concerning it." , _ => "Ce membre est le propriétaire de ce serveur. Vous ne pouvez pas effectuer d'actions de modération le concernant." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_log_rule(&self) -> &'static str {
+        match self {
+            Lang::En => "Rule",
+            _ => "Règle",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_remove_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Stop persisting a role",
+            _ => "Ne plus conserver un rôle",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mute_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "You don't have permission to mute this member",
+            _ => "You don't have permission to mute this member",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_action_captcha(&self) -> &'static str {
+        match self {
+            Lang::En => "Captcha",
+            _ => "Captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_rename_description(
+        &self,
+        channel: impl ::std::fmt::Display,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("You can safely rename the {role} role and the {channel} channel if their names are not suitable. Be careful not to change their permissions, the captcha might not work anymore." , role = role , channel = channel) , _ => format ! ("Vous pouvez sans problème renommer le rôle {role} et le salon {channel} si leurs noms ne vous conviennent pas. Attention à ne pas modifier leurs permissions, le captcha risque de ne plus fonctionner." , role = role , channel = channel) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn ban_not_member(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Impossible to ban **{user}**, it is not a member of this server.",
+                user = user
+            ),
+            _ => format!(
+                "Impossible to ban **{user}**, it is not a member of this server.",
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_roles_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Roles after the verification",
+            _ => "Rôles après la vérification",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_cache_miss_description(&self) -> &'static str {
+        match self { Lang :: En => "RaidProtect has not yet loaded all the data of this server. Wait a few minutes and try again." , _ => "RaidProtect n'a pas encore chargé toutes les données de ce serveur. Patientez quelques minutes et réessayez." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn warn_bot_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect doesn't have permission to warn this member.",
+            _ => "RaidProtect doesn't have permission to warn this member.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_dm_ban(
+        &self,
+        age: impl ::std::fmt::Display,
+        allowed: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Your account must be at least **{age}** old to join this server, and you have been banned. Your account will be old enough {allowed}, contact a moderator of the server to be unbanned." , allowed = allowed , age = age) , _ => format ! ("Votre compte doit avoir au moins **{age}** pour rejoindre ce serveur, et vous avez été banni. Votre compte sera assez ancien {allowed}, contactez un modérateur du serveur pour être débanni." , allowed = allowed , age = age) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_reason_label(&self) -> &'static str {
+        match self {
+            Lang::En => "Reason",
+            _ => "Reason",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn profile_joined_at(&self) -> &'static str {
+        match self {
+            Lang::En => "Members of this guild",
+            _ => "Membre de ce serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn kick_bot_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect doesn't have permission to kick this member.",
+            _ => "RaidProtect n'a pas la permission d'expulser ce membre.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_not_found(&self) -> &'static str {
+        match self { Lang :: En => "This backup does not exist. Use `/backup list` to see the available backups." , _ => "Cette sauvegarde n'existe pas. Utilisez `/backup list` pour voir les sauvegardes disponibles." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_ban_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Ban Members",
+            _ => "Bannir des membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn kick_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "You don't have permission to kick this member",
+            _ => "Vous n'avez pas la permission d'expulser ce membre",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_role_error(&self) -> &'static str {
+        match self {
+            Lang::En => "An error occurred while creating the quarantine role.",
+            _ => "Une erreur est survenue lors de la création du rôle de quarantaine.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_confirm_description(
+        &self,
+        mode: impl ::std::fmt::Display,
+        rule: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The **{rule}** rule is now in **{mode}** mode.",
+                rule = rule,
+                mode = mode
+            ),
+            _ => format!(
+                "La règle **{rule}** est maintenant en mode **{mode}**.",
+                rule = rule,
+                mode = mode
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_delete(
+        &self,
+        channel: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The message sent by {user} in {channel} has been deleted.",
+                user = user,
+                channel = channel
+            ),
+            _ => format!(
+                "Le message envoyé par {user} dans {channel} a été supprimé.",
+                user = user,
+                channel = channel
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_rule_entry(
+        &self,
+        kind: impl ::std::fmt::Display,
+        pattern: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("**{kind}**: `{pattern}`", kind = kind, pattern = pattern),
+            _ => format!("**{kind}** : `{pattern}`", kind = kind, pattern = pattern),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_remove_confirm_description(
+        &self,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The {role} role will no longer be given to members who have completed the captcha." , role = role) , _ => format ! ("Le rôle {role} ne sera plus donné aux membres ayant complété le captcha." , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the RaidProtect raid protection",
+            _ => "Configurer la protection anti-raid de RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_already_enabled_title(&self) -> &'static str {
+        match self {
+            Lang::En => "The captcha is already enabled",
+            _ => "Le captcha est déjà activé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_role_missing(&self) -> &'static str {
+        match self { Lang :: En => "No quarantine role is configured. Use `/config quarantine setup` to configure one." , _ => "Aucun rôle de quarantaine n'est configuré. Utilisez `/config quarantine setup` pour en configurer un." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_not_found_description(&self) -> &'static str {
+        match self { _ => "RaidProtect ne trouve pas de vérification en attente pour vous. Si vous n'arrivez pas à accéder au reste du serveur, contactez des responsables." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_error_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Unable to update the filter",
+            _ => "Impossible de modifier le filtre",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_coordinated_timeout(
+        &self,
+        members: impl ::std::fmt::Display,
+        messages: impl ::std::fmt::Display,
+        minutes: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{messages} messages with the same content sent by {members} members have been deleted, and the members have been timed out for {minutes} minutes." , minutes = minutes , members = members , messages = messages) , _ => format ! ("{messages} messages avec le même contenu envoyés par {members} membres ont été supprimés, et les membres ont été exclus temporairement pendant {minutes} minutes." , minutes = minutes , members = members , messages = messages) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_title(&self) -> &'static str {
+        match self {
+            Lang::En => "The captcha has been successfully enabled",
+            _ => "Le captcha a été activé avec succès",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_restore_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Backup restored",
+            _ => "Sauvegarde restaurée",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_manage_messages(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage Messages",
+            _ => "Gérer les messages",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_quarantine_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Member quarantined",
+            _ => "Membre mis en quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_recent_account(&self) -> &'static str {
+        match self {
+            Lang::En => "Account created less than a week ago",
+            _ => "Compte créé il y a moins d'une semaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_reason_placeholder(&self) -> &'static str {
+        match self {
+            Lang::En => "Reason sent to the sanctioned user",
+            _ => "Raison envoyée à l'utilisateur sanctionné",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Mass action after a raid",
+            _ => "Action de masse suite à un raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_remove_confirm(&self, rule: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("The rule {rule} has been removed.", rule = rule),
+            _ => format!("La règle {rule} a été supprimée.", rule = rule),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_role_name(&self) -> &'static str {
+        match self {
+            Lang::En => "Quarantine",
+            _ => "Quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_schedule_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure automatic backups of the server structure",
+            _ => "Configurer les sauvegardes automatiques de la structure du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_detection_enabled(
+        &self,
+        duration: impl ::std::fmt::Display,
+        joins: impl ::std::fmt::Display,
+        window: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Raid mode will be enabled for **{duration} minutes** when **{joins} members** join within **{window} seconds**." , duration = duration , joins = joins , window = window) , _ => format ! ("Le mode raid sera activé pendant **{duration} minutes** lorsque **{joins} membres** rejoignent en moins de **{window} secondes**." , duration = duration , joins = joins , window = window) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_list_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Regex filters",
+            _ => "Filtres regex",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mute_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Mutes a user from the server",
+            _ => "Mutes a user from the server",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_before(&self) -> &'static str {
+        match self {
+            Lang::En => "Before",
+            _ => "Avant",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_missing_enable_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect is not allowed to enable the captcha",
+            _ => "RaidProtect n'a pas la permission d'activer le captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_test_entry(
+        &self,
+        channel: impl ::std::fmt::Display,
+        content: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{user} in {channel}:\n{content}",
+                content = content,
+                channel = channel,
+                user = user
+            ),
+            _ => format!(
+                "{user} dans {channel} :\n{content}",
+                content = content,
+                channel = channel,
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn unquarantine_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Member released from quarantine",
+            _ => "Membre sorti de quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_log_action(&self) -> &'static str {
+        match self {
+            Lang::En => "Action",
+            _ => "Action",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode status",
+            _ => "État du mode raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_new_roles(&self) -> &'static str {
+        match self {
+            Lang::En => "New roles",
+            _ => "Nouveaux rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_mode_enforce(&self) -> &'static str {
+        match self {
+            Lang::En => "enforce",
+            _ => "application",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_quarantine(
+        &self,
+        channel: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The message sent by {user} in {channel} has been deleted and the member has been quarantined." , channel = channel , user = user) , _ => format ! ("Le message envoyé par {user} dans {channel} a été supprimé et le membre a été mis en quarantaine." , channel = channel , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Compare a backup with the current server structure",
+            _ => "Comparer une sauvegarde avec la structure actuelle du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_captcha_threshold(&self, score: impl ::std::fmt::Display) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The captcha is required for members with a risk score of **{score}** or more." , score = score) , _ => format ! ("Le captcha est requis pour les membres ayant un score de risque de **{score}** ou plus." , score = score) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_create_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Backup created",
+            _ => "Sauvegarde créée",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_regenerate_error_description(&self) -> &'static str {
+        match self { _ => "Vous avez demandé trop de regénérations du captcha. Par mesure de sécurité, vous allez être expulsé du serveur dans quelques secondes. Vous pouvez rejoindre le serveur de nouveau et réésayer." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_score(&self) -> &'static str {
+        match self {
+            Lang::En => "Risk score",
+            _ => "Score de risque",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_confirm_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Confirm",
+            _ => "Confirmer",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn kick_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Kick a member from the server",
+            _ => "Expulser un membre du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_invalid(&self, error: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The regex pattern is invalid:\n```\n{error}\n```",
+                error = error
+            ),
+            _ => format!(
+                "Le motif regex est invalide :\n```\n{error}\n```",
+                error = error
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_missing_permissions_guild(
+        &self,
+        permissions: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("RaidProtect needs the following permissions on the server to perform this action:\n{permissions}\n\nGrant them to the RaidProtect role in the server settings, then try again." , permissions = permissions) , _ => format ! ("RaidProtect a besoin des permissions suivantes sur le serveur pour effectuer cette action :\n{permissions}\n\nAccordez-les au rôle de RaidProtect dans les paramètres du serveur, puis réessayez." , permissions = permissions) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure an auto-moderation rule",
+            _ => "Configurer une règle d'auto-modération",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_add_confirm(
+        &self,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The role {role} will be restored when members join the server again." , role = role) , _ => format ! ("Le rôle {role} sera restauré lorsque les membres rejoindront à nouveau le serveur." , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_bulk_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Messages deleted in bulk",
+            _ => "Messages supprimés en masse",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_score_value(
+        &self,
+        max: impl ::std::fmt::Display,
+        score: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{score}/{max}", score = score, max = max),
+            _ => format!("{score}/{max}", score = score, max = max),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_kick_reason_label(&self) -> &'static str {
+        match self {
+            _ => "Raison de l'expulsion",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_not_member(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{user} is not a member of the server.", user = user),
+            _ => format!("{user} n'est pas membre du serveur.", user = user),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure custom auto-moderation filters",
+            _ => "Configurer les filtres d'auto-modération personnalisés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_reason(&self, rule: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Auto-moderation: {rule}", rule = rule),
+            _ => format!("Auto-modération : {rule}", rule = rule),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_detected_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid detected",
+            _ => "Raid détecté",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn internal_error_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Oops, an unknown error occurred ...",
+            _ => "Oups, une erreur inconnue s'est produite ...",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_mute_title(&self, username: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Mute {username}", username = username),
+            _ => format!("Mute {username}", username = username),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disable_confirm_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Disabling the captcha",
+            _ => "Désactivation du captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_test_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Regex filter test",
+            _ => "Test des filtres regex",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enable_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Enable the RaidProtect captcha",
+            _ => "Activer le captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_role_already_added(&self) -> &'static str {
+        match self { Lang :: En => "This role is already configured to be assigned automatically to new members. You can disable it by using the command `/config captcha autorole-remove`." , _ => "Ce rôle est déjà configuré pour être assigné automatiquement aux nouveaux membres. Vous pouvez le désactiver en utilisant la commande `/config captcha autorole-remove`." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_alert_description(
+        &self,
+        count: impl ::std::fmt::Display,
+        id: impl ::std::fmt::Display,
+        kind: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+        window: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{user} (`{id}`) performed **{count} {kind}** within {window} seconds. Their roles have been removed." , count = count , id = id , kind = kind , user = user , window = window) , _ => format ! ("{user} (`{id}`) a effectué **{count} {kind}** en {window} secondes. Ses rôles ont été retirés." , count = count , id = id , kind = kind , user = user , window = window) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn internal_error_description(&self) -> &'static str {
+        match self { Lang :: En => "The action you performed has returned an unexpected result. Don't panic, we have been informed of the problem! In the meantime, please try the command again.\n\n**If the problem persists, please inform us.** You can contact us by [joining our Discord server](https://discord.gg/raidprotect)." , _ => "L'action que vous avez effectuée a renvoyé un résultat imprévu. Pas de panique, nous avons été informés du problème ! En attendant, veuillez réessayer la commande de nouveau.\n\n**Si le problème persiste, merci de nous en informer.** Vous pouvez nous contacter en [rejoignant notre serveur Discord](https://discord.gg/raidprotect)." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_preview_more(
+        &self,
+        count: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(" and **{count}** more", count = count),
+            _ => format!(" et **{count}** autres", count = count),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_alert_failed(
+        &self,
+        count: impl ::std::fmt::Display,
+        id: impl ::std::fmt::Display,
+        kind: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+        window: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{user} (`{id}`) performed **{count} {kind}** within {window} seconds, but their roles could not be removed. Check the permissions and the role hierarchy of the bot." , count = count , id = id , window = window , kind = kind , user = user) , _ => format ! ("{user} (`{id}`) a effectué **{count} {kind}** en {window} secondes, mais ses rôles n'ont pas pu être retirés. Vérifiez les permissions et la hiérarchie des rôles du bot." , count = count , id = id , window = window , kind = kind , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode",
+            _ => "Mode raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_stats_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Auto-moderation statistics",
+            _ => "Statistiques de l'auto-modération",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_too_many(&self) -> &'static str {
+        match self {
+            Lang::En => "You cannot persist more than 10 roles.",
+            _ => "Vous ne pouvez pas conserver plus de 10 rôles.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Account too recent",
+            _ => "Compte trop récent",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn ban_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "You don't have permission to ban this member",
+            _ => "You don't have permission to ban this member",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_no_backups(&self) -> &'static str {
+        match self { Lang :: En => "No backup has been created for this server. Use `/backup create` to create one." , _ => "Aucune sauvegarde n'a été créée pour ce serveur. Utilisez `/backup create` pour en créer une." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_missing_role_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect is not allowed to give this role",
+            _ => "RaidProtect n'a pas la permission de donner ce rôle",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_log_title(&self, action: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Mass action completed: {action}", action = action),
+            _ => format!("Action de masse terminée : {action}", action = action),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Member quarantined",
+            _ => "Membre mis en quarantaine",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enable_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Activating the captcha",
+            _ => "Activation du captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_alert_action(&self) -> &'static str {
+        match self {
+            Lang::En => "Action",
+            _ => "Action",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_list_entry(
+        &self,
+        mode: impl ::std::fmt::Display,
+        pattern: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Mode: **{mode}**\n```\n{pattern}\n```",
+                mode = mode,
+                pattern = pattern
+            ),
+            _ => format!(
+                "Mode : **{mode}**\n```\n{pattern}\n```",
+                mode = mode,
+                pattern = pattern
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_error_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Unable to update the name rules",
+            _ => "Impossible de modifier les règles de noms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the protection against destructive actions of staff members",
+            _ => "Configurer la protection contre les actions destructrices des membres du staff",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_remove_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Remove a regex filter",
+            _ => "Supprimer un filtre regex",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Name matching a rule",
+            _ => "Nom correspondant à une règle",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_not_quarantined(&self) -> &'static str {
+        match self {
+            Lang::En => "This member is not quarantined.",
+            _ => "Ce membre n'est pas en quarantaine.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_already_enabled_description(&self) -> &'static str {
+        match self { Lang :: En => "If the captcha is no longer properly configured, you can disable it with the `/config captcha disable` command and then enable it again." , _ => "Si le captcha n'est plus correctement configuré, vous pouvez le désactiver avec la commande `/config captcha disable` puis le réactiver." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_detection_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the automatic detection of join waves",
+            _ => "Configurer la détection automatique des vagues d'arrivées",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_logs_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Set the RaidProtect captcha logs channel",
+            _ => "Définir le salon de logs du captcha RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the RaidProtect auto-moderation",
+            _ => "Configurer l'auto-modération de RaidProtect",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_member_hierarchy_description(
+        &self,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{user} is the server owner or has a role higher than or equal to the highest role of RaidProtect.\n\nMove the RaidProtect role above the roles of this member in the server settings, then try again." , user = user) , _ => format ! ("{user} est le propriétaire du serveur ou a un rôle supérieur ou égal au rôle le plus haut de RaidProtect.\n\nDéplacez le rôle de RaidProtect au-dessus des rôles de ce membre dans les paramètres du serveur, puis réessayez." , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_verification_title(
+        &self,
+        server: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Welcome to {server}", server = server),
+            _ => format!("Bienvenue sur {server}", server = server),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn post_in_chat_author(&self, user_id: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("*Command made by <@{user_id}>*", user_id = user_id),
+            _ => format!("*Commande effectuée par <@{user_id}>*", user_id = user_id),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_log_description(
+        &self,
+        done: impl ::std::fmt::Display,
+        moderator: impl ::std::fmt::Display,
+        total: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "**{done}/{total} members** have been sanctioned by {moderator}.",
+                done = done,
+                moderator = moderator,
+                total = total
+            ),
+            _ => format!(
+                "**{done}/{total} membres** ont été sanctionnés par {moderator}.",
+                done = done,
+                moderator = moderator,
+                total = total
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_role_hierarchy_description(
+        &self,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("RaidProtect cannot manage the {role} role because it is higher than or equal to its own highest role.\n\nMove the RaidProtect role above {role} in the server settings, then try again." , role = role) , _ => format ! ("RaidProtect ne peut pas gérer le rôle {role} car il est supérieur ou égal à son rôle le plus haut.\n\nDéplacez le rôle de RaidProtect au-dessus de {role} dans les paramètres du serveur, puis réessayez." , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_automatic(&self) -> &'static str {
+        match self {
+            Lang::En => "automatic",
+            _ => "automatique",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_kick_reason(&self) -> &'static str {
+        match self {
+            _ => "Échec de la vérification",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_enabled_by(&self) -> &'static str {
+        match self {
+            Lang::En => "Enabled by",
+            _ => "Activé par",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_confirm(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{user} has been quarantined.", user = user),
+            _ => format!("{user} a été mis en quarantaine.", user = user),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_log_disabled(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Raid mode has been disabled by {user}.", user = user),
+            _ => format!("Le mode raid a été désactivé par {user}.", user = user),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_invalid(&self) -> &'static str {
+        match self { Lang :: En => "This role cannot be persisted. Managed roles and the @everyone role cannot be assigned." , _ => "Ce rôle ne peut pas être conservé. Les rôles gérés par une intégration et le rôle @everyone ne peuvent pas être attribués." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_already_exists(&self) -> &'static str {
+        match self {
+            Lang::En => "This rule already exists.",
+            _ => "Cette règle existe déjà.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Member joined",
+            _ => "Nouveau membre",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_all_disabled(&self) -> &'static str {
+        match self { Lang :: En => "Only the quarantine, unverified and persisted roles will be restored when members join the server again." , _ => "Seuls les rôles de quarantaine, non vérifié et conservés seront restaurés lorsque les membres rejoindront à nouveau le serveur." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Username or nickname matching a rule",
+            _ => "Pseudo ou surnom correspondant à une règle",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_role_invalid(&self) -> &'static str {
+        match self { Lang :: En => "This role cannot be used as quarantine role. Managed roles and the @everyone role cannot be assigned." , _ => "Ce rôle ne peut pas être utilisé comme rôle de quarantaine. Les rôles gérés par une intégration et le rôle @everyone ne peuvent pas être attribués." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode is **disabled**.",
+            _ => "Le mode raid est **désactivé**.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_manage_roles(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage Roles",
+            _ => "Gérer les rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_action_kick(&self) -> &'static str {
+        match self {
+            Lang::En => "Kick",
+            _ => "Expulsion",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_all_enabled(&self) -> &'static str {
+        match self { Lang :: En => "All the roles of members will be restored when they join the server again." , _ => "Tous les rôles des membres seront restaurés lorsqu'ils rejoindront à nouveau le serveur." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_placeholder(&self) -> &'static str {
+        match self {
+            Lang::En => "Renamed member",
+            _ => "Membre renommé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Anti-nuke protection: too many destructive actions",
+            _ => "Protection anti-nuke : trop d'actions destructrices",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_list_description(&self) -> &'static str {
+        match self {
+            Lang::En => "List the username and nickname rules",
+            _ => "Afficher les règles de pseudos et de surnoms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_dm_kick(
+        &self,
+        age: impl ::std::fmt::Display,
+        allowed: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Your account must be at least **{age}** old to join this server. You can join the server again {allowed}." , age = age , allowed = allowed) , _ => format ! ("Votre compte doit avoir au moins **{age}** pour rejoindre ce serveur. Vous pourrez rejoindre le serveur à nouveau {allowed}." , age = age , allowed = allowed) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_kick_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Kick Members",
+            _ => "Expulser des membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Targeted members",
+            _ => "Membres ciblés",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn unquarantine_log_description(
+        &self,
+        moderator: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{user} has been released from quarantine by {moderator}.",
+                user = user,
+                moderator = moderator
+            ),
+            _ => format!(
+                "{user} a été sorti de quarantaine par {moderator}.",
+                user = user,
+                moderator = moderator
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_copies(&self) -> &'static str {
+        match self {
+            Lang::En => "Other copies",
+            _ => "Autres copies",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_log_kick(&self, count: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "**{count} members** have been kicked because raid mode is enabled.",
+                count = count
+            ),
+            _ => format!(
+                "**{count} membres** ont été expulsés car le mode raid est activé.",
+                count = count
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_monitor_delete(
+        &self,
+        channel: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**Monitor mode**: the message sent by {user} in {channel} would have been deleted." , channel = channel , user = user) , _ => format ! ("**Mode surveillance** : le message envoyé par {user} dans {channel} aurait été supprimé." , channel = channel , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_failed(&self) -> &'static str {
+        match self {
+            Lang::En => "Failed",
+            _ => "Échecs",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_restore_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Restore the missing roles and channels of a backup",
+            _ => "Restaurer les rôles et salons manquants d'une sauvegarde",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_error_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Mass action error",
+            _ => "Erreur d'action de masse",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_random_name(&self) -> &'static str {
+        match self {
+            Lang::En => "Random username",
+            _ => "Pseudo aléatoire",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn unquarantine_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Release a member from quarantine and restore its roles",
+            _ => "Sortir un membre de quarantaine et restaurer ses rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn warn_not_member(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Impossible to warn **{user}**, it is not a member of this server.",
+                user = user
+            ),
+            _ => format!(
+                "Impossible to warn **{user}**, it is not a member of this server.",
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_coordinated_delete(
+        &self,
+        members: impl ::std::fmt::Display,
+        messages: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("{messages} messages with the same content sent by {members} members have been deleted." , messages = messages , members = members) , _ => format ! ("{messages} messages avec le même contenu envoyés par {members} membres ont été supprimés." , messages = messages , members = members) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_mode_monitor(&self) -> &'static str {
+        match self {
+            Lang::En => "monitor",
+            _ => "surveillance",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_enabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode is **enabled**.",
+            _ => "Le mode raid est **activé**.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_dm_title(&self, guild: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Your account is too recent to join {guild}", guild = guild),
+            _ => format!(
+                "Votre compte est trop récent pour rejoindre {guild}",
+                guild = guild
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_done(&self, role: impl ::std::fmt::Display) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The quarantine role has been set to {role}. The channels permissions are being configured, this may take a few minutes." , role = role) , _ => format ! ("Le rôle de quarantaine a été défini sur {role}. Les permissions des salons sont en cours de configuration, cela peut prendre quelques minutes." , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_log_description(
+        &self,
+        author: impl ::std::fmt::Display,
+        done: impl ::std::fmt::Display,
+        total: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The names cleanup started by {author} is complete. The action has been applied to **{done}** of the {total} matching members." , done = done , total = total , author = author) , _ => format ! ("Le nettoyage des noms lancé par {author} est terminé. L'action a été appliquée à **{done}** des {total} membres concernés." , done = done , total = total , author = author) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_role_error(&self) -> &'static str {
+        match self { Lang :: En => "RaidProtect was unable to create the `Unverified` role that the captcha needs to work. Please try again." , _ => "RaidProtect n'a pas pu créer le rôle `Non vérifié` nécessaire au fonctionnement du captcha. Veuillez réessayer." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn profile_avatar_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Profile picture",
+            _ => "Photo de profil",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_ban_title(&self, username: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Ban {username}", username = username),
+            _ => format!("Ban {username}", username = username),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_logs_disabled_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Message logs are now disabled.",
+            _ => "Les logs de messages sont désormais désactivés.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn masskick_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Kick the members who joined the server within a time range",
+            _ => "Expulser les membres ayant rejoint le serveur dans un intervalle de temps",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_empty_description(&self) -> &'static str {
+        match self { Lang :: En => "No roles are currently given to new members who have completed the captcha. Use the `/config captcha autorole-add` command to add one." , _ => "Aucun rôle n'est actuellement donné aux nouveaux membres ayant complété le captcha. Utilisez la commande `/config captcha autorole-add` pour en ajouter." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_logs_confirm_description(
+        &self,
+        channel: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Edited and deleted messages will now be logged in the {channel} channel." , channel = channel) , _ => format ! ("Les messages modifiés et supprimés seront désormais enregistrés dans le salon {channel}." , channel = channel) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_log_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Reason",
+            _ => "Raison",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_kind_role_delete(&self) -> &'static str {
+        match self {
+            Lang::En => "role deletions",
+            _ => "suppressions de rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_no_members(&self) -> &'static str {
+        match self {
+            Lang::En => "No member matches the given range and filters.",
+            _ => "Aucun membre ne correspond à l'intervalle et aux filtres donnés.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_remove_confirm(
+        &self,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The role {role} will no longer be restored when members join the server again." , role = role) , _ => format ! ("Le rôle {role} ne sera plus restauré lorsque les membres rejoindront à nouveau le serveur." , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Show the raid mode status",
+            _ => "Afficher l'état du mode raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_add_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Add a username and nickname rule",
+            _ => "Ajouter une règle de pseudos et de surnoms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_notes_placeholder(&self) -> &'static str {
+        match self {
+            Lang::En => "Notes visible to server moderators",
+            _ => "Notes visibles par les modérateurs du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_verification_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Begin the verification",
+            _ => "Commencer la vérification",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_off_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Disable the raid mode",
+            _ => "Désactiver le mode raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_enabled_until(
+        &self,
+        action: impl ::std::fmt::Display,
+        time: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Members joining the server will be **{action}** until raid mode expires {time}." , time = time , action = action) , _ => format ! ("Les membres rejoignant le serveur seront **{action}** jusqu'à l'expiration du mode raid {time}." , time = time , action = action) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_invalid(&self, error: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "This pattern is not valid:\n```\n{error}\n```",
+                error = error
+            ),
+            _ => format!(
+                "Ce motif n'est pas valide :\n```\n{error}\n```",
+                error = error
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_hidden(&self) -> &'static str {
+        match self {
+            Lang::En => "Hidden content",
+            _ => "Contenu caché",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_error_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Backup error",
+            _ => "Erreur de sauvegarde",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_all_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Restore all the roles of returning members",
+            _ => "Restaurer tous les rôles des membres qui reviennent",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_name_too_long(
+        &self,
+        max: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "The filter name must contain between 1 and {max} characters.",
+                max = max
+            ),
+            _ => format!(
+                "Le nom du filtre doit contenir entre 1 et {max} caractères.",
+                max = max
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_retention_description(&self) -> &'static str {
+        match self { Lang :: En => "Set how long messages are kept to log their edition or deletion" , _ => "Définir la durée de conservation des messages pour enregistrer leur modification ou suppression" }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_list_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Username and nickname rules",
+            _ => "Règles de pseudos et de surnoms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_reason_custom(
+        &self,
+        reason: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Mass action: {reason}", reason = reason),
+            _ => format!("Action de masse : {reason}", reason = reason),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_not_found(&self, name: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("No regex filter is named \"{name}\".", name = name),
+            _ => format!("Aucun filtre regex n'est nommé « {name} ».", name = name),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_view_audit_log(&self) -> &'static str {
+        match self {
+            Lang::En => "View Audit Log",
+            _ => "Voir les logs du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_pattern_too_long(
+        &self,
+        max: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("the pattern cannot exceed {max} characters", max = max),
+            _ => format!("le motif ne peut pas dépasser {max} caractères", max = max),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_stats_rule(
+        &self,
+        enforced: impl ::std::fmt::Display,
+        mode: impl ::std::fmt::Display,
+        monitored: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Mode: **{mode}**\nMatched in monitor mode: {monitored}\nMatched in enforce mode: {enforced}" , monitored = monitored , enforced = enforced , mode = mode) , _ => format ! ("Mode : **{mode}**\nDétectés en mode surveillance : {monitored}\nDétectés en mode application : {enforced}" , monitored = monitored , enforced = enforced , mode = mode) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_image_description(&self) -> &'static str {
+        match self { _ => "Pour accéder au serveur, __mémorisez le code que vous lisez dans l'image ci-dessous__ puis cliquez sur le bouton et écrivez-le dans le formulaire qui s'affichera.\n\nSi vous ne validez pas ce captcha, vous serez expulsé du serveur dans 5 minutes. Vous pouvez le regénérer si vous avez des difficultés à le lire." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_log_description(
+        &self,
+        id: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{user} (`{id}`) joined the server.", id = id, user = user),
+            _ => format!(
+                "{user} (`{id}`) a rejoint le serveur.",
+                id = id,
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage the backups of the server structure",
+            _ => "Gérer les sauvegardes de la structure du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_confirm_description(&self) -> &'static str {
+        match self { Lang :: En => "By enabling the captcha, RaidProtect will ask new server members to complete a challenge to ensure that they are not robots (*selfbot/userbot*). \n\nA `#verification` channel and a `@Unverfied` role will be created and configured automatically." , _ => "En activant le captcha, RaidProtect demandera à tous les nouveaux membres de votre serveur de passer un challenge pour vérifier qu'ils ne sont pas des robots (*selfbot/userbot*). \n\nUn salon `#vérification` et un role `@Non verifié` seront créés et configurés automatiquement." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_channel_error(&self) -> &'static str {
+        match self { Lang :: En => "RaidProtect was unable to create the `verification` channel that the captcha needs to work. Please try again." , _ => "RaidProtect n'a pas pu créer le salon `vérification` nécessaire au fonctionnement du captcha. Veuillez réessayer." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_logs_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Join logs are now disabled.",
+            _ => "Les logs d'arrivée sont maintenant désactivés.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_days(&self, days: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("{days} days", days = days),
+            _ => format!("{days} jours", days = days),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disable_confirm_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Click to disable the captcha",
+            _ => "Cliquez pour désactiver le captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_empty_title(&self) -> &'static str {
+        match self {
+            Lang::En => "No configured role",
+            _ => "Aucun rôle configuré",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_diff_new_channels(&self) -> &'static str {
+        match self {
+            Lang::En => "New channels",
+            _ => "Nouveaux salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_kick_title(&self, username: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Kick {username}", username = username),
+            _ => format!("Expulsion de {username}", username = username),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_rule_coordinated(&self) -> &'static str {
+        match self {
+            Lang::En => "Coordinated spam",
+            _ => "Spam coordonné",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn warn_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Warns a user from the server",
+            _ => "Warns a user from the server",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_description(
+        &self,
+        channel: impl ::std::fmt::Display,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("New members will now have to complete a verification to access this server. The {channel} channel and {role} role have been created and automatically configured.\n\nRaidProtect is hiding your channels from members who have not completed thr verification. This process may take up to 10 minutes depending on the number of channels you have." , channel = channel , role = role) , _ => format ! ("Les nouveaux membres devront désormais passer une vérification afin d'accéder à ce serveur. Le salon {channel} et le rôle {role} ont été créés et configurés automatiquement.\n\nRaidProtect est en train de masquer vos salons aux membres n'ayant pas passé la vérification. Tout sera fonctionnel d'ici quelques minutes !" , channel = channel , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn help_embed_description(&self) -> &'static str {
+        match self { Lang :: En => "You will find tutorials to use raidprotect in our **[help center](https://docs.raidprotect.org/)**. You can also join our **[Discord server](https://raidpro.tk/discord)** and ask your questions to our team." , _ => "Vous trouverez des tutoriels pour utiliser RaidProtect dans notre **[centre d'aide](https://docs.raidprotect.org/)**. Vous pouvez également rejoindre notre **[serveur Discord](https://raidpro.tk/discord)** et poser vos questions à notre équipe." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_join_wave(&self) -> &'static str {
+        match self {
+            Lang::En => "Joined during a join wave",
+            _ => "Arrivée pendant une vague",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_confirm_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Click to enable the captcha",
+            _ => "Cliquez pour activer le captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_monitor_timeout(
+        &self,
+        channel: impl ::std::fmt::Display,
+        minutes: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**Monitor mode**: the message sent by {user} in {channel} would have been deleted and the member timed out for {minutes} minutes." , channel = channel , minutes = minutes , user = user) , _ => format ! ("**Mode surveillance** : le message envoyé par {user} dans {channel} aurait été supprimé et le membre exclu temporairement pendant {minutes} minutes." , channel = channel , minutes = minutes , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn account_age_log_description(
+        &self,
+        created: impl ::std::fmt::Display,
+        id: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{user} (`{id}`) joined with an account created {created}.",
+                id = id,
+                user = user,
+                created = created
+            ),
+            _ => format!(
+                "{user} (`{id}`) a rejoint avec un compte créé {created}.",
+                id = id,
+                user = user,
+                created = created
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_coordinated_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Members",
+            _ => "Membres",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_send_messages_in_threads(&self) -> &'static str {
+        match self {
+            Lang::En => "Send Messages in Threads",
+            _ => "Envoyer des messages dans les fils",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_default_avatar(&self) -> &'static str {
+        match self {
+            Lang::En => "Default avatar",
+            _ => "Avatar par défaut",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_cancel_button(&self) -> &'static str {
+        match self {
+            Lang::En => "Cancel",
+            _ => "Annuler",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn ban_bot_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect doesn't have permission to ban this member.",
+            _ => "RaidProtect doesn't have permission to ban this member.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn unknown_command_description(&self) -> &'static str {
+        match self { Lang :: En => "The command you are trying to perform is not yet available. Wait a few minutes and try again." , _ => "La commande que vous essayez d'effectuer n'est pas encore disponible. Patientez quelques minutes et réessayez." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_rename_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Renaming the role and the channel",
+            _ => "Renommage du rôle et du salon",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_confirm_title(
+        &self,
+        action: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Confirm: {action} members", action = action),
+            _ => format!("Confirmation : {action} des membres", action = action),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_footer(
+        &self,
+        message: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Message: {message} • User: {user}",
+                message = message,
+                user = user
+            ),
+            _ => format!(
+                "Message : {message} • Utilisateur : {user}",
+                message = message,
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_verification_description(&self) -> &'static str {
+        match self { Lang :: En => "This server uses an automated verification system to prevent bots from joining. To access all channels, you must complete a short verification to prove that you are not one of these bots.\n\nIf you don't complete the verification, you' ll be kicked out of this server in 5 minutes. You can always join again using the same invite." , _ => "Ce serveur utilise un système de vérification automatisé pour empêcher des robots de le rejoindre. Afin d'accéder à tous les salons, vous devez passer une vérification rapide pour prouver que vous n'êtes pas un de ces robots.\n\nEn l'absence de vérification, vous serez expulsé de ce serveur dans 5 minutes. Vous pourrez toujours le rejoindre à nouveau en utilisant la même invitation." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_warn_title(&self, username: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Warn {username}", username = username),
+            _ => format!("Warn {username}", username = username),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_dm_ban(&self) -> &'static str {
+        match self { Lang :: En => "The server is currently protected against a raid and new members are banned. Contact a moderator of the server if you think this is a mistake." , _ => "Le serveur est actuellement protégé contre un raid et les nouveaux membres sont bannis. Contactez un modérateur du serveur si vous pensez qu'il s'agit d'une erreur." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_image_title(&self) -> &'static str {
+        match self {
+            _ => "Complétez le captcha pour continuer",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_ban(&self) -> &'static str {
+        match self {
+            Lang::En => "ban",
+            _ => "bannir",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_test_result(
+        &self,
+        matched: impl ::std::fmt::Display,
+        total: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{matched} of the {total} last cached messages match.",
+                total = total,
+                matched = matched
+            ),
+            _ => format!(
+                "{matched} des {total} derniers messages en cache correspondent.",
+                total = total,
+                matched = matched
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_title(&self, rule: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Auto-moderation: {rule}", rule = rule),
+            _ => format!("Auto-modération : {rule}", rule = rule),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_action_confirm(&self, action: impl ::std::fmt::Display) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Members joining while raid mode is enabled will now be **{action}**." , action = action) , _ => format ! ("Les membres rejoignant le serveur pendant le mode raid seront désormais **{action}**." , action = action) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_action_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the action performed on members joining during raid mode",
+            _ => "Configurer l'action effectuée sur les membres rejoignant pendant le mode raid",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_list_empty(&self) -> &'static str {
+        match self { Lang :: En => "No rule is configured. Use `/config names add` to add a rule." , _ => "Aucune règle n'est configurée. Utilisez `/config names add` pour ajouter une règle." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_confirm_create(&self) -> &'static str {
+        match self { Lang :: En => "A new quarantine role will be created. Its permissions will be denied in every channel of the server, so quarantined members cannot see any channel." , _ => "Un nouveau rôle de quarantaine sera créé. Ses permissions seront refusées dans tous les salons du serveur, afin que les membres en quarantaine ne puissent voir aucun salon." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_restore_started(
+        &self,
+        version: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The restoration of backup **#{version}** has started. A summary will be sent in the logs channel once it is complete." , version = version) , _ => format ! ("La restauration de la sauvegarde **n°{version}** a commencé. Un résumé sera envoyé dans le salon de logs une fois terminée." , version = version) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn modal_notes_label(&self) -> &'static str {
+        match self {
+            Lang::En => "Notes",
+            _ => "Notes",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_status_automatic(&self) -> &'static str {
+        match self {
+            Lang::En => "Automatic detection",
+            _ => "Détection automatique",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_already_quarantined(&self) -> &'static str {
+        match self {
+            Lang::En => "This member is already quarantined.",
+            _ => "Ce membre est déjà en quarantaine.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mute_not_member(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "Impossible to mute **{user}**, it is not a member of this server.",
+                user = user
+            ),
+            _ => format!(
+                "Impossible to mute **{user}**, it is not a member of this server.",
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_log_description(
+        &self,
+        id: impl ::std::fmt::Display,
+        name: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "{user} (`{id}`, **{name}**) has a username or nickname matching a rule.",
+                id = id,
+                name = name,
+                user = user
+            ),
+            _ => format!(
+                "{user} (`{id}`, **{name}**) a un pseudo ou un surnom correspondant à une règle.",
+                id = id,
+                name = name,
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_confirm_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Enabling the captcha",
+            _ => "Activation du captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_timeout(
+        &self,
+        channel: impl ::std::fmt::Display,
+        minutes: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The message sent by {user} in {channel} has been deleted and the member has been timed out for {minutes} minutes." , channel = channel , minutes = minutes , user = user) , _ => format ! ("Le message envoyé par {user} dans {channel} a été supprimé et le membre a été exclu temporairement pendant {minutes} minutes." , channel = channel , minutes = minutes , user = user) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mute_bot_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect doesn't have permission to mute this member.",
+            _ => "RaidProtect doesn't have permission to mute this member.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn mass_action_log_reason(&self) -> &'static str {
+        match self {
+            Lang::En => "Reason",
+            _ => "Raison",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_no_match(
+        &self,
+        scanned: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "None of the {scanned} scanned members has a name matching a rule.",
+                scanned = scanned
+            ),
+            _ => format!(
+                "Aucun des {scanned} membres analysés n'a de nom correspondant à une règle.",
+                scanned = scanned
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signals(&self) -> &'static str {
+        match self {
+            Lang::En => "Signals",
+            _ => "Signaux",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raid_detected_members(&self) -> &'static str {
+        match self {
+            Lang::En => "Members of the join wave",
+            _ => "Membres de la vague d'arrivées",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_test_pattern(&self) -> &'static str {
+        match self {
+            Lang::En => "Tested pattern",
+            _ => "Motif testé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_monitor_hits(
+        &self,
+        hits: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "This rule has matched {hits} messages in monitor mode",
+                hits = hits
+            ),
+            _ => format!(
+                "Cette règle a détecté {hits} messages en mode surveillance",
+                hits = hits
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn message_logs_update_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Message edited",
+            _ => "Message modifié",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn bot_missing_permission(&self) -> &'static str {
+        match self { Lang :: En => "RaidProtect requires permission **Administrator** to work properly. Go to your server's role settings to grant it." , _ => "RaidProtect a besoin de la permission **Administrateur** pour fonctionner correctement. Rendez-vous dans les paramètres des rôles de votre serveur pour la lui accorder." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_monitor_quarantine(
+        &self,
+        channel: impl ::std::fmt::Display,
+        user: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("**Monitor mode**: the message sent by {user} in {channel} would have been deleted and the member quarantined." , user = user , channel = channel) , _ => format ! ("**Mode surveillance** : le message envoyé par {user} dans {channel} aurait été supprimé et le membre mis en quarantaine." , user = user , channel = channel) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_add_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Persist a role, such as a mute role, when members leave",
+            _ => "Conserver un rôle, comme un rôle muet, lorsque les membres quittent",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn hierarchy_bot(&self) -> &'static str {
+        match self { Lang :: En => "This member has a role above or equivalent to that of RaidProtect in the list of roles, which prevents moderation actions from being performed on him. You can correct this by placing RaidProtect higher in the list of roles." , _ => "Ce membre a un rôle au dessus ou équivalent à celui de RaidProtect dans la liste des rôles, ce qui empêche d'effectuer des actions de modération le concernant. Vous pouvez corriger cela en plaçant RaidProtect plus haut dans la liste des rôles." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn messages_missing_logs_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect cannot send logs to this channel",
+            _ => "RaidProtect ne peut pas envoyer les logs dans ce salon",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_not_enabled_description(&self) -> &'static str {
+        match self { Lang :: En => "This command cannot be performed because captcha is not enabled on this server. You can enable it with the `/config captcha enable` command." , _ => "Cette commande ne peut pas être effectuée car le captcha n'est pas activé sur ce serveur. Vous pouvez l'activer avec la commande `/config captcha enable`." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn filter_regex_remove_confirm(
+        &self,
+        rule: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self {
+            Lang::En => format!("The **{rule}** has been removed.", rule = rule),
+            _ => format!("Le **{rule}** a été supprimé.", rule = rule),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_create_confirm(
+        &self,
+        channels: impl ::std::fmt::Display,
+        roles: impl ::std::fmt::Display,
+        version: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("Backup **#{version}** has been created with {roles} roles and {channels} channels." , version = version , roles = roles , channels = channels) , _ => format ! ("La sauvegarde **n°{version}** a été créée avec {roles} rôles et {channels} salons." , version = version , roles = roles , channels = channels) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn profile_created_at(&self) -> &'static str {
+        match self {
+            Lang::En => "Profile created at",
+            _ => "Création du compte",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_role_hierarchy_title(&self) -> &'static str {
+        match self {
+            Lang::En => "RaidProtect's role is too low",
+            _ => "Le rôle de RaidProtect est trop bas",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn permission_manage_webhooks(&self) -> &'static str {
+        match self {
+            Lang::En => "Manage Webhooks",
+            _ => "Gérer les webhooks",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_mode_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "disabled",
+            _ => "désactivé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_action_strip_roles(&self) -> &'static str {
+        match self {
+            Lang::En => "Remove the roles",
+            _ => "Retirer les rôles",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_logs_confirm(&self, channel: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!("Joins will now be logged in {channel}.", channel = channel),
+            _ => format!(
+                "Les arrivées seront désormais enregistrées dans {channel}.",
+                channel = channel
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_list_description(&self) -> &'static str {
+        match self {
+            Lang::En => "List the backups of the server",
+            _ => "Lister les sauvegardes du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_channels(&self) -> &'static str {
+        match self {
+            Lang::En => "Channels",
+            _ => "Salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_log_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Names cleanup complete",
+            _ => "Nettoyage des noms terminé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_action_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the action performed on members with a matching name",
+            _ => "Configurer l'action effectuée sur les membres dont le nom correspond à une règle",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_disabled_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode disabled",
+            _ => "Mode raid désactivé",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_image_alt(&self) -> &'static str {
+        match self {
+            _ => "Image du captcha",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_expired_reason(&self) -> &'static str {
+        match self {
+            _ => "Vérification non completée dans les délais",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn backup_list_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Server backups",
+            _ => "Sauvegardes du serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_confirm_role(
+        &self,
+        role: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The role {role} will be used as quarantine role. Its permissions will be denied in every channel of the server, so quarantined members cannot see any channel." , role = role) , _ => format ! ("Le rôle {role} sera utilisé comme rôle de quarantaine. Ses permissions seront refusées dans tous les salons du serveur, afin que les membres en quarantaine ne puissent voir aucun salon." , role = role) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_signal_new_account(&self) -> &'static str {
+        match self {
+            Lang::En => "Account created less than a day ago",
+            _ => "Compte créé il y a moins d'un jour",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn antinuke_disabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Anti-nuke protection disabled.",
+            _ => "Protection anti-nuke désactivée.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disable_confirm_description(
+        &self,
+        unverified: impl ::std::fmt::Display,
+        verification: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("If you continue, RaidProtect will disable the captcha and remove the {verification} channel and the {unverified} role." , verification = verification , unverified = unverified) , _ => format ! ("En continuant, RaidProtect désactivera le captcha et supprimera le salon {verification} et le rôle {unverified}. " , verification = verification , unverified = unverified) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Configure the username and nickname rules",
+            _ => "Configurer les règles de pseudos et de surnoms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disabled_description(&self) -> &'static str {
+        match self {
+            Lang::En => {
+                "You can enable it again at any time with the `/config captcha enable` command."
+            }
+            _ => {
+                "Vous pouvez le réactiver à tout moment avec la commande `/config captcha enable`."
+            }
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_enabled_roles_description(&self) -> &'static str {
+        match self { Lang :: En => "By default, members will not receive any roles after completing the verification. You can add a role to be given automatically with the `/config captcha autorole-add` command. \n\n**If you already have a bot that automatically gives a role to all new members** (autorole), disable it and use the captcha feature described above. Other bots may interfere with the captcha." , _ => "Par défaut, les membres ne recevront aucun rôle après avoir passé la vérification. Vous pouvez ajouter un rôle à donner automatiquement avec la commande `/config captcha autorole-add`. \n\n**Si vous avez déjà un bot qui donne automatiquement un rôle à tous les nouveaux membres** (autorole), désactivez-le et utilisez la fonctionnalité du captcha décrite ci-dessus. Les autres bots risquent d'interférer avec le captcha." }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn warn_missing_permission_title(&self) -> &'static str {
+        match self {
+            Lang::En => "You don't have permission to warn this member",
+            _ => "You don't have permission to warn this member",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_captcha_all(&self) -> &'static str {
+        match self {
+            Lang::En => "The captcha is required for all members.",
+            _ => "Le captcha est requis pour tous les membres.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn raidmode_not_enabled(&self) -> &'static str {
+        match self {
+            Lang::En => "Raid mode is not enabled.",
+            _ => "Le mode raid n'est pas activé.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn automod_log_content(&self) -> &'static str {
+        match self {
+            Lang::En => "Message content",
+            _ => "Contenu du message",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn quarantine_setup_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Set the quarantine role and hide it from all channels",
+            _ => "Définir le rôle de quarantaine et le masquer de tous les salons",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cleanup_names_title(&self) -> &'static str {
+        match self {
+            Lang::En => "Names cleanup",
+            _ => "Nettoyage des noms",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn risk_logs_description(&self) -> &'static str {
+        match self {
+            Lang::En => "Set the channel where joins are logged with their risk score",
+            _ => "Définir le salon où les arrivées sont enregistrées avec leur score de risque",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn cache_http_missing_permissions_channel(
+        &self,
+        channel: impl ::std::fmt::Display,
+        permissions: impl ::std::fmt::Display,
+    ) -> ::std::string::String {
+        match self { Lang :: En => format ! ("RaidProtect needs the following permissions in {channel} to perform this action:\n{permissions}\n\nGrant them to the RaidProtect role in the channel settings, then try again." , channel = channel , permissions = permissions) , _ => format ! ("RaidProtect a besoin des permissions suivantes dans {channel} pour effectuer cette action :\n{permissions}\n\nAccordez-les au rôle de RaidProtect dans les paramètres du salon, puis réessayez." , channel = channel , permissions = permissions) }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_kind_substring(&self) -> &'static str {
+        match self {
+            Lang::En => "Contains",
+            _ => "Contient",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_disabled_log(&self, user: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "**The captcha has been disabled** on the server by {user}.",
+                user = user
+            ),
+            _ => format!(
+                "**Le captcha a été désactivé** sur le serveur par {user}.",
+                user = user
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn persist_role_not_configured(&self) -> &'static str {
+        match self {
+            Lang::En => "This role is not persisted.",
+            _ => "Ce rôle n'est pas conservé.",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn help_bot_invite(&self) -> &'static str {
+        match self {
+            Lang::En => "Add to my server",
+            _ => "Ajouter à mon serveur",
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn names_not_found(&self, pattern: impl ::std::fmt::Display) -> ::std::string::String {
+        match self {
+            Lang::En => format!(
+                "No rule found with the pattern **{pattern}**.",
+                pattern = pattern
+            ),
+            _ => format!(
+                "Aucune règle trouvée avec le motif **{pattern}**.",
+                pattern = pattern
+            ),
+        }
+    }
+    #[allow(clippy::match_single_binding)]
+    pub fn captcha_autorole_list(&self, roles: impl ::std::fmt::Display) -> ::std::string::String {
+        match self { Lang :: En => format ! ("The following roles will be given to new members after they complete the captcha:\n\n{roles}\n\nTo add a role, use the `/config captcha autorole-add` command." , roles = roles) , _ => format ! ("Les rôles suivants seront donnés aux nouveaux membres après qu'ils aient complété le captcha:\n\n{roles}\n\nPour ajouter un rôle, utilisez la commande `/config captcha autorole-add`." , roles = roles) }
+    }
+}
+impl ::rosetta_i18n::Language for Lang {
+    fn from_language_id(language_id: &::rosetta_i18n::LanguageId) -> ::core::option::Option<Self> {
+        match language_id.value() {
+            "en" => ::core::option::Option::Some(Self::En),
+            "fr" => ::core::option::Option::Some(Self::Fr),
+            _ => ::core::option::Option::None,
+        }
+    }
+    fn language_id(&self) -> ::rosetta_i18n::LanguageId {
+        match self {
+            Self::En => ::rosetta_i18n::LanguageId::new("en"),
+            Self::Fr => ::rosetta_i18n::LanguageId::new("fr"),
+        }
+    }
+    fn fallback() -> Self {
+        Self::Fr
+    }
+}